@@ -0,0 +1,28 @@
+//! Authorization of the admin instructions.
+//!
+//! The admin of a market and the authority of a bank are either a regular key, which has to sign, or a `Multisig`, in which case enough of its signers have to sign instead.
+//! Admin instructions take their authority as an unchecked account and go through `check_authority` so that both work the same everywhere.
+
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::Multisig;
+
+/// Checks that `authority` approved the instruction.
+///
+/// A `Multisig` owned by the program is approved when `threshold` of its signers are among the signing `signers`, the remaining accounts of the instruction.
+/// Any other account has to sign the transaction itself.
+/// The caller still has to check that `authority` is the expected admin or authority, e.g. with `has_one` or `LendingMarket::check_admin`.
+pub fn check_authority(authority: &AccountInfo, signers: &[AccountInfo]) -> Result<()> {
+    if authority.owner == &crate::ID {
+        let multisig = Multisig::try_deserialize(&mut &authority.try_borrow_data()?[..])?;
+
+        return multisig.check_signatures(signers);
+    }
+
+    if !authority.is_signer {
+        return Err(ErrorCode::Unauthorized.into());
+    }
+
+    Ok(())
+}
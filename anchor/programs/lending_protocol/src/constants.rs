@@ -1,9 +1,67 @@
 use anchor_lang::prelude::*;
 
+/// Upper bound for the staleness a bank can accept from its oracle
 #[constant]
-pub const SOL_USD_FEED_ID: &str =
-    "0xef0d8b6fda2ceba41da15d4095d1da392a0d2f8ed0c6c7bc0f4cfac8c280b56d";
-pub const USDC_USD_FEED_ID: &str =
-    "0xeaa020c61cc479712813461ce153894a96a6c00b21ed0cfc2798d1f9a9e9c94a";
-// Price feeds from devnet snapshots may be old
-pub const MAX_AGE: u64 = 60 * 60 * 24; // 24 hours in seconds
+pub const MAX_PRICE_AGE: u64 = 60 * 60 * 24; // 24 hours in seconds
+
+/// Maximum number of banks a single user can have a position in
+pub const MAX_POSITIONS: usize = 8;
+
+/// Maximum number of configuration changes a bank can have queued at once
+pub const MAX_PENDING_CONFIG_CHANGES: usize = 4;
+
+/// Maximum number of signers of a multisig
+pub const MAX_MULTISIG_SIGNERS: usize = 10;
+
+/// Upper bound for the timelock of configuration changes
+#[constant]
+pub const MAX_CONFIG_TIMELOCK: u64 = 60 * 60 * 24 * 30; // 30 days in seconds
+
+// Operations that can be paused, as bits of `Bank::paused` and `LendingMarket::paused`
+#[constant]
+pub const PAUSE_DEPOSIT: u8 = 1 << 0;
+#[constant]
+pub const PAUSE_WITHDRAW: u8 = 1 << 1;
+#[constant]
+pub const PAUSE_BORROW: u8 = 1 << 2;
+#[constant]
+pub const PAUSE_REPAY: u8 = 1 << 3;
+#[constant]
+pub const PAUSE_LIQUIDATE: u8 = 1 << 4;
+#[constant]
+pub const PAUSE_ALL: u8 =
+    PAUSE_DEPOSIT | PAUSE_WITHDRAW | PAUSE_BORROW | PAUSE_REPAY | PAUSE_LIQUIDATE;
+
+/// Scale used for percentages stored on-chain: 10_000 basis points == 100%
+pub const BASIS_POINTS: u64 = 10_000;
+pub const SECONDS_PER_YEAR: u64 = 365 * 24 * 60 * 60;
+
+/// Longest period a single accrual charges interest for.
+/// A bank left without any instruction for longer only accrues this much, anyone can call `refresh_bank` to avoid it.
+pub const MAX_ACCRUAL_PERIOD: u64 = SECONDS_PER_YEAR;
+
+/// Upper bound for `base_rate`, `slope_1` and `slope_2` of a bank.
+/// Even at the three maxima, a single accrual of at most `MAX_ACCRUAL_PERIOD` compounds to e^9 and stays far from overflowing a `Rate`.
+#[constant]
+pub const MAX_RATE: u64 = 30_000; // 300%
+
+// Default interest rate model assigned to new banks. Rates are annualized and in basis points.
+pub const DEFAULT_BASE_RATE: u64 = 0; // 0%
+pub const DEFAULT_OPTIMAL_UTILIZATION: u64 = 8_000; // 80%
+pub const DEFAULT_SLOPE_1: u64 = 400; // 4%
+pub const DEFAULT_SLOPE_2: u64 = 7_500; // 75%
+pub const DEFAULT_MAX_UTILIZATION: u64 = 9_500; // 95%
+pub const DEFAULT_RESERVE_FACTOR: u64 = 1_000; // 10%
+pub const DEFAULT_INSURANCE_FACTOR: u64 = 500; // 5%
+pub const DEFAULT_LIQUIDATION_INSURANCE_FEE: u64 = 1_000; // 10% of the liquidation bonus
+
+/// Default share of a borrower's debt, as a whole percentage, that a single liquidation can repay
+pub const DEFAULT_LIQUIDATION_CLOSE_FACTOR: u64 = 50; // 50%
+
+// Default dutch auction liquidation bonus, in basis points
+pub const DEFAULT_MIN_LIQUIDATION_BONUS: u64 = 200; // 2%
+pub const DEFAULT_MAX_LIQUIDATION_BONUS: u64 = 1_000; // 10%
+pub const DEFAULT_LIQUIDATION_BONUS_FLOOR_HEALTH: u64 = 8_000; // health factor of 0.8
+
+/// Default delay before a queued configuration change can be executed
+pub const DEFAULT_CONFIG_TIMELOCK: u64 = 60 * 60 * 24 * 2; // 2 days in seconds
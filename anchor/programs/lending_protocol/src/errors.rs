@@ -13,4 +13,135 @@ pub enum ErrorCode {
 
     #[msg("User's account is not unhealthy")]
     AccountNotUnhealthy,
+
+    #[msg("Withdrawal would leave the user's debt above their borrow limit")]
+    InsufficientCollateral,
+
+    #[msg("Accounts passed to compute the health of the user don't match their positions")]
+    InvalidHealthAccounts,
+
+    #[msg("Collateral and borrowed asset of a liquidation must be different")]
+    SameCollateralAndBorrowedAsset,
+
+    #[msg("Liquidation would seize less collateral than the liquidator's minimum")]
+    LiquidationSlippageExceeded,
+
+    #[msg("Account still has collateral and must be liquidated instead")]
+    AccountHasCollateral,
+
+    #[msg("Account has no debt in this bank to write off")]
+    NoBadDebt,
+
+    #[msg("User already has a position in the maximum number of banks")]
+    MaxPositionsReached,
+
+    #[msg("Signer is not the authority of the bank")]
+    Unauthorized,
+
+    #[msg("Signer is not the pending authority of the bank")]
+    NotPendingAuthority,
+
+    #[msg("Signer is not the admin of the lending market")]
+    NotMarketAdmin,
+
+    #[msg("Operation is paused")]
+    ProtocolPaused,
+
+    #[msg("Pause flags contain an unknown operation")]
+    InvalidPauseFlags,
+
+    #[msg("Oracle configuration is invalid")]
+    InvalidOracleConfig,
+
+    #[msg("Price account does not match the oracle configured on the bank")]
+    InvalidPriceFeed,
+
+    #[msg("Oracle price is not positive")]
+    InvalidPrice,
+
+    #[msg("Oracle confidence interval is too wide for the price to be reliable")]
+    PriceConfidenceTooWide,
+
+    #[msg("Oracle price is older than the maximum age configured on the bank")]
+    StalePrice,
+
+    #[msg("Primary and secondary oracle prices diverge too much to take on more risk")]
+    OracleDivergence,
+
+    #[msg("Secondary oracle account is required when a secondary oracle is configured")]
+    MissingSecondaryOracle,
+
+    #[msg("Liquidation threshold must be between 1 and 100 percent")]
+    InvalidLiquidationThreshold,
+
+    #[msg("Max LTV must be positive and below the liquidation threshold")]
+    InvalidMaxLtv,
+
+    #[msg("Liquidation close factor must be between 1 and 100 percent")]
+    InvalidCloseFactor,
+
+    #[msg("Liquidation bonus is too high for liquidations to improve the health of an account")]
+    InvalidLiquidationBonus,
+
+    #[msg("Optimal utilization must be between 1 and 10000 basis points and each rate at most MAX_RATE")]
+    InvalidInterestRateModel,
+
+    #[msg("Max utilization must be between 1 and 10000 basis points")]
+    InvalidMaxUtilization,
+
+    #[msg("Reserve factor, insurance factor and liquidation insurance fee must not exceed 100 percent")]
+    InvalidFeeConfig,
+
+    #[msg("Config timelock is longer than the maximum allowed")]
+    InvalidConfigTimelock,
+
+    #[msg("Bank already has the maximum number of queued config changes")]
+    ConfigQueueFull,
+
+    #[msg("No queued config change with this id")]
+    ConfigChangeNotFound,
+
+    #[msg("Timelock of the config change has not expired yet")]
+    ConfigChangeNotReady,
+
+    #[msg("Multisig needs between 1 and the maximum number of distinct signers, and a threshold between 1 and the number of signers")]
+    InvalidMultisig,
+
+    #[msg("Not enough signers of the multisig signed the transaction")]
+    MultisigThresholdNotMet,
+
+    #[msg("Deposit would take the total deposits of the bank above its deposit cap")]
+    DepositCapExceeded,
+
+    #[msg("Borrow would take the total borrows of the bank above its borrow cap")]
+    BorrowCapExceeded,
+
+    #[msg("Borrow would take the utilization of the bank above its maximum")]
+    MaxUtilizationExceeded,
+
+    #[msg("Not enough liquidity in the bank token account")]
+    InsufficientLiquidity,
+
+    #[msg("Math operation overflowed")]
+    MathOverflow,
+
+    #[msg("Division by zero")]
+    DivisionByZero,
+
+    #[msg("Borrower has no debt in the borrowed bank to liquidate")]
+    NoDebtToLiquidate,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn released_error_codes_are_stable() {
+        // Clients match on these numbers, new variants go at the end of the enum
+        assert_eq!(u32::from(ErrorCode::InsufficientFunds), 6000);
+        assert_eq!(u32::from(ErrorCode::OverBorrowableAmount), 6001);
+        assert_eq!(u32::from(ErrorCode::OverRepay), 6002);
+        assert_eq!(u32::from(ErrorCode::AccountNotUnhealthy), 6003);
+    }
 }
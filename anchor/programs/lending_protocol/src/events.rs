@@ -0,0 +1,20 @@
+//! Events emitted by the program for indexers and monitoring
+
+use anchor_lang::prelude::*;
+
+/// Debt of a borrower without any collateral left was written off, paid by the insurance fund of the bank and then by its depositors
+#[event]
+pub struct BadDebtSocialized {
+    pub bank: Pubkey,
+    /// Owner of the user account whose debt was written off
+    pub borrower: Pubkey,
+    /// Debt written off, in tokens of the bank
+    pub amount: u64,
+    /// Part of `amount` paid back by the insurance fund, the rest is the loss of the depositors
+    pub insurance_covered: u64,
+    pub borrow_shares: u64,
+    /// Supply index before and after the loss, as a `Rate`
+    pub supply_index_before: u128,
+    pub supply_index_after: u128,
+    pub timestamp: i64,
+}
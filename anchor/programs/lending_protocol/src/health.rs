@@ -0,0 +1,231 @@
+//! Health of a user across every position they hold.
+//!
+//! Collateral and debt are summed in USD over all the banks a user deposited into or borrowed from, so a borrow in one asset is backed by the deposits in all the others.
+//! Borrow, withdraw and liquidate all go through `compute_health` so that they can never disagree on whether a user is healthy.
+
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::math::{Rounding, Wad};
+use crate::oracle::{get_bank_price, lower_bound_price, upper_bound_price, usd_value};
+use crate::state::{Bank, User};
+
+/// Totals of a user's positions, all in USD
+#[derive(Clone, Copy, Debug, Default)]
+pub struct AccountHealth {
+    /// Collateral weighted by the `max_ltv` of each bank, the most the user can owe after a borrow or withdrawal
+    pub borrow_limit: Wad,
+
+    /// Collateral weighted by the `liquidation_threshold` of each bank, the most the user can owe before they can be liquidated
+    pub liquidation_limit: Wad,
+
+    /// Debt across every bank the user borrowed from
+    pub debt_value: Wad,
+}
+
+impl AccountHealth {
+    /// Risk-weighted collateral divided by the debt. `None` if the user has no debt, in which case they are always healthy.
+    pub fn health_factor(&self) -> Result<Option<Wad>> {
+        if self.debt_value.is_zero() {
+            return Ok(None);
+        }
+
+        self.liquidation_limit
+            .checked_div(self.debt_value, Rounding::Down)
+            .map(Some)
+    }
+
+    /// A user can be liquidated once their health factor is below 1
+    pub fn is_liquidatable(&self) -> Result<bool> {
+        Ok(self
+            .health_factor()?
+            .is_some_and(|health_factor| health_factor < Wad::ONE))
+    }
+
+    /// Whether the user's debt is still within their borrow limit
+    pub fn is_within_borrow_limit(&self) -> bool {
+        self.debt_value <= self.borrow_limit
+    }
+}
+
+/// Computes the health of `user` from the banks and oracles of all of their positions.
+///
+/// `accounts` are the remaining accounts of the instruction and must hold, for each position of the user and in the same order:
+/// the bank of the position, its primary oracle, and its secondary oracle if the bank has one.
+/// The accounts of a position the instruction closed may still be there, see `next_position_bank`.
+///
+/// Banks are read as they were before the instruction and their interest is accrued in memory, so banks modified by the instruction give the same indices as their up to date copy.
+/// With `risk_increase` the oracles of every bank have to agree, see `BankPrice::for_risk_increase`.
+pub fn compute_health(
+    user: &User,
+    accounts: &[AccountInfo],
+    risk_increase: bool,
+) -> Result<AccountHealth> {
+    let clock = Clock::get()?;
+    let mut accounts = accounts.iter();
+    let mut health = AccountHealth::default();
+
+    for position in &user.positions {
+        let bank_account = next_position_bank(&mut accounts, user, &position.bank)?;
+        if bank_account.owner != &crate::ID {
+            return Err(ErrorCode::InvalidHealthAccounts.into());
+        }
+
+        let mut bank = Bank::try_deserialize(&mut &bank_account.try_borrow_data()?[..])?;
+        if bank.market != user.market {
+            return Err(ErrorCode::InvalidHealthAccounts.into());
+        }
+
+        bank.accrue_interest(clock.unix_timestamp)?;
+
+        let primary_oracle = accounts.next().ok_or(ErrorCode::InvalidHealthAccounts)?;
+        let secondary_oracle = match bank.secondary_oracle {
+            Some(_) => Some(accounts.next().ok_or(ErrorCode::InvalidHealthAccounts)?),
+            None => None,
+        };
+
+        let bank_price = get_bank_price(&bank, primary_oracle, secondary_oracle, &clock)?;
+        let price = if risk_increase {
+            bank_price.for_risk_increase()?
+        } else {
+            bank_price.price
+        };
+
+        // Collateral is valued at the bottom of the confidence interval and debt at the top, so that the uncertainty of the oracle always works against the user
+        if position.deposit_shares > 0 {
+            let collateral_value = usd_value(
+                bank.deposit_amount(position.deposit_shares)?,
+                bank.decimals,
+                &lower_bound_price(&price),
+                Rounding::Down,
+            )?;
+
+            let borrow_limit =
+                collateral_value.checked_mul(Wad::from_percent(bank.max_ltv)?, Rounding::Down)?;
+            let liquidation_limit = collateral_value.checked_mul(
+                Wad::from_percent(bank.liquidation_threshold)?,
+                Rounding::Down,
+            )?;
+
+            health.borrow_limit = health.borrow_limit.checked_add(borrow_limit)?;
+            health.liquidation_limit = health.liquidation_limit.checked_add(liquidation_limit)?;
+        }
+
+        if position.borrow_shares > 0 {
+            let debt_value = usd_value(
+                bank.borrow_amount(position.borrow_shares, Rounding::Up)?,
+                bank.decimals,
+                &upper_bound_price(&price),
+                Rounding::Up,
+            )?;
+
+            health.debt_value = health.debt_value.checked_add(debt_value)?;
+        }
+    }
+
+    Ok(health)
+}
+
+/// Takes the accounts up to `bank`, the bank of the next position of `user`, and returns it.
+///
+/// Clients build the accounts from the positions before the instruction, while a withdrawal of a whole deposit closes its position before the health is computed.
+/// So the accounts of a bank the user no longer has a position in are skipped, along with its oracles.
+fn next_position_bank<'a, 'info>(
+    accounts: &mut std::slice::Iter<'a, AccountInfo<'info>>,
+    user: &User,
+    bank: &Pubkey,
+) -> Result<&'a AccountInfo<'info>> {
+    loop {
+        let bank_account = accounts.next().ok_or(ErrorCode::InvalidHealthAccounts)?;
+        if bank_account.key() == *bank {
+            return Ok(bank_account);
+        }
+
+        if bank_account.owner != &crate::ID || user.position(&bank_account.key()).is_some() {
+            return Err(ErrorCode::InvalidHealthAccounts.into());
+        }
+
+        let closed_bank = Bank::try_deserialize(&mut &bank_account.try_borrow_data()?[..])?;
+        let oracles = if closed_bank.secondary_oracle.is_some() {
+            2
+        } else {
+            1
+        };
+
+        for _ in 0..oracles {
+            accounts.next().ok_or(ErrorCode::InvalidHealthAccounts)?;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::{OracleConfig, Position};
+    use crate::test_utils::{account, program_account};
+
+    fn bank(secondary_oracle: Option<OracleConfig>) -> AccountInfo<'static> {
+        program_account(
+            Pubkey::new_unique(),
+            &Bank {
+                secondary_oracle,
+                ..Default::default()
+            },
+        )
+    }
+
+    fn oracle() -> AccountInfo<'static> {
+        account(Pubkey::new_unique(), Pubkey::new_unique(), vec![])
+    }
+
+    fn user(banks: &[&AccountInfo]) -> User {
+        User {
+            owner: Pubkey::new_unique(),
+            market: Pubkey::new_unique(),
+            positions: banks
+                .iter()
+                .map(|bank| Position {
+                    bank: bank.key(),
+                    deposit_shares: 1,
+                    borrow_shares: 1,
+                })
+                .collect(),
+            last_updated: 0,
+        }
+    }
+
+    #[test]
+    fn the_accounts_of_closed_positions_are_skipped() {
+        let (first, closed, last) = (bank(None), bank(Some(OracleConfig::default())), bank(None));
+        let accounts = [
+            first.clone(),
+            oracle(),
+            closed.clone(),
+            oracle(),
+            oracle(),
+            last.clone(),
+            oracle(),
+        ];
+        let user = user(&[&first, &last]);
+
+        let mut accounts = accounts.iter();
+        for position in &user.positions {
+            let bank_account = next_position_bank(&mut accounts, &user, &position.bank).unwrap();
+            assert_eq!(bank_account.key(), position.bank);
+            accounts.next().unwrap();
+        }
+        assert!(accounts.next().is_none());
+    }
+
+    #[test]
+    fn the_banks_of_open_positions_have_to_be_in_order() {
+        let (first, last) = (bank(None), bank(None));
+        let accounts = [last.clone(), oracle(), first.clone(), oracle()];
+        let user = user(&[&first, &last]);
+
+        assert_eq!(
+            next_position_bank(&mut accounts.iter(), &user, &first.key()).unwrap_err(),
+            ErrorCode::InvalidHealthAccounts.into()
+        );
+    }
+}
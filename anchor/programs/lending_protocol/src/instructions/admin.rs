@@ -3,13 +3,52 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
-use crate::state::{Bank, User};
+use crate::constants::{
+    DEFAULT_BASE_RATE, DEFAULT_CONFIG_TIMELOCK, DEFAULT_INSURANCE_FACTOR,
+    DEFAULT_LIQUIDATION_BONUS_FLOOR_HEALTH, DEFAULT_LIQUIDATION_CLOSE_FACTOR,
+    DEFAULT_LIQUIDATION_INSURANCE_FEE, DEFAULT_MAX_LIQUIDATION_BONUS, DEFAULT_MAX_UTILIZATION,
+    DEFAULT_MIN_LIQUIDATION_BONUS, DEFAULT_OPTIMAL_UTILIZATION, DEFAULT_RESERVE_FACTOR,
+    DEFAULT_SLOPE_1, DEFAULT_SLOPE_2,
+};
+use crate::math::Rate;
+use crate::oracle::validate_oracle_config;
+use crate::state::{Bank, LendingMarket, OracleConfig, User};
+
+/// Define the struct needed for our context to create the instruction for intializing a lending market
+#[derive(Accounts)]
+pub struct InitializeMarket<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The admin of the new market, e.g. the payer itself or a `Multisig`. It doesn't have to sign since it is only given rights here.
+    ///
+    /// CHECK: only its address is stored
+    pub admin: UncheckedAccount<'info>,
+
+    /// Initialize the market account. It's a regular account rather than a PDA, so an admin can create as many markets as they want.
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + LendingMarket::INIT_SPACE,
+    )]
+    pub market: Account<'info, LendingMarket>,
+
+    pub system_program: Program<'info, System>,
+}
 
 /// Define the struct needed for our context to create the instruction for intializing a bank
 #[derive(Accounts)]
 pub struct InitializeBank<'info> {
     #[account(mut)]
-    pub signer: Signer<'info>,
+    pub payer: Signer<'info>,
+
+    /// Only the admin of the market can list a new bank, otherwise whoever gets there first would control the bank of an asset
+    ///
+    /// CHECK: either a key that signs or a `Multisig`, checked by `LendingMarket::check_admin`
+    pub admin: UncheckedAccount<'info>,
+
+    /// The lending market the bank belongs to
+    pub market: Account<'info, LendingMarket>,
 
     /// The mint address of asset of the bank
     pub mint: InterfaceAccount<'info, Mint>,
@@ -17,12 +56,12 @@ pub struct InitializeBank<'info> {
     /// Initialize the bank account
     #[account(
         init,
-        payer = signer,
+        payer = payer,
         // 8 is the space for the account discriminator. Discriminator is used to identify the account type.
         space = 8 + Bank::INIT_SPACE,
         // Since we are going to make the bank a PDA, we need to pass the seeds to the instruction
-        // We will pass the mint address as the seed since every bank will have a unique mint address for the asset
-        seeds = [mint.key().as_ref()],
+        // We pass the market and the mint address as the seeds since a market has a single bank per asset, while the same asset can be listed in several markets
+        seeds = [market.key().as_ref(), mint.key().as_ref()],
         // Bump is used to create a unique address for the bank account
         bump,
     )]
@@ -37,13 +76,27 @@ pub struct InitializeBank<'info> {
         token::mint = mint,
         // Setting the authority to this account itself 
         token::authority = bank_token_account,
-        payer = signer,
+        payer = payer,
 
-        seeds = [b"Treasury", mint.key().as_ref()],
+        seeds = [b"Treasury", market.key().as_ref(), mint.key().as_ref()],
         bump,
     )]
     pub bank_token_account: InterfaceAccount<'info, TokenAccount>,
 
+    /// Insurance fund of the bank, a backstop that absorbs bad debt before the depositors do
+    ///
+    /// Same as the bank token account, it's a PDA token account that is its own authority.
+    #[account(
+        init,
+        token::mint = mint,
+        token::authority = insurance_token_account,
+        payer = payer,
+
+        seeds = [b"Insurance", market.key().as_ref(), mint.key().as_ref()],
+        bump,
+    )]
+    pub insurance_token_account: InterfaceAccount<'info, TokenAccount>,
+
     /// Token program to create the token account.
     ///
     /// When you use Interface<'info, TokenInterface>, Anchor automatically determines which token program to use based on the accounts passed in
@@ -59,12 +112,15 @@ pub struct InitializeAccount<'info> {
     #[account(mut)]
     pub signer: Signer<'info>,
 
+    /// The lending market the user account is opened in
+    pub market: Account<'info, LendingMarket>,
+
     /// Initialize the user account
     #[account(
         init,
         payer = signer,
         space = 8 + User::INIT_SPACE,
-        seeds = [signer.key().as_ref()],
+        seeds = [market.key().as_ref(), signer.key().as_ref()],
         bump,
     )]
     pub user_account: Account<'info, User>,
@@ -72,6 +128,16 @@ pub struct InitializeAccount<'info> {
     pub system_program: Program<'info, System>,
 }
 
+/// Instruction to initialize a lending market
+pub fn process_initialize_market(ctx: Context<InitializeMarket>, name: [u8; 32]) -> Result<()> {
+    let market = &mut ctx.accounts.market;
+
+    market.admin = ctx.accounts.admin.key();
+    market.name = name;
+
+    Ok(())
+}
+
 /// Instruction to initialize the bank
 ///
 /// Initialization happened in the stuct so here is - saving the infromation we need to the account state of the bank.
@@ -79,20 +145,51 @@ pub fn process_initialize_bank(
     ctx: Context<InitializeBank>,
     liquidation_threshold: u64,
     max_ltv: u64,
+    oracle: OracleConfig,
 ) -> Result<()> {
+    ctx.accounts
+        .market
+        .check_admin(&ctx.accounts.admin, ctx.remaining_accounts)?;
+
+    validate_oracle_config(&oracle)?;
+
     // Getting the mutable reference to the bank account
     let bank = &mut ctx.accounts.bank;
 
-    // Setting the mint address to the bank account
+    // Setting the market and the mint address to the bank account
+    bank.market = ctx.accounts.market.key();
     bank.mint_address = ctx.accounts.mint.key();
+    bank.decimals = ctx.accounts.mint.decimals;
 
-    // Setting the authority of the bank to the current signer - so only the admin can initialize the bank.
-    bank.authority = ctx.accounts.signer.key();
+    // Setting the authority of the bank to the admin of the market. It can be handed over to someone else with `propose_bank_authority`.
+    bank.authority = ctx.accounts.admin.key();
 
     bank.liquidation_threshold = liquidation_threshold;
     bank.max_ltv = max_ltv;
-
-    bank.interest_rate = 0.05 as u64;
+    bank.oracle = oracle;
+    bank.liquidation_close_factor = DEFAULT_LIQUIDATION_CLOSE_FACTOR;
+    bank.min_liquidation_bonus = DEFAULT_MIN_LIQUIDATION_BONUS;
+    bank.max_liquidation_bonus = DEFAULT_MAX_LIQUIDATION_BONUS;
+    bank.liquidation_bonus_floor_health = DEFAULT_LIQUIDATION_BONUS_FLOOR_HEALTH;
+
+    // Every bank starts with the default interest rate model
+    bank.base_rate = DEFAULT_BASE_RATE;
+    bank.optimal_utilization = DEFAULT_OPTIMAL_UTILIZATION;
+    bank.slope_1 = DEFAULT_SLOPE_1;
+    bank.slope_2 = DEFAULT_SLOPE_2;
+    bank.max_utilization = DEFAULT_MAX_UTILIZATION;
+    bank.reserve_factor = DEFAULT_RESERVE_FACTOR;
+    bank.insurance_factor = DEFAULT_INSURANCE_FACTOR;
+    bank.liquidation_insurance_fee = DEFAULT_LIQUIDATION_INSURANCE_FEE;
+    bank.config_timelock = DEFAULT_CONFIG_TIMELOCK;
+
+    // The threshold and LTV chosen by the caller have to be consistent with the defaults
+    bank.validate_config()?;
+
+    // Shares are worth exactly one token until interest starts accruing
+    bank.cumulative_borrow_index = Rate::ONE.0;
+    bank.supply_index = Rate::ONE.0;
+    bank.last_updated = Clock::get()?.unix_timestamp;
 
     Ok(())
 }
@@ -104,6 +201,7 @@ pub fn process_initialize_account(ctx: Context<InitializeAccount>) -> Result<()>
     let user = &mut ctx.accounts.user_account;
 
     user.owner = ctx.accounts.signer.key();
+    user.market = ctx.accounts.market.key();
 
     Ok(())
 }
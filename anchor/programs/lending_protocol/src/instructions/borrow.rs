@@ -1,18 +1,17 @@
 //! Handles the borrow instruction that will be used to cal. the quantity of assests that a user can borrow against their collateral.
 
-use std::f64::consts::E;
-
 use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
     token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked},
 };
-use pyth_solana_receiver_sdk::price_update::{get_feed_id_from_hex, PriceUpdateV2};
 
 use crate::{
-    constants::{MAX_AGE, SOL_USD_FEED_ID, USDC_USD_FEED_ID},
+    constants::PAUSE_BORROW,
     errors::ErrorCode,
-    state::{Bank, TokenType, User},
+    health::compute_health,
+    math::Rounding,
+    state::{Bank, LendingMarket, User},
 };
 
 /// Define the struct needed for our context to create the instruction for borrowing assets
@@ -22,13 +21,16 @@ pub struct Borrow<'info> {
     #[account(mut)]
     pub signer: Signer<'info>,
 
+    /// The lending market the bank belongs to
+    pub market: Account<'info, LendingMarket>,
+
     /// The mint address of the asset to be borrowed
     pub mint: InterfaceAccount<'info, Mint>,
 
     /// The bank account of the mint that the user wants to borrow
     #[account(
         mut,
-        seeds = [mint.key().as_ref()],
+        seeds = [market.key().as_ref(), mint.key().as_ref()],
         bump,
     )]
     pub bank: Account<'info, Bank>,
@@ -36,7 +38,7 @@ pub struct Borrow<'info> {
     /// The bank token account of the mint that the user wants to borrow
     #[account(
         mut,
-        seeds = [b"Treasury", mint.key().as_ref()],
+        seeds = [b"Treasury", market.key().as_ref(), mint.key().as_ref()],
         bump,
     )]
     pub bank_token_account: InterfaceAccount<'info, TokenAccount>,
@@ -44,7 +46,7 @@ pub struct Borrow<'info> {
     /// The user account that stores the state of the user
     #[account(
         mut,
-        seeds = [signer.key().as_ref()],
+        seeds = [market.key().as_ref(), signer.key().as_ref()],
         bump,
     )]
     pub user_account: Account<'info, User>,
@@ -61,9 +63,6 @@ pub struct Borrow<'info> {
     )]
     pub user_token_account: InterfaceAccount<'info, TokenAccount>,
 
-    /// Since the user will be borrowing a different asset than the one they deposited - we need to calculate the price corresponding to the asset they deposited using pyth-solana-receiver-sdk
-    pub price_update: Account<'info, PriceUpdateV2>,
-
     /// Associated token program because it's referenced in the instruction
     pub associated_token_program: Program<'info, AssociatedToken>,
 
@@ -77,56 +76,48 @@ pub struct Borrow<'info> {
 /// Instruction to process the borrow.
 ///
 /// Before processing the borrow, we need to check if the user has deposited enough collateral to be able to borrow the desired amount.
-pub fn process_borrow(
-    ctx: Context<Borrow>,
-    amount_to_borrow: u64,
-    token_type: TokenType,
-) -> Result<()> {
+/// The collateral and debt of every position count, so the remaining accounts must hold the banks and oracles of all the positions of the user once the borrow is added, see `compute_health`.
+pub fn process_borrow(ctx: Context<Borrow>, amount_to_borrow: u64) -> Result<()> {
+    ctx.accounts
+        .bank
+        .check_not_paused(&ctx.accounts.market, PAUSE_BORROW)?;
+
     let bank_account = &mut ctx.accounts.bank;
     let user_account = &mut ctx.accounts.user_account;
 
-    let price_update = &mut ctx.accounts.price_update;
-
-    // Cal. the total collateral a user holds
-    let total_collateral: u64 = match token_type {
-        TokenType::USDC => {
-            // If the user is passing USDC as key, means that they have their collateral in SOL
-            let sol_feed_id = get_feed_id_from_hex(SOL_USD_FEED_ID)?;
-            let sol_price =
-                price_update.get_price_no_older_than(&Clock::get()?, MAX_AGE, &sol_feed_id)?;
-
-            let new_value = calculate_accrued_interest(
-                user_account.deposited_sol,
-                bank_account.interest_rate,
-                user_account.last_updated,
-            )?;
-
-            sol_price.price as u64 * new_value
-        }
-        TokenType::SOL => {
-            let usdc_feed_id = get_feed_id_from_hex(USDC_USD_FEED_ID)?;
-            let usdc_price =
-                price_update.get_price_no_older_than(&Clock::get()?, MAX_AGE, &usdc_feed_id)?;
-
-            let new_value = calculate_accrued_interest(
-                user_account.deposited_usdc,
-                bank_account.interest_rate,
-                user_account.last_updated,
-            )?;
-
-            usdc_price.price as u64 * new_value
-        }
-    };
+    // Accrue the interest so the new debt is priced up to this second
+    bank_account.accrue_interest(Clock::get()?.unix_timestamp)?;
+
+    // Fail with a clear error rather than in the token program when the bank can't pay out the borrow
+    if amount_to_borrow > bank_account.available_liquidity(ctx.accounts.bank_token_account.amount) {
+        return Err(ErrorCode::InsufficientLiquidity.into());
+    }
+
+    // Debt shares are rounded up so that a borrow can never owe less than it took
+    let user_shares = bank_account.borrow_shares(amount_to_borrow, Rounding::Up)?;
+
+    // Update the state of the user and bank to reflect this borrow. Nothing is persisted if the health check below fails.
+    user_account
+        .position_mut(&bank_account.key())?
+        .borrow_shares += user_shares;
+
+    bank_account.total_borrows += amount_to_borrow;
+    bank_account.total_borrows_shares += user_shares;
+
+    bank_account.check_borrow_cap()?;
+    bank_account.check_max_utilization()?;
+
+    user_account.last_updated = Clock::get()?.unix_timestamp;
 
-    // Calculate the borrowable amount that a user can borrow against their collateral
-    let borrowable_amount = total_collateral
-        .checked_mul(bank_account.liquidation_threshold)
-        .unwrap_or(0);
+    // The new borrow is added to everything the user already owes and has to stay within the borrow limit of all their collateral.
+    // Borrowing adds risk, so it is refused while the oracles of any of the banks disagree.
+    let health = compute_health(user_account, ctx.remaining_accounts, true)?;
 
     // Check if the requested amount to borrow is greater than the borrowable amount
-    if amount_to_borrow > borrowable_amount {
+    if !health.is_within_borrow_limit() {
         return Err(ErrorCode::OverBorrowableAmount.into());
     }
+
     // Process the CPI instruction to transfer the requested amount to borrow from the bank token account to the user token account
     let transfer_cpi_accounts = TransferChecked {
         from: ctx.accounts.bank_token_account.to_account_info(),
@@ -141,6 +132,7 @@ pub fn process_borrow(
     // Same as withdraw instruction - since we are using the same PDA from the same token account
     let signer_seeds: &[&[&[u8]]] = &[&[
         b"Treasury",
+        ctx.accounts.market.to_account_info().key.as_ref(),
         ctx.accounts.mint.to_account_info().key.as_ref(),
         &[ctx.bumps.bank_token_account],
     ]];
@@ -152,51 +144,5 @@ pub fn process_borrow(
     // Perform the transfer
     transfer_checked(cpi_ctx, amount_to_borrow, decimals)?;
 
-    // Update the state of the user and bank to reflect this borrow
-
-    // Perform the check to ensure total borrows non 0
-    if bank_account.total_borrows == 0 {
-        bank_account.total_borrows_shares = amount_to_borrow;
-        bank_account.total_borrows = amount_to_borrow;
-    }
-
-    let borrow_ratio = amount_to_borrow
-        .checked_div(bank_account.total_borrows)
-        .unwrap();
-    let user_shares = bank_account
-        .total_borrows_shares
-        .checked_mul(borrow_ratio)
-        .unwrap();
-
-    match token_type {
-        TokenType::USDC => {
-            user_account.borrowed_usdc += amount_to_borrow;
-            user_account.borrowed_usdc_shares += user_shares;
-        }
-        TokenType::SOL => {
-            user_account.borrowed_sol += amount_to_borrow;
-            user_account.borrowed_sol_shares += user_shares;
-        }
-    }
-
-    bank_account.total_borrows += amount_to_borrow;
-    bank_account.total_borrows_shares += user_shares;
-
-    user_account.last_updated = Clock::get()?.unix_timestamp;
-
     Ok(())
 }
-
-pub fn calculate_accrued_interest(
-    deposited: u64,
-    interest_rate: u64,
-    last_updated: i64,
-) -> Result<u64> {
-    let current_time = Clock::get()?.unix_timestamp;
-    let time_diff = current_time - last_updated;
-
-    let new_value =
-        (deposited as f64 * E.powf(interest_rate as f64 * time_diff as f64) as f64) as u64;
-
-    Ok(new_value)
-}
@@ -0,0 +1,97 @@
+//! Handles the collection of the protocol fees accrued by a bank
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::authority::check_authority;
+use crate::errors::ErrorCode;
+use crate::state::{Bank, LendingMarket};
+
+/// Define the struct needed for our context to create the instruction for collecting the protocol fees
+#[derive(Accounts)]
+pub struct CollectProtocolFees<'info> {
+    /// Only the authority of the bank can collect its fees
+    ///
+    /// CHECK: either a key that signs or a `Multisig`, checked by `check_authority`
+    pub authority: UncheckedAccount<'info>,
+
+    /// The lending market the bank belongs to
+    pub market: Account<'info, LendingMarket>,
+
+    /// The mint address of the asset of the bank
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// The bank account the fees accrued on
+    #[account(
+        mut,
+        seeds = [market.key().as_ref(), mint.key().as_ref()],
+        bump,
+        has_one = authority @ ErrorCode::Unauthorized,
+    )]
+    pub bank: Account<'info, Bank>,
+
+    /// The bank token account holding the fees alongside the depositors' liquidity
+    #[account(
+        mut,
+        seeds = [b"Treasury", market.key().as_ref(), mint.key().as_ref()],
+        bump,
+    )]
+    pub bank_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Token account the fees are sent to. Can be owned by anyone chosen by the authority, e.g. a multisig.
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    pub fee_receiver: InterfaceAccount<'info, TokenAccount>,
+
+    /// Token program to transfer the fees
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Instruction to collect the protocol fees.
+///
+/// Interest is accrued first so that the fees are up to date, then only the part of the fees that is actually backed by tokens in the bank token account is transferred.
+pub fn process_collect_protocol_fees(ctx: Context<CollectProtocolFees>) -> Result<()> {
+    check_authority(&ctx.accounts.authority, ctx.remaining_accounts)?;
+
+    let bank = &mut ctx.accounts.bank;
+
+    bank.accrue_interest(Clock::get()?.unix_timestamp)?;
+
+    let amount_to_collect = bank.collectable_protocol_fees(ctx.accounts.bank_token_account.amount);
+
+    if amount_to_collect == 0 {
+        return Ok(());
+    }
+
+    bank.accumulated_protocol_fees -= amount_to_collect;
+
+    let transfer_cpi_accounts = TransferChecked {
+        from: ctx.accounts.bank_token_account.to_account_info(),
+        to: ctx.accounts.fee_receiver.to_account_info(),
+        authority: ctx.accounts.bank_token_account.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+    };
+
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+
+    // Since we are signing with the bank token account which is a PDA - we hv to define the signer seeds for this CPI to process
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        b"Treasury",
+        ctx.accounts.market.to_account_info().key.as_ref(),
+        ctx.accounts.mint.to_account_info().key.as_ref(),
+        &[ctx.bumps.bank_token_account],
+    ]];
+
+    let cpi_ctx = CpiContext::new(cpi_program, transfer_cpi_accounts).with_signer(signer_seeds);
+
+    let decimals = ctx.accounts.mint.decimals;
+
+    transfer_checked(cpi_ctx, amount_to_collect, decimals)?;
+
+    Ok(())
+}
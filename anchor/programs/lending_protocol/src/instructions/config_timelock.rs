@@ -0,0 +1,190 @@
+//! Handles timelocked changes to the risk parameters, interest rate model and oracles of a bank.
+//!
+//! Changing e.g. `max_ltv` or `liquidation_threshold` instantly could make healthy users liquidatable in the same slot, and so could moving the bank to another price feed.
+//! Instead the authority queues a change, which can only be executed once the `config_timelock` of the bank has passed, leaving users time to adjust their positions.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::authority::check_authority;
+use crate::constants::MAX_PENDING_CONFIG_CHANGES;
+use crate::errors::ErrorCode;
+use crate::state::{Bank, BankConfigUpdate, ConfigQueue, LendingMarket, PendingConfigChange};
+
+/// Define the struct needed for our context to create the instruction for queuing a configuration change
+#[derive(Accounts)]
+pub struct QueueConfigChange<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Only the authority of the bank can change its configuration
+    ///
+    /// CHECK: either a key that signs or a `Multisig`, checked by `check_authority`
+    pub authority: UncheckedAccount<'info>,
+
+    /// The lending market the bank belongs to
+    pub market: Account<'info, LendingMarket>,
+
+    /// The mint address of the asset of the bank
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// The bank account the change is for
+    #[account(
+        seeds = [market.key().as_ref(), mint.key().as_ref()],
+        bump,
+        has_one = authority @ ErrorCode::Unauthorized,
+    )]
+    pub bank: Account<'info, Bank>,
+
+    /// The queue of pending changes of the bank, created with the first change
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + ConfigQueue::INIT_SPACE,
+        seeds = [b"ConfigQueue", bank.key().as_ref()],
+        bump,
+    )]
+    pub config_queue: Account<'info, ConfigQueue>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Define the struct needed for our context to create the instruction for executing a queued configuration change
+///
+/// Anyone can execute a change once its timelock expired, it was already approved by the authority when it was queued.
+#[derive(Accounts)]
+pub struct ExecuteConfigChange<'info> {
+    /// The lending market the bank belongs to
+    pub market: Account<'info, LendingMarket>,
+
+    /// The mint address of the asset of the bank
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// The bank account to update
+    #[account(
+        mut,
+        seeds = [market.key().as_ref(), mint.key().as_ref()],
+        bump,
+    )]
+    pub bank: Account<'info, Bank>,
+
+    /// The queue of pending changes of the bank
+    #[account(
+        mut,
+        seeds = [b"ConfigQueue", bank.key().as_ref()],
+        bump,
+    )]
+    pub config_queue: Account<'info, ConfigQueue>,
+}
+
+/// Define the struct needed for our context to create the instruction for cancelling a queued configuration change
+#[derive(Accounts)]
+pub struct CancelConfigChange<'info> {
+    /// Only the authority of the bank can cancel its changes
+    ///
+    /// CHECK: either a key that signs or a `Multisig`, checked by `check_authority`
+    pub authority: UncheckedAccount<'info>,
+
+    /// The lending market the bank belongs to
+    pub market: Account<'info, LendingMarket>,
+
+    /// The mint address of the asset of the bank
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// The bank account the change is for
+    #[account(
+        seeds = [market.key().as_ref(), mint.key().as_ref()],
+        bump,
+        has_one = authority @ ErrorCode::Unauthorized,
+    )]
+    pub bank: Account<'info, Bank>,
+
+    /// The queue of pending changes of the bank
+    #[account(
+        mut,
+        seeds = [b"ConfigQueue", bank.key().as_ref()],
+        bump,
+    )]
+    pub config_queue: Account<'info, ConfigQueue>,
+}
+
+/// Instruction to queue a change to any of the risk parameters, the interest rate model or the oracles of a bank.
+///
+/// Oracle changes wait for the timelock too, up to `MAX_CONFIG_TIMELOCK`, there is no fast path for a deprecated or broken feed.
+/// Meanwhile a stale primary falls back to the secondary oracle if the bank has one, but any other failure of the primary blocks the instructions that price the bank, liquidations included.
+/// The admin or the guardian of the market can pause the bank with `set_bank_pause` until the change is executed, and a bank should keep a short timelock if its oracles may need replacing quickly.
+///
+/// The change is validated against the current configuration right away so that a mistake shows up now rather than when the timelock expires.
+/// It is validated again when executed, since other changes may have been executed in between.
+pub fn process_queue_config_change(
+    ctx: Context<QueueConfigChange>,
+    update: BankConfigUpdate,
+) -> Result<()> {
+    check_authority(&ctx.accounts.authority, ctx.remaining_accounts)?;
+
+    let bank = &ctx.accounts.bank;
+    let config_queue = &mut ctx.accounts.config_queue;
+
+    bank.clone().apply_config_update(&update)?;
+
+    if config_queue.changes.len() >= MAX_PENDING_CONFIG_CHANGES {
+        return Err(ErrorCode::ConfigQueueFull.into());
+    }
+
+    let eta = Clock::get()?
+        .unix_timestamp
+        .checked_add(bank.config_timelock as i64)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    let id = config_queue.next_id;
+
+    config_queue.bank = bank.key();
+    config_queue
+        .changes
+        .push(PendingConfigChange { id, eta, update });
+    config_queue.next_id = id.checked_add(1).ok_or(ErrorCode::MathOverflow)?;
+
+    Ok(())
+}
+
+/// Instruction to apply the queued change `id` to the bank once its timelock expired
+pub fn process_execute_config_change(ctx: Context<ExecuteConfigChange>, id: u64) -> Result<()> {
+    let bank = &mut ctx.accounts.bank;
+    let config_queue = &mut ctx.accounts.config_queue;
+
+    let current_time = Clock::get()?.unix_timestamp;
+
+    let index = config_queue
+        .changes
+        .iter()
+        .position(|change| change.id == id)
+        .ok_or(ErrorCode::ConfigChangeNotFound)?;
+
+    if current_time < config_queue.changes[index].eta {
+        return Err(ErrorCode::ConfigChangeNotReady.into());
+    }
+
+    let change = config_queue.changes.remove(index);
+
+    // The interest up to now is owed under the old rate model
+    bank.accrue_interest(current_time)?;
+
+    bank.apply_config_update(&change.update)
+}
+
+/// Instruction to drop the queued change `id` without applying it
+pub fn process_cancel_config_change(ctx: Context<CancelConfigChange>, id: u64) -> Result<()> {
+    check_authority(&ctx.accounts.authority, ctx.remaining_accounts)?;
+
+    let config_queue = &mut ctx.accounts.config_queue;
+
+    let index = config_queue
+        .changes
+        .iter()
+        .position(|change| change.id == id)
+        .ok_or(ErrorCode::ConfigChangeNotFound)?;
+
+    config_queue.changes.remove(index);
+
+    Ok(())
+}
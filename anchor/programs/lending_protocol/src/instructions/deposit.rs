@@ -6,8 +6,9 @@ use anchor_spl::{
     token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked},
 };
 
-use crate::errors::ErrorCode;
-use crate::state::{Bank, TokenType, User};
+use crate::constants::PAUSE_DEPOSIT;
+use crate::math::Rounding;
+use crate::state::{Bank, LendingMarket, User};
 
 /// Define all the accounts needed for the deposit instruction
 #[derive(Accounts)]
@@ -16,13 +17,16 @@ pub struct Deposit<'info> {
     #[account(mut)]
     pub signer: Signer<'info>,
 
+    /// The lending market the bank belongs to
+    pub market: Account<'info, LendingMarket>,
+
     /// The mint address of the asset to be deposited
     pub mint: InterfaceAccount<'info, Mint>,
 
     /// The bank account to deposit the asset to
     #[account(
         mut,
-        seeds = [mint.key().as_ref()],
+        seeds = [market.key().as_ref(), mint.key().as_ref()],
         bump,
     )]
     pub bank: Account<'info, Bank>,
@@ -30,7 +34,7 @@ pub struct Deposit<'info> {
     /// The bank token account to deposit the tokens
     #[account(
         mut,
-        seeds = [b"Treasury", mint.key().as_ref()],
+        seeds = [b"Treasury", market.key().as_ref(), mint.key().as_ref()],
         bump,
     )]
     pub bank_token_account: InterfaceAccount<'info, TokenAccount>,
@@ -38,7 +42,7 @@ pub struct Deposit<'info> {
     /// The user account that stores the state of the user
     #[account(
         mut,
-        seeds = [signer.key().as_ref()],
+        seeds = [market.key().as_ref(), signer.key().as_ref()],
         bump,
     )]
     pub user_account: Account<'info, User>,
@@ -67,13 +71,14 @@ pub struct Deposit<'info> {
 /// Logic to make a deposit into the protocol:
 /// 1. Make a CPI Transfer from the user's token account to the bank token account
 /// 2. Calculate the new shared added to the bank and to the user
-/// 3. Update the user's deposited amount and total collateral value
+/// 3. Update the user's deposited shares
 /// 4. Update the bank's total deposits and total deposits shares
-pub fn process_deposit(
-    ctx: Context<Deposit>,
-    amount_to_deposit: u64,
-    token_type: TokenType,
-) -> Result<()> {
+pub fn process_deposit(ctx: Context<Deposit>, amount_to_deposit: u64) -> Result<()> {
+    // Deposits can be paused by the guardian during an incident
+    ctx.accounts
+        .bank
+        .check_not_paused(&ctx.accounts.market, PAUSE_DEPOSIT)?;
+
     // CPI Transfer
     let transfer_cpi_accounts = TransferChecked {
         // From user's token account
@@ -104,42 +109,25 @@ pub fn process_deposit(
 
     // Update the state for both user account and bank account to reflect this transfer
 
-    // Load the bank account
+    // Load the bank account and bring its indices up to date before pricing the new shares
     let bank = &mut ctx.accounts.bank;
+    bank.accrue_interest(Clock::get()?.unix_timestamp)?;
 
-    // Prevent division by zero
-    if bank.total_deposits == 0 {
-        bank.total_deposits = amount_to_deposit;
-        bank.total_deposits_shares = amount_to_deposit;
-    }
-
-    // In order to prevent uderflow/overflow calculation errors for share calculations we will use checked math operations.
-    let deposit_ratio = amount_to_deposit
-        .checked_div(bank.total_deposits)
-        .unwrap_or(0);
-    let user_shares = bank
-        .total_deposits_shares
-        .checked_mul(deposit_ratio)
-        .unwrap_or(0);
+    // Shares are rounded down so that a deposit can never mint more than it is worth
+    let user_shares = bank.deposit_shares(amount_to_deposit, Rounding::Down)?;
 
     // Update the information for user state
     let user_account = &mut ctx.accounts.user_account;
 
-    // Since we are only using SOL and USDC we will use a simple match comparison to indentify and update the corresponding assests.
-    match token_type {
-        TokenType::USDC => {
-            user_account.deposited_usdc += amount_to_deposit;
-            user_account.deposited_usdc_shares += user_shares;
-        }
-        TokenType::SOL => {
-            user_account.deposited_sol += amount_to_deposit;
-            user_account.deposited_sol_shares += user_shares;
-        }
-    }
+    // The shares are credited to the user's position in this bank, which is opened on their first deposit
+    user_account.position_mut(&bank.key())?.deposit_shares += user_shares;
 
     bank.total_deposits += amount_to_deposit;
     bank.total_deposits_shares += user_shares;
 
+    // The cap applies to the deposits including the interest accrued above
+    bank.check_deposit_cap()?;
+
     user_account.last_updated = Clock::get()?.unix_timestamp;
 
     Ok(())
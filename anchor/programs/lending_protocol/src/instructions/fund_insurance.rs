@@ -0,0 +1,89 @@
+//! Handles moving the insurance fees accrued by a bank to its insurance fund
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::state::{Bank, LendingMarket};
+
+/// Define the struct needed for our context to create the instruction for funding the insurance fund
+///
+/// Anyone can fund the insurance fund, the fees can only ever go from the bank token account to the insurance fund of the same bank.
+#[derive(Accounts)]
+pub struct FundInsurance<'info> {
+    /// The lending market the bank belongs to
+    pub market: Account<'info, LendingMarket>,
+
+    /// The mint address of the asset of the bank
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// The bank account the insurance fees accrued on
+    #[account(
+        mut,
+        seeds = [market.key().as_ref(), mint.key().as_ref()],
+        bump,
+    )]
+    pub bank: Account<'info, Bank>,
+
+    /// The bank token account holding the fees alongside the depositors' liquidity
+    #[account(
+        mut,
+        seeds = [b"Treasury", market.key().as_ref(), mint.key().as_ref()],
+        bump,
+    )]
+    pub bank_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// The insurance fund of the bank
+    #[account(
+        mut,
+        seeds = [b"Insurance", market.key().as_ref(), mint.key().as_ref()],
+        bump,
+    )]
+    pub insurance_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Token program to transfer the fees
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Instruction to move the insurance fees to the insurance fund.
+///
+/// Works like collecting the protocol fees: interest is accrued first and only the fees actually backed by tokens in the bank token account are moved.
+pub fn process_fund_insurance(ctx: Context<FundInsurance>) -> Result<()> {
+    let bank = &mut ctx.accounts.bank;
+
+    bank.accrue_interest(Clock::get()?.unix_timestamp)?;
+
+    let amount_to_fund = bank.collectable_insurance_fees(ctx.accounts.bank_token_account.amount);
+
+    if amount_to_fund == 0 {
+        return Ok(());
+    }
+
+    bank.accumulated_insurance_fees -= amount_to_fund;
+
+    let transfer_cpi_accounts = TransferChecked {
+        from: ctx.accounts.bank_token_account.to_account_info(),
+        to: ctx.accounts.insurance_token_account.to_account_info(),
+        authority: ctx.accounts.bank_token_account.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+    };
+
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+
+    // Since we are signing with the bank token account which is a PDA - we hv to define the signer seeds for this CPI to process
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        b"Treasury",
+        ctx.accounts.market.to_account_info().key.as_ref(),
+        ctx.accounts.mint.to_account_info().key.as_ref(),
+        &[ctx.bumps.bank_token_account],
+    ]];
+
+    let cpi_ctx = CpiContext::new(cpi_program, transfer_cpi_accounts).with_signer(signer_seeds);
+
+    let decimals = ctx.accounts.mint.decimals;
+
+    transfer_checked(cpi_ctx, amount_to_fund, decimals)?;
+
+    Ok(())
+}
@@ -1,17 +1,18 @@
 //! Handles the liquidation of an account
 
-use crate::instructions::borrow::calculate_accrued_interest;
 use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
     token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked},
 };
-use pyth_solana_receiver_sdk::price_update::{get_feed_id_from_hex, PriceUpdateV2};
 
 use crate::{
-    constants::{MAX_AGE, SOL_USD_FEED_ID, USDC_USD_FEED_ID},
+    constants::PAUSE_LIQUIDATE,
     errors::ErrorCode,
-    state::{Bank, TokenType, User},
+    health::compute_health,
+    math::{Rounding, Wad},
+    oracle::{get_bank_price, token_amount, usd_value, OraclePrice},
+    state::{Bank, LendingMarket, User},
 };
 
 #[derive(Accounts)]
@@ -19,11 +20,33 @@ pub struct Liquidate<'info> {
     #[account(mut)]
     pub liquidator: Signer<'info>,
 
-    #[account(mut)]
-    pub price_update_account: Account<'info, PriceUpdateV2>,
+    /// Price of the collateral asset
+    ///
+    /// CHECK: Must be the price account of the oracle configured on the collateral bank, which is checked by the oracle adapter when the price is read.
+    pub collateral_oracle: UncheckedAccount<'info>,
+
+    /// Price of the borrowed asset
+    ///
+    /// CHECK: Must be the price account of the oracle configured on the borrowed bank, which is checked by the oracle adapter when the price is read.
+    pub borrowed_oracle: UncheckedAccount<'info>,
+
+    /// Secondary oracle of the collateral bank, only needed if it has one
+    ///
+    /// CHECK: Checked by the oracle adapter against the secondary oracle configured on the collateral bank.
+    pub collateral_secondary_oracle: Option<UncheckedAccount<'info>>,
+
+    /// Secondary oracle of the borrowed bank, only needed if it has one
+    ///
+    /// CHECK: Checked by the oracle adapter against the secondary oracle configured on the borrowed bank.
+    pub borrowed_secondary_oracle: Option<UncheckedAccount<'info>>,
+
+    /// The lending market both banks and the borrower belong to
+    pub market: Account<'info, LendingMarket>,
 
     /// Mints for the collateral and borrowed asset
     pub collateral_mint: InterfaceAccount<'info, Mint>,
+    /// Must be a different asset than the collateral, otherwise the same bank would be loaded and written twice
+    #[account(constraint = borrowed_mint.key() != collateral_mint.key() @ ErrorCode::SameCollateralAndBorrowedAsset)]
     pub borrowed_mint: InterfaceAccount<'info, Mint>,
 
     /// Defining borrowed and collateral bank account for there respective token accounts
@@ -31,7 +54,7 @@ pub struct Liquidate<'info> {
     /// Collateral bank account
     #[account(
         mut,
-        seeds = [collateral_mint.key().as_ref()],
+        seeds = [market.key().as_ref(), collateral_mint.key().as_ref()],
         bump,
     )]
     pub collateral_bank: Account<'info, Bank>,
@@ -39,15 +62,23 @@ pub struct Liquidate<'info> {
     /// Collateral Bank token account
     #[account(
         mut,
-        seeds = [b"Treasury", collateral_mint.key().as_ref()],
+        seeds = [b"Treasury", market.key().as_ref(), collateral_mint.key().as_ref()],
         bump,
     )]
     pub collateral_bank_token_account: InterfaceAccount<'info, TokenAccount>,
 
+    /// Insurance fund of the collateral bank, receives its share of the liquidation bonus
+    #[account(
+        mut,
+        seeds = [b"Insurance", market.key().as_ref(), collateral_mint.key().as_ref()],
+        bump,
+    )]
+    pub collateral_insurance_token_account: InterfaceAccount<'info, TokenAccount>,
+
     /// Borrowed bank account
     #[account(
         mut,
-        seeds = [borrowed_mint.key().as_ref()],
+        seeds = [market.key().as_ref(), borrowed_mint.key().as_ref()],
         bump,
     )]
     pub borrowed_bank: Account<'info, Bank>,
@@ -55,21 +86,25 @@ pub struct Liquidate<'info> {
     /// Borrowed Bank token account
     #[account(
         mut,
-        seeds = [b"Treasury", borrowed_mint.key().as_ref()],
+        seeds = [b"Treasury", market.key().as_ref(), borrowed_mint.key().as_ref()],
         bump,
     )]
     pub borrowed_bank_token_account: InterfaceAccount<'info, TokenAccount>,
 
-    /// User account for the liquidator
-    #[account(mut)]
-    pub liquidator_user_account: Account<'info, User>,
+    /// User account of the borrower being liquidated
+    #[account(
+        mut,
+        seeds = [market.key().as_ref(), borrower_user_account.owner.as_ref()],
+        bump,
+    )]
+    pub borrower_user_account: Account<'info, User>,
 
-    /// Liquidator's borrowed token account
+    /// Liquidator's borrowed token account, the debt of the borrower is repaid from it
     #[account(
-        init_if_needed,
-        payer = liquidator,
+        mut,
         associated_token::mint = borrowed_mint,
-        associated_token::authority = liquidator_user_account,
+        associated_token::authority = liquidator,
+        associated_token::token_program = token_program,
     )]
     pub liquidator_borrowed_token_account: InterfaceAccount<'info, TokenAccount>,
 
@@ -78,7 +113,8 @@ pub struct Liquidate<'info> {
         init_if_needed,
         payer = liquidator,
         associated_token::mint = collateral_mint,
-        associated_token::authority = liquidator_user_account,
+        associated_token::authority = liquidator,
+        associated_token::token_program = token_program,
     )]
     pub liquidator_collateral_token_account: InterfaceAccount<'info, TokenAccount>,
 
@@ -93,76 +129,109 @@ pub struct Liquidate<'info> {
 }
 
 /// Logic: a liquidator is able to come to a protocol and repay the debt of an unhealthy account, and in return they recieve the collateral + a liquidation bonus - a bonus of the liquidation amount that they receive to incentivize them to liquidate.
-pub fn process_liquidate(ctx: Context<Liquidate>, token_type: TokenType) -> Result<()> {
+///
+/// The repaid debt and the seized collateral are taken out of the borrower's positions, the liquidator only pays and receives tokens.
+///
+/// The liquidator repays up to `repay_amount` of the borrowed asset, capped by the close factor of the borrowed bank, and the liquidation fails if they would receive less than `min_collateral_out` of the collateral asset.
+pub fn process_liquidate(
+    ctx: Context<Liquidate>,
+    repay_amount: u64,
+    min_collateral_out: u64,
+) -> Result<()> {
+    // Liquidations are paused if either of the banks is paused
+    ctx.accounts
+        .collateral_bank
+        .check_not_paused(&ctx.accounts.market, PAUSE_LIQUIDATE)?;
+    ctx.accounts
+        .borrowed_bank
+        .check_not_paused(&ctx.accounts.market, PAUSE_LIQUIDATE)?;
+
     // Verifying that the account is indeed unhealthy to process the liquidation
 
     let collateral_bank = &mut ctx.accounts.collateral_bank;
-    let liquidator_user_account = &mut ctx.accounts.liquidator_user_account;
+    let borrower_user_account = &mut ctx.accounts.borrower_user_account;
     let liquidator_borrowed_token_account = &mut ctx.accounts.liquidator_borrowed_token_account;
     let borrowed_bank = &mut ctx.accounts.borrowed_bank;
     let liquidator_collateral_token_account = &mut ctx.accounts.liquidator_collateral_token_account;
 
-    let price_update_account = &mut ctx.accounts.price_update_account;
-
-    let sol_feed_id = get_feed_id_from_hex(SOL_USD_FEED_ID)?;
-    let sol_price =
-        price_update_account.get_price_no_older_than(&Clock::get()?, MAX_AGE, &sol_feed_id)?;
-
-    let usdc_feed_id = get_feed_id_from_hex(USDC_USD_FEED_ID)?;
-    let usdc_price =
-        price_update_account.get_price_no_older_than(&Clock::get()?, MAX_AGE, &usdc_feed_id)?;
-
-    let total_collateral_value: u64;
-    let total_borrowed_value: u64;
-
-    match token_type {
-        TokenType::USDC => {
-            let new_usdc_price = calculate_accrued_interest(
-                liquidator_user_account.deposited_usdc,
-                collateral_bank.interest_rate,
-                liquidator_user_account.last_updated,
-            )?;
-
-            total_collateral_value = new_usdc_price * usdc_price.price as u64;
-
-            let new_sol_price = calculate_accrued_interest(
-                liquidator_user_account.borrowed_sol,
-                borrowed_bank.interest_rate,
-                liquidator_user_account.last_updated,
-            )?;
-
-            total_borrowed_value = new_sol_price * sol_price.price as u64;
-        }
-        TokenType::SOL => {
-            let new_sol_price = calculate_accrued_interest(
-                liquidator_user_account.deposited_sol,
-                collateral_bank.interest_rate,
-                liquidator_user_account.last_updated,
-            )?;
-
-            total_collateral_value = new_sol_price * sol_price.price as u64;
-
-            let new_usdc_price = calculate_accrued_interest(
-                liquidator_user_account.borrowed_usdc,
-                borrowed_bank.interest_rate,
-                liquidator_user_account.last_updated,
-            )?;
-
-            total_borrowed_value = new_usdc_price * usdc_price.price as u64;
-        }
+    let clock = Clock::get()?;
+
+    // Each asset is priced with the oracles of its own bank.
+    // Liquidating reduces the risk of the protocol, so it keeps going with the primary price even if the oracles disagree.
+    let collateral_price = get_bank_price(
+        collateral_bank,
+        &ctx.accounts.collateral_oracle,
+        ctx.accounts.collateral_secondary_oracle.as_deref(),
+        &clock,
+    )?
+    .price;
+    let borrowed_price = get_bank_price(
+        borrowed_bank,
+        &ctx.accounts.borrowed_oracle,
+        ctx.accounts.borrowed_secondary_oracle.as_deref(),
+        &clock,
+    )?
+    .price;
+
+    // Accrue the interest on both banks so the collateral and the debt are priced up to this second
+    collateral_bank.accrue_interest(clock.unix_timestamp)?;
+    borrowed_bank.accrue_interest(clock.unix_timestamp)?;
+
+    // Cal. the health factor over every position of the account to ensure that it is unhealthy. If the HF < 1, then the account is unhealthy.
+    // The remaining accounts must hold the banks and oracles of all of its positions, see `compute_health`.
+    let health = compute_health(borrower_user_account, ctx.remaining_accounts, false)?;
+
+    if !health.is_liquidatable()? {
+        return Err(ErrorCode::AccountNotUnhealthy.into());
     }
 
-    // Cal. the health factor to ensure that the account is healthy. Is the HF < 1, then the account is unhealthy.
-    let health_factor = ((total_collateral_value as f64
-        * collateral_bank.liquidation_threshold as f64)
-        / total_borrowed_value as f64) as f64;
-
-    if health_factor >= 1.0 {
-        return Err(ErrorCode::AccountNotUnhealthy.into());
+    // An account can be unhealthy because of its debt in other banks, only a debt in the borrowed bank can be repaid here
+    let borrower_borrow_shares = borrower_user_account.borrow_shares(&borrowed_bank.key());
+    if borrower_borrow_shares == 0 {
+        return Err(ErrorCode::NoDebtToLiquidate.into());
     }
 
+    let borrowed_amount = borrowed_bank.borrow_amount(borrower_borrow_shares, Rounding::Up)?;
+
     // Now that we have confirmed that the account is indeed unhealthy, we can proceed to liquidate the account.
 
+    // Calculate the liquidation amount, in borrowed tokens, that the liquidator pays back to the bank.
+    // A single liquidation can repay at most `liquidation_close_factor` of the borrower's debt, so an account that is barely unhealthy is not wiped out at once.
+    let max_liquidation_amount = Wad::from_percent(borrowed_bank.liquidation_close_factor)?
+        .apply_to(borrowed_amount, Rounding::Down)?;
+    let liquidation_amount = repay_amount.min(max_liquidation_amount);
+
+    // Calculate the amount that the liquidator will receive from the liquidation, see `seize_amounts`.
+    // The bonus grows with how unhealthy the account is, see `Bank::liquidation_bonus`
+    let health_factor = health
+        .health_factor()?
+        .ok_or(ErrorCode::AccountNotUnhealthy)?;
+    let bonus_multiplier =
+        Wad::ONE.checked_add(collateral_bank.liquidation_bonus(health_factor)?)?;
+
+    let borrower_deposit_shares = borrower_user_account.deposit_shares(&collateral_bank.key());
+    let borrower_collateral = collateral_bank.deposit_amount(borrower_deposit_shares)?;
+
+    let SeizeAmounts {
+        liquidation_amount,
+        seized_amount,
+        insurance_amount,
+        liquidator_amount,
+    } = seize_amounts(
+        liquidation_amount,
+        borrowed_bank,
+        &borrowed_price,
+        collateral_bank,
+        &collateral_price,
+        borrower_collateral,
+        bonus_multiplier,
+    )?;
+
+    // Prices can move between the moment the liquidator builds the transaction and its execution, so they choose the least collateral they accept
+    if liquidator_amount < min_collateral_out {
+        return Err(ErrorCode::LiquidationSlippageExceeded.into());
+    }
+
     // STEP 1: The liquidator needs to pay back the borrowed amount to the bank
     let transfer_to_bank = TransferChecked {
         from: liquidator_borrowed_token_account.to_account_info(),
@@ -176,20 +245,11 @@ pub fn process_liquidate(ctx: Context<Liquidate>, token_type: TokenType) -> Resu
     let cpi_ctx = CpiContext::new(cpi_program, transfer_to_bank);
     let decimals = ctx.accounts.borrowed_mint.decimals;
 
-    // Calculate the liquidation amount that the liquidator needs to pay back to the bank in order to liquidate the account.
-    let liquidation_amount = total_borrowed_value
-        .checked_mul(borrowed_bank.liquidation_close_factor)
-        .unwrap();
-
     // Process the transfer to the bank
     transfer_checked(cpi_ctx, liquidation_amount, decimals)?;
 
     // STEP 2: Tranferring from the collateral account to the liquidator. It's going to pay back evrything the liquidator sent to repay the loan alongside an additional amount for liquidation bonus.
 
-    // Calculate the amount that the liquidator will receive from the liquidation.
-    let liquidator_amount =
-        (liquidation_amount * collateral_bank.liquidation_bonus) + liquidation_amount;
-
     // Process the transfer to the liquidator.
     let transfer_to_liquidator = TransferChecked {
         from: ctx.accounts.collateral_bank_token_account.to_account_info(),
@@ -201,6 +261,7 @@ pub fn process_liquidate(ctx: Context<Liquidate>, token_type: TokenType) -> Resu
     // Since we are tranferring from bank token account which is a PDA, we need to provide the seeds for the transfer.
     let signer_seeds: &[&[&[u8]]] = &[&[
         b"Treasury",
+        ctx.accounts.market.to_account_info().key.as_ref(),
         ctx.accounts.collateral_mint.to_account_info().key.as_ref(),
         &[ctx.bumps.collateral_bank_token_account],
     ]];
@@ -216,5 +277,329 @@ pub fn process_liquidate(ctx: Context<Liquidate>, token_type: TokenType) -> Resu
     // Process the transfer to the liquidator.
     transfer_checked(cpi_ctx_to_liquidator, liquidator_amount, decimals)?;
 
+    // Process the transfer of the insurance share of the bonus to the insurance fund
+    if insurance_amount > 0 {
+        let transfer_to_insurance = TransferChecked {
+            from: ctx.accounts.collateral_bank_token_account.to_account_info(),
+            to: ctx
+                .accounts
+                .collateral_insurance_token_account
+                .to_account_info(),
+            authority: ctx.accounts.collateral_bank_token_account.to_account_info(),
+            mint: ctx.accounts.collateral_mint.to_account_info(),
+        };
+
+        let cpi_ctx_to_insurance = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            transfer_to_insurance,
+        )
+        .with_signer(signer_seeds);
+
+        transfer_checked(cpi_ctx_to_insurance, insurance_amount, decimals)?;
+    }
+
+    // STEP 3: Update the state of the borrower and both banks to reflect this liquidation
+
+    let collateral_bank = &mut ctx.accounts.collateral_bank;
+    let borrowed_bank = &mut ctx.accounts.borrowed_bank;
+    let borrower_user_account = &mut ctx.accounts.borrower_user_account;
+
+    // Same rounding as a repay, the debt shares burnt are rounded down in favour of the bank
+    let repaid_shares = if liquidation_amount == borrowed_amount {
+        borrower_borrow_shares
+    } else {
+        borrowed_bank
+            .borrow_shares(liquidation_amount, Rounding::Down)?
+            .min(borrower_borrow_shares)
+    };
+
+    borrower_user_account
+        .position_mut(&borrowed_bank.key())?
+        .borrow_shares -= repaid_shares;
+
+    borrowed_bank.total_borrows = borrowed_bank
+        .total_borrows
+        .saturating_sub(liquidation_amount);
+    borrowed_bank.total_borrows_shares -= repaid_shares;
+
+    let seized_shares = seized_shares(
+        collateral_bank,
+        seized_amount,
+        borrower_collateral,
+        borrower_deposit_shares,
+    )?;
+
+    // Nothing is seized if the borrower has no deposit in the collateral bank, which must not open a position there
+    if let Some(position) = borrower_user_account
+        .positions
+        .iter_mut()
+        .find(|position| position.bank == collateral_bank.key())
+    {
+        position.deposit_shares -= seized_shares;
+    }
+
+    collateral_bank.total_deposits = collateral_bank.total_deposits.saturating_sub(seized_amount);
+    collateral_bank.total_deposits_shares -= seized_shares;
+
+    borrower_user_account.close_empty_positions();
+    borrower_user_account.last_updated = Clock::get()?.unix_timestamp;
+
     Ok(())
 }
+
+/// Deposit shares burnt for seizing `seized_amount` out of a deposit of `borrower_deposit_shares`, worth `borrower_collateral`.
+///
+/// Same rounding as a withdrawal, the shares are rounded up in favour of the bank.
+/// Seizing the whole deposit burns all of its shares, like a repay of the whole debt: with a supply index below 1 rounding would leave shares worth nothing.
+fn seized_shares(
+    collateral_bank: &Bank,
+    seized_amount: u64,
+    borrower_collateral: u64,
+    borrower_deposit_shares: u64,
+) -> Result<u64> {
+    if seized_amount == borrower_collateral {
+        return Ok(borrower_deposit_shares);
+    }
+
+    Ok(collateral_bank
+        .deposit_shares(seized_amount, Rounding::Up)?
+        .min(borrower_deposit_shares))
+}
+
+/// Tokens moved by a liquidation
+#[derive(Debug, PartialEq, Eq)]
+struct SeizeAmounts {
+    /// Borrowed tokens the liquidator pays back to the bank
+    liquidation_amount: u64,
+    /// Collateral tokens taken out of the borrower's deposit, bonus included
+    seized_amount: u64,
+    /// Part of the bonus that goes to the insurance fund of the collateral bank
+    insurance_amount: u64,
+    /// Collateral tokens the liquidator receives
+    liquidator_amount: u64,
+}
+
+/// Collateral seized for repaying `liquidation_amount` of the borrowed asset with a bonus of `bonus_multiplier - 1`.
+///
+/// The repaid debt is valued in USD, the bonus is added on top and the total is converted to collateral tokens.
+/// Both conversions use the mid prices so the liquidator gets exactly the bonus, whatever the confidence interval.
+/// Every rounding goes against the liquidator: the collateral they receive is rounded down and the debt they repay up.
+fn seize_amounts(
+    liquidation_amount: u64,
+    borrowed_bank: &Bank,
+    borrowed_price: &OraclePrice,
+    collateral_bank: &Bank,
+    collateral_price: &OraclePrice,
+    borrower_collateral: u64,
+    bonus_multiplier: Wad,
+) -> Result<SeizeAmounts> {
+    let mut liquidation_amount = liquidation_amount;
+
+    let repaid_value = usd_value(
+        liquidation_amount,
+        borrowed_bank.decimals,
+        borrowed_price,
+        Rounding::Down,
+    )?;
+    let seized_value = repaid_value.checked_mul(bonus_multiplier, Rounding::Down)?;
+    let mut seized_amount = token_amount(
+        seized_value,
+        collateral_bank.decimals,
+        collateral_price,
+        Rounding::Down,
+    )?;
+
+    // The liquidator can't receive more than the borrower deposited in the collateral bank.
+    // In that case they only repay the part of the debt that the remaining collateral covers, bonus included.
+    if seized_amount > borrower_collateral {
+        seized_amount = borrower_collateral;
+
+        let collateral_value = usd_value(
+            borrower_collateral,
+            collateral_bank.decimals,
+            collateral_price,
+            Rounding::Down,
+        )?;
+        liquidation_amount = token_amount(
+            collateral_value.checked_div(bonus_multiplier, Rounding::Up)?,
+            borrowed_bank.decimals,
+            borrowed_price,
+            Rounding::Up,
+        )?
+        .min(liquidation_amount);
+    }
+
+    // Part of the bonus is kept for the insurance fund of the collateral bank, the liquidator receives the rest
+    let bonus_amount =
+        seized_amount - bonus_multiplier.apply_inverse_to(seized_amount, Rounding::Up)?;
+    let insurance_amount = Wad::from_bps(collateral_bank.liquidation_insurance_fee)?
+        .apply_to(bonus_amount, Rounding::Down)?;
+
+    Ok(SeizeAmounts {
+        liquidation_amount,
+        seized_amount,
+        insurance_amount,
+        liquidator_amount: seized_amount - insurance_amount,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// USDC at $1, borrowed
+    const USDC_PRICE: OraclePrice = OraclePrice {
+        price: 100_000_000,
+        conf: 0,
+        exponent: -8,
+    };
+
+    /// SOL at $100, used as collateral
+    const SOL_PRICE: OraclePrice = OraclePrice {
+        price: 10_000_000_000,
+        conf: 0,
+        exponent: -8,
+    };
+
+    fn usdc_bank() -> Bank {
+        Bank {
+            decimals: 6,
+            ..Default::default()
+        }
+    }
+
+    /// SOL bank keeping 10% of the liquidation bonus for its insurance fund
+    fn sol_bank() -> Bank {
+        Bank {
+            decimals: 9,
+            liquidation_insurance_fee: 1_000,
+            ..Default::default()
+        }
+    }
+
+    /// 5% liquidation bonus
+    fn bonus_multiplier() -> Wad {
+        Wad::from_bps(10_500).unwrap()
+    }
+
+    #[test]
+    fn seizes_the_repaid_value_plus_the_bonus() {
+        // 100 USDC repaid at $1 with a 5% bonus = $105 = 1.05 SOL
+        let amounts = seize_amounts(
+            100_000_000,
+            &usdc_bank(),
+            &USDC_PRICE,
+            &sol_bank(),
+            &SOL_PRICE,
+            10_000_000_000,
+            bonus_multiplier(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            amounts,
+            SeizeAmounts {
+                liquidation_amount: 100_000_000,
+                seized_amount: 1_050_000_000,
+                // 10% of the 0.05 SOL bonus
+                insurance_amount: 5_000_000,
+                liquidator_amount: 1_045_000_000,
+            }
+        );
+    }
+
+    #[test]
+    fn repays_only_what_the_remaining_collateral_covers() {
+        // 0.525 SOL = $52.5 covers $50 of debt with the 5% bonus on top
+        let amounts = seize_amounts(
+            100_000_000,
+            &usdc_bank(),
+            &USDC_PRICE,
+            &sol_bank(),
+            &SOL_PRICE,
+            525_000_000,
+            bonus_multiplier(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            amounts,
+            SeizeAmounts {
+                liquidation_amount: 50_000_000,
+                seized_amount: 525_000_000,
+                insurance_amount: 2_500_000,
+                liquidator_amount: 522_500_000,
+            }
+        );
+    }
+
+    #[test]
+    fn rounds_against_the_liquidator() {
+        // 1 base unit of USDC with the bonus is worth 10.5 base units of SOL, the liquidator gets 10
+        let amounts = seize_amounts(
+            1,
+            &usdc_bank(),
+            &USDC_PRICE,
+            &sol_bank(),
+            &SOL_PRICE,
+            10_000_000_000,
+            bonus_multiplier(),
+        )
+        .unwrap();
+
+        assert_eq!(amounts.seized_amount, 10);
+        assert_eq!(amounts.liquidation_amount, 1);
+
+        // $0.0000001 of collateral covers 0.095238... base units of USDC with the bonus, which are repaid as 1
+        let amounts = seize_amounts(
+            100_000_000,
+            &usdc_bank(),
+            &USDC_PRICE,
+            &sol_bank(),
+            &SOL_PRICE,
+            1,
+            bonus_multiplier(),
+        )
+        .unwrap();
+
+        assert_eq!(amounts.seized_amount, 1);
+        assert_eq!(amounts.liquidation_amount, 1);
+    }
+
+    #[test]
+    fn the_insurance_fee_only_applies_to_the_bonus() {
+        let amounts = seize_amounts(
+            100_000_000,
+            &usdc_bank(),
+            &USDC_PRICE,
+            &sol_bank(),
+            &SOL_PRICE,
+            10_000_000_000,
+            Wad::ONE,
+        )
+        .unwrap();
+
+        assert_eq!(amounts.seized_amount, 1_000_000_000);
+        assert_eq!(amounts.insurance_amount, 0);
+        assert_eq!(amounts.liquidator_amount, 1_000_000_000);
+    }
+
+    #[test]
+    fn seizing_the_whole_deposit_burns_every_share() {
+        use crate::math::Rate;
+
+        // After a loss, 1 share is worth 0.75 tokens
+        let bank = Bank {
+            supply_index: Rate::ONE.0 * 3 / 4,
+            ..sol_bank()
+        };
+
+        // 5 shares are worth 3 tokens, which are only 4 shares
+        let borrower_collateral = bank.deposit_amount(5).unwrap();
+        assert_eq!(borrower_collateral, 3);
+        assert_eq!(bank.deposit_shares(3, Rounding::Up).unwrap(), 4);
+
+        assert_eq!(seized_shares(&bank, 3, borrower_collateral, 5).unwrap(), 5);
+        assert_eq!(seized_shares(&bank, 1, borrower_collateral, 5).unwrap(), 2);
+    }
+}
@@ -1,6 +1,16 @@
 pub mod admin;
 pub mod borrow;
+pub mod collect_protocol_fees;
+pub mod config_timelock;
 pub mod deposit;
+pub mod fund_insurance;
 pub mod liquidate;
+pub mod multisig;
+pub mod pause;
+pub mod refresh_bank;
 pub mod repay;
+pub mod socialize_bad_debt;
+pub mod transfer_bank_authority;
+pub mod update_bank_caps;
 pub mod withdraw;
+pub mod withdraw_insurance;
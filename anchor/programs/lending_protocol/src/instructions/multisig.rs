@@ -0,0 +1,67 @@
+//! Handles the multisig accounts that can administer markets and banks instead of a single key
+
+use anchor_lang::prelude::*;
+
+use crate::state::Multisig;
+
+/// Define the struct needed for our context to create the instruction for creating a multisig
+#[derive(Accounts)]
+pub struct CreateMultisig<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Any fresh key, only used to derive the address of the multisig. It signs so that nobody can front-run the creation of a multisig at the same address.
+    pub create_key: Signer<'info>,
+
+    /// Initialize the multisig account
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Multisig::INIT_SPACE,
+        seeds = [b"Multisig", create_key.key().as_ref()],
+        bump,
+    )]
+    pub multisig: Account<'info, Multisig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Define the struct needed for our context to create the instruction for changing the signers of a multisig
+///
+/// The current signers approve the change by signing, passed as remaining accounts.
+#[derive(Accounts)]
+pub struct SetMultisigSigners<'info> {
+    /// The multisig to update
+    #[account(mut)]
+    pub multisig: Account<'info, Multisig>,
+}
+
+/// Instruction to create a multisig that needs `threshold` of `signers` to approve an admin instruction
+pub fn process_create_multisig(
+    ctx: Context<CreateMultisig>,
+    signers: Vec<Pubkey>,
+    threshold: u8,
+) -> Result<()> {
+    let multisig = &mut ctx.accounts.multisig;
+
+    multisig.signers = signers;
+    multisig.threshold = threshold;
+
+    multisig.validate()
+}
+
+/// Instruction to replace the signers and threshold of a multisig, e.g. to rotate a lost key
+pub fn process_set_multisig_signers(
+    ctx: Context<SetMultisigSigners>,
+    signers: Vec<Pubkey>,
+    threshold: u8,
+) -> Result<()> {
+    let multisig = &mut ctx.accounts.multisig;
+
+    multisig.check_signatures(ctx.remaining_accounts)?;
+
+    multisig.signers = signers;
+    multisig.threshold = threshold;
+
+    multisig.validate()
+}
@@ -0,0 +1,178 @@
+//! Handles the emergency pause of a market or a single bank.
+//!
+//! The admin of the market (or the authority of a bank) can pause and unpause, while the guardian of the market can only pause.
+//! A leaked guardian key can then only stop the protocol, never force it to keep running during an incident.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::authority::check_authority;
+use crate::constants::PAUSE_ALL;
+use crate::errors::ErrorCode;
+use crate::state::{Bank, LendingMarket};
+
+/// Define the struct needed for our context to create the instruction for setting the guardian of a market
+#[derive(Accounts)]
+pub struct SetGuardian<'info> {
+    /// Only the admin of the market can choose its guardian
+    ///
+    /// CHECK: either a key that signs or a `Multisig`, checked by `LendingMarket::check_admin`
+    pub admin: UncheckedAccount<'info>,
+
+    /// The lending market to update
+    #[account(mut)]
+    pub market: Account<'info, LendingMarket>,
+}
+
+/// Define the struct needed for our context to create the instruction for pausing or unpausing a whole market
+#[derive(Accounts)]
+pub struct SetMarketPause<'info> {
+    /// Admin or guardian of the market, checked in the instruction since it depends on whether it pauses or unpauses
+    ///
+    /// CHECK: either a key that signs or a `Multisig`, checked by `check_authority`
+    pub signer: UncheckedAccount<'info>,
+
+    /// The lending market to pause or unpause
+    #[account(mut)]
+    pub market: Account<'info, LendingMarket>,
+}
+
+/// Define the struct needed for our context to create the instruction for pausing or unpausing a single bank
+#[derive(Accounts)]
+pub struct SetBankPause<'info> {
+    /// Authority of the bank or admin / guardian of its market, checked in the instruction since it depends on whether it pauses or unpauses
+    ///
+    /// CHECK: either a key that signs or a `Multisig`, checked by `check_authority`
+    pub signer: UncheckedAccount<'info>,
+
+    /// The lending market the bank belongs to
+    pub market: Account<'info, LendingMarket>,
+
+    /// The mint address of the asset of the bank
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// The bank account to pause or unpause
+    #[account(
+        mut,
+        seeds = [market.key().as_ref(), mint.key().as_ref()],
+        bump,
+    )]
+    pub bank: Account<'info, Bank>,
+}
+
+/// Instruction to set the guardian of a market, or remove it with `None`
+pub fn process_set_guardian(ctx: Context<SetGuardian>, guardian: Option<Pubkey>) -> Result<()> {
+    ctx.accounts
+        .market
+        .check_admin(&ctx.accounts.admin, ctx.remaining_accounts)?;
+
+    ctx.accounts.market.guardian = guardian;
+
+    Ok(())
+}
+
+/// Instruction to pause (`paused == true`) or unpause the `operations` of every bank of a market.
+///
+/// `operations` is a combination of the `PAUSE_*` flags, the other operations keep their current state.
+pub fn process_set_market_pause(
+    ctx: Context<SetMarketPause>,
+    operations: u8,
+    paused: bool,
+) -> Result<()> {
+    check_authority(&ctx.accounts.signer, ctx.remaining_accounts)?;
+
+    let market = &mut ctx.accounts.market;
+    let signer = ctx.accounts.signer.key();
+
+    check_pause_flags(operations)?;
+
+    if paused {
+        if !market.can_pause(&signer) {
+            return Err(ErrorCode::Unauthorized.into());
+        }
+
+        market.paused |= operations;
+    } else {
+        if market.admin != signer {
+            return Err(ErrorCode::NotMarketAdmin.into());
+        }
+
+        market.paused &= !operations;
+    }
+
+    Ok(())
+}
+
+/// Instruction to pause (`paused == true`) or unpause the `operations` of a single bank.
+///
+/// Operations paused for the whole market stay paused whatever the flags of the bank.
+pub fn process_set_bank_pause(
+    ctx: Context<SetBankPause>,
+    operations: u8,
+    paused: bool,
+) -> Result<()> {
+    check_authority(&ctx.accounts.signer, ctx.remaining_accounts)?;
+
+    let bank = &mut ctx.accounts.bank;
+    let signer = ctx.accounts.signer.key();
+
+    check_pause_flags(operations)?;
+
+    if paused {
+        if bank.authority != signer && !ctx.accounts.market.can_pause(&signer) {
+            return Err(ErrorCode::Unauthorized.into());
+        }
+
+        bank.paused |= operations;
+    } else {
+        if bank.authority != signer {
+            return Err(ErrorCode::Unauthorized.into());
+        }
+
+        bank.paused &= !operations;
+    }
+
+    Ok(())
+}
+
+fn check_pause_flags(operations: u8) -> Result<()> {
+    if operations & !PAUSE_ALL != 0 {
+        return Err(ErrorCode::InvalidPauseFlags.into());
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::*;
+
+    #[test]
+    fn only_the_admin_can_set_the_guardian() {
+        let (admin, someone_else) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let market = program_account(
+            Pubkey::new_unique(),
+            &LendingMarket {
+                admin,
+                name: [0; 32],
+                guardian: None,
+                paused: 0,
+            },
+        );
+
+        assert_error(
+            process::<SetGuardian>(vec![signer(someone_else), market.clone()], vec![], |ctx| {
+                process_set_guardian(ctx, Some(someone_else))
+            }),
+            ErrorCode::NotMarketAdmin,
+        );
+        assert_eq!(read::<LendingMarket>(&market).guardian, None);
+
+        process::<SetGuardian>(vec![signer(admin), market.clone()], vec![], |ctx| {
+            process_set_guardian(ctx, Some(someone_else))
+        })
+        .unwrap();
+        assert_eq!(read::<LendingMarket>(&market).guardian, Some(someone_else));
+    }
+}
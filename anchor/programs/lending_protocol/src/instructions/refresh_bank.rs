@@ -0,0 +1,35 @@
+//! Handles the accrual of interest on a bank
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::state::{Bank, LendingMarket};
+
+/// Define the struct needed for our context to create the instruction for refreshing a bank
+///
+/// Anyone can refresh a bank, there is nothing to gain from it other than keeping the indices up to date.
+#[derive(Accounts)]
+pub struct RefreshBank<'info> {
+    /// The lending market the bank belongs to
+    pub market: Account<'info, LendingMarket>,
+
+    /// The mint address of the asset of the bank
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// The bank account to accrue the interest on
+    #[account(
+        mut,
+        seeds = [market.key().as_ref(), mint.key().as_ref()],
+        bump,
+    )]
+    pub bank: Account<'info, Bank>,
+}
+
+/// Instruction to accrue the interest of a bank up to the current second.
+pub fn process_refresh_bank(ctx: Context<RefreshBank>) -> Result<()> {
+    let bank = &mut ctx.accounts.bank;
+
+    bank.accrue_interest(Clock::get()?.unix_timestamp)?;
+
+    Ok(())
+}
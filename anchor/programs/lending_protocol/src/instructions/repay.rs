@@ -1,27 +1,30 @@
 //! Handles the repayment of a borrowed asset
 
-use std::f64::consts::E;
-
 use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
     token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked},
 };
 
-use crate::state::{Bank, User};
-use crate::{errors::ErrorCode, state::TokenType};
+use crate::constants::PAUSE_REPAY;
+use crate::errors::ErrorCode;
+use crate::math::Rounding;
+use crate::state::{Bank, LendingMarket, User};
 
 #[derive(Accounts)]
 pub struct Repay<'info> {
     #[account(mut)]
     pub signer: Signer<'info>,
 
+    /// The lending market the bank belongs to
+    pub market: Account<'info, LendingMarket>,
+
     pub mint: InterfaceAccount<'info, Mint>,
 
     /// The bank account to deposit the asset to
     #[account(
         mut,
-        seeds = [mint.key().as_ref()],
+        seeds = [market.key().as_ref(), mint.key().as_ref()],
         bump,
     )]
     pub bank: Account<'info, Bank>,
@@ -29,7 +32,7 @@ pub struct Repay<'info> {
     /// The bank token account to deposit the tokens
     #[account(
         mut,
-        seeds = [b"Treasury", mint.key().as_ref()],
+        seeds = [b"Treasury", market.key().as_ref(), mint.key().as_ref()],
         bump,
     )]
     pub bank_token_account: InterfaceAccount<'info, TokenAccount>,
@@ -37,7 +40,7 @@ pub struct Repay<'info> {
     /// The user account that stores the state of the user
     #[account(
         mut,
-        seeds = [signer.key().as_ref()],
+        seeds = [market.key().as_ref(), signer.key().as_ref()],
         bump,
     )]
     pub user_account: Account<'info, User>,
@@ -69,39 +72,24 @@ pub struct Repay<'info> {
 /// Esentially we are making a CPI transfer from the user token account to the bank token account to repay the borrowed asset.
 ///
 /// We will also have to perfrom a basic check to ensure that the user doesn't repay more than they borrowed.
-pub fn process_repay(
-    ctx: Context<Repay>,
-    amount_to_repay: u64,
-    token_type: TokenType,
-) -> Result<()> {
+pub fn process_repay(ctx: Context<Repay>, amount_to_repay: u64) -> Result<()> {
+    ctx.accounts
+        .bank
+        .check_not_paused(&ctx.accounts.market, PAUSE_REPAY)?;
+
     let bank_account = &mut ctx.accounts.bank;
     let user_account = &mut ctx.accounts.user_account;
 
-    let borrowed_tokens: u64;
-    match token_type {
-        TokenType::USDC => borrowed_tokens = user_account.borrowed_usdc,
-        TokenType::SOL => borrowed_tokens = user_account.borrowed_sol,
-    }
-
-    // Cal. the interest to be paid alongside the borrowed amount since the last time the user borrowed
-
-    // Get the current time diffrance between the last time the user borrowed and the current time
-    let time_diff = Clock::get()?.unix_timestamp - user_account.last_updated;
+    // Accrue the interest owed so far before pricing the user's debt
+    bank_account.accrue_interest(Clock::get()?.unix_timestamp)?;
 
-    // Cal. the total borrow amount after the interest has been applied, taking into the consideration the APY on the bank.
-    bank_account.total_borrows = (bank_account.total_borrows as f64
-        * E.powf(time_diff as f64 * bank_account.interest_rate as f64))
-        as u64;
+    let user_shares = user_account.borrow_shares(&bank_account.key());
 
-    // Cal. the current value of 1 share
-    let value_per_share =
-        bank_account.total_borrows as f64 / bank_account.total_borrows_shares as f64;
+    // Cal. the user's debt, interest included. Debt is rounded up so that rounding never works against the depositors.
+    let borrowed_tokens = bank_account.borrow_amount(user_shares, Rounding::Up)?;
 
-    // Cal. the user's shares based on the current share value (interest included)
-    let current_user_shares = borrowed_tokens as f64 / value_per_share;
-
-    // Now that we have current user's shares after taking interest into consideration we can perform a check to ensure they are not repaying more than they currently hold.
-    if current_user_shares < amount_to_repay as f64 {
+    // Now that we have the user's debt after taking interest into consideration we can perform a check to ensure they are not repaying more than they currently owe.
+    if borrowed_tokens < amount_to_repay {
         return Err(ErrorCode::OverRepay.into());
     }
 
@@ -132,28 +120,25 @@ pub fn process_repay(
 
     // Update the state of the user and bank to reflect this repayment
 
-    let borrow_ratio = amount_to_repay
-        .checked_div(bank_account.total_borrows)
-        .unwrap();
-    let user_shares = bank_account
-        .total_borrows_shares
-        .checked_mul(borrow_ratio)
-        .unwrap();
-
-    match token_type {
-        TokenType::USDC => {
-            user_account.borrowed_usdc -= amount_to_repay;
-            user_account.borrowed_usdc_shares -= user_shares as u64;
-        }
-        TokenType::SOL => {
-            user_account.borrowed_sol -= amount_to_repay;
-            user_account.borrowed_sol_shares -= user_shares as u64;
-        }
-    }
+    // Repaying the whole debt clears all of the user's shares, otherwise the shares burnt are rounded down in favour of the bank
+    let shares_to_repay = if amount_to_repay == borrowed_tokens {
+        user_shares
+    } else {
+        bank_account
+            .borrow_shares(amount_to_repay, Rounding::Down)?
+            .min(user_shares)
+    };
+
+    user_account
+        .position_mut(&bank_account.key())?
+        .borrow_shares -= shares_to_repay;
+    user_account.close_empty_positions();
 
     // Finally update the state of the bank account
-    bank_account.total_borrows -= amount_to_repay;
-    bank_account.total_borrows_shares -= user_shares as u64;
+    bank_account.total_borrows = bank_account.total_borrows.saturating_sub(amount_to_repay);
+    bank_account.total_borrows_shares -= shares_to_repay;
+
+    user_account.last_updated = Clock::get()?.unix_timestamp;
 
     Ok(())
 }
@@ -0,0 +1,248 @@
+//! Handles the write off of debt that can never be repaid
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::errors::ErrorCode;
+use crate::events::BadDebtSocialized;
+use crate::math::Rounding;
+use crate::state::{Bank, LendingMarket, User};
+
+/// Define the struct needed for our context to create the instruction for socializing bad debt
+///
+/// Anyone can call it, a borrower without any collateral left is bad debt no matter who notices it.
+#[derive(Accounts)]
+pub struct SocializeBadDebt<'info> {
+    /// The lending market the bank belongs to
+    pub market: Account<'info, LendingMarket>,
+
+    /// The mint address of the asset that was borrowed
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// The bank account the debt is owed to
+    #[account(
+        mut,
+        seeds = [market.key().as_ref(), mint.key().as_ref()],
+        bump,
+    )]
+    pub bank: Account<'info, Bank>,
+
+    /// User account of the borrower whose debt is written off
+    #[account(
+        mut,
+        seeds = [market.key().as_ref(), borrower_user_account.owner.as_ref()],
+        bump,
+    )]
+    pub borrower_user_account: Account<'info, User>,
+
+    /// The bank token account, refunded by the insurance fund
+    #[account(
+        mut,
+        seeds = [b"Treasury", market.key().as_ref(), mint.key().as_ref()],
+        bump,
+    )]
+    pub bank_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// The insurance fund of the bank, which covers the bad debt before the depositors
+    #[account(
+        mut,
+        seeds = [b"Insurance", market.key().as_ref(), mint.key().as_ref()],
+        bump,
+    )]
+    pub insurance_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Token program to transfer from the insurance fund
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Instruction to write off the debt a borrower owes to a bank once all of their collateral has been seized.
+///
+/// The remaining accounts are the other banks the borrower still holds deposit shares in, in the order of their positions, see `has_collateral`.
+/// Nobody has any reason to repay that debt anymore, so it is removed from the bank.
+/// The insurance fund of the bank pays back as much of it as it can, and only what is left is taken by the depositors through the supply index.
+/// Otherwise `total_borrows` would keep counting (and charging interest on) tokens that will never come back.
+pub fn process_socialize_bad_debt(ctx: Context<SocializeBadDebt>) -> Result<()> {
+    let bank = &mut ctx.accounts.bank;
+    let borrower_user_account = &mut ctx.accounts.borrower_user_account;
+
+    // Only an account without any collateral in any bank is bad debt, anything else can still be liquidated
+    if has_collateral(borrower_user_account, bank, ctx.remaining_accounts)? {
+        return Err(ErrorCode::AccountHasCollateral.into());
+    }
+
+    let borrow_shares = borrower_user_account.borrow_shares(&bank.key());
+    if borrow_shares == 0 {
+        return Err(ErrorCode::NoBadDebt.into());
+    }
+
+    let current_time = Clock::get()?.unix_timestamp;
+    bank.accrue_interest(current_time)?;
+
+    let bad_debt = bank.borrow_amount(borrow_shares, Rounding::Up)?;
+
+    // Remove the debt from the borrower and the bank
+    borrower_user_account
+        .position_mut(&bank.key())?
+        .borrow_shares = 0;
+    borrower_user_account.close_empty_positions();
+    borrower_user_account.last_updated = current_time;
+
+    bank.total_borrows = bank.total_borrows.saturating_sub(bad_debt);
+    bank.total_borrows_shares -= borrow_shares;
+
+    // The insurance fund replaces the lost tokens first
+    let insurance_covered = bad_debt.min(ctx.accounts.insurance_token_account.amount);
+
+    // Whatever it can't cover is gone, so every depositor's balance is reduced in proportion to their share of the bank
+    let supply_index_before = bank.supply_index;
+    bank.socialize_loss(bad_debt - insurance_covered)?;
+
+    emit!(BadDebtSocialized {
+        bank: bank.key(),
+        borrower: borrower_user_account.owner,
+        amount: bad_debt,
+        insurance_covered,
+        borrow_shares,
+        supply_index_before,
+        supply_index_after: bank.supply_index,
+        timestamp: current_time,
+    });
+
+    if insurance_covered > 0 {
+        let transfer_cpi_accounts = TransferChecked {
+            from: ctx.accounts.insurance_token_account.to_account_info(),
+            to: ctx.accounts.bank_token_account.to_account_info(),
+            authority: ctx.accounts.insurance_token_account.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+        };
+
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+
+        // The insurance token account is a PDA and its own authority, same as the bank token account
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            b"Insurance",
+            ctx.accounts.market.to_account_info().key.as_ref(),
+            ctx.accounts.mint.to_account_info().key.as_ref(),
+            &[ctx.bumps.insurance_token_account],
+        ]];
+
+        let cpi_ctx = CpiContext::new(cpi_program, transfer_cpi_accounts).with_signer(signer_seeds);
+
+        let decimals = ctx.accounts.mint.decimals;
+
+        transfer_checked(cpi_ctx, insurance_covered, decimals)?;
+    }
+
+    Ok(())
+}
+
+/// Whether `user` has deposits worth anything in any bank.
+///
+/// Once a loss took the supply index of a bank below 1, rounding can leave deposit shares that are worth 0 tokens, which no liquidation can seize.
+/// So the deposits are valued rather than counted: `bank` values the position in itself and `accounts` hold, in the order of the positions, the other banks with deposit shares.
+fn has_collateral(user: &User, bank: &Account<Bank>, accounts: &[AccountInfo]) -> Result<bool> {
+    let mut accounts = accounts.iter();
+
+    for position in user
+        .positions
+        .iter()
+        .filter(|position| position.deposit_shares > 0)
+    {
+        let deposit_amount = if position.bank == bank.key() {
+            bank.deposit_amount(position.deposit_shares)?
+        } else {
+            let bank_account = accounts.next().ok_or(ErrorCode::InvalidHealthAccounts)?;
+            if bank_account.key() != position.bank || bank_account.owner != &crate::ID {
+                return Err(ErrorCode::InvalidHealthAccounts.into());
+            }
+
+            Bank::try_deserialize(&mut &bank_account.try_borrow_data()?[..])?
+                .deposit_amount(position.deposit_shares)?
+        };
+
+        if deposit_amount > 0 {
+            return Ok(true);
+        }
+    }
+
+    Ok(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::Rate;
+    use crate::state::Position;
+    use crate::test_utils::program_account;
+
+    /// Bank at `key` where a loss took the value of a share down to 0.75 tokens
+    fn bank_after_a_loss(key: Pubkey) -> AccountInfo<'static> {
+        program_account(
+            key,
+            &Bank {
+                supply_index: Rate::ONE.0 * 3 / 4,
+                ..Default::default()
+            },
+        )
+    }
+
+    fn user(positions: Vec<Position>) -> User {
+        User {
+            owner: Pubkey::new_unique(),
+            market: Pubkey::new_unique(),
+            positions,
+            last_updated: 0,
+        }
+    }
+
+    fn position(bank: &AccountInfo, deposit_shares: u64) -> Position {
+        Position {
+            bank: bank.key(),
+            deposit_shares,
+            borrow_shares: 0,
+        }
+    }
+
+    #[test]
+    fn shares_worth_nothing_are_not_collateral() {
+        let (bank_info, other_bank) = (
+            bank_after_a_loss(Pubkey::new_unique()),
+            bank_after_a_loss(Pubkey::new_unique()),
+        );
+        let bank: Account<Bank> =
+            Account::try_from(Box::leak(Box::new(bank_info.clone()))).unwrap();
+
+        // 1 share is worth 0.75 tokens, rounded down to nothing
+        let dust = user(vec![position(&bank_info, 1), position(&other_bank, 1)]);
+        assert!(!has_collateral(&dust, &bank, std::slice::from_ref(&other_bank)).unwrap());
+
+        // 2 shares are still worth 1 token, in the bank itself or in another one
+        let in_bank = user(vec![position(&bank_info, 2)]);
+        assert!(has_collateral(&in_bank, &bank, &[]).unwrap());
+
+        let in_other_bank = user(vec![position(&bank_info, 1), position(&other_bank, 2)]);
+        assert!(has_collateral(&in_other_bank, &bank, std::slice::from_ref(&other_bank)).unwrap());
+    }
+
+    #[test]
+    fn the_other_banks_have_to_be_passed() {
+        let (bank_info, other_bank) = (
+            bank_after_a_loss(Pubkey::new_unique()),
+            bank_after_a_loss(Pubkey::new_unique()),
+        );
+        let bank: Account<Bank> =
+            Account::try_from(Box::leak(Box::new(bank_info.clone()))).unwrap();
+        let user = user(vec![position(&other_bank, 1)]);
+
+        assert_eq!(
+            has_collateral(&user, &bank, &[]).unwrap_err(),
+            ErrorCode::InvalidHealthAccounts.into()
+        );
+        assert_eq!(
+            has_collateral(&user, &bank, std::slice::from_ref(&bank_info)).unwrap_err(),
+            ErrorCode::InvalidHealthAccounts.into()
+        );
+    }
+}
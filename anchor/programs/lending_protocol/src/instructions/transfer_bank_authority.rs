@@ -0,0 +1,210 @@
+//! Handles the transfer of a bank to a new authority.
+//!
+//! The transfer takes two steps: the current authority proposes a new one, which then has to accept it by signing (or, for a `Multisig`, by enough of its signers signing).
+//! A mistyped address can never take over the bank, it just leaves a proposal that nobody can accept.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::authority::check_authority;
+use crate::errors::ErrorCode;
+use crate::state::{Bank, LendingMarket};
+
+/// Define the struct needed for our context to create the instruction for proposing a new authority
+#[derive(Accounts)]
+pub struct ProposeBankAuthority<'info> {
+    /// Only the current authority of the bank can propose a new one
+    ///
+    /// CHECK: either a key that signs or a `Multisig`, checked by `check_authority`
+    pub authority: UncheckedAccount<'info>,
+
+    /// The lending market the bank belongs to
+    pub market: Account<'info, LendingMarket>,
+
+    /// The mint address of the asset of the bank
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// The bank account to transfer
+    #[account(
+        mut,
+        seeds = [market.key().as_ref(), mint.key().as_ref()],
+        bump,
+        has_one = authority @ ErrorCode::Unauthorized,
+    )]
+    pub bank: Account<'info, Bank>,
+}
+
+/// Define the struct needed for our context to create the instruction for accepting the authority of a bank
+#[derive(Accounts)]
+pub struct AcceptBankAuthority<'info> {
+    /// The proposed authority, it has to approve to prove it controls the key
+    ///
+    /// CHECK: either a key that signs or a `Multisig`, checked by `check_authority`
+    pub pending_authority: UncheckedAccount<'info>,
+
+    /// The lending market the bank belongs to
+    pub market: Account<'info, LendingMarket>,
+
+    /// The mint address of the asset of the bank
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// The bank account to transfer
+    #[account(
+        mut,
+        seeds = [market.key().as_ref(), mint.key().as_ref()],
+        bump,
+        constraint = bank.pending_authority == Some(pending_authority.key()) @ ErrorCode::NotPendingAuthority,
+    )]
+    pub bank: Account<'info, Bank>,
+}
+
+/// Instruction to propose `new_authority` as the authority of the bank.
+///
+/// Proposing replaces any previous proposal, and proposing `None` cancels it.
+pub fn process_propose_bank_authority(
+    ctx: Context<ProposeBankAuthority>,
+    new_authority: Option<Pubkey>,
+) -> Result<()> {
+    check_authority(&ctx.accounts.authority, ctx.remaining_accounts)?;
+
+    ctx.accounts.bank.pending_authority = new_authority;
+
+    Ok(())
+}
+
+/// Instruction for the proposed authority to take over the bank
+pub fn process_accept_bank_authority(ctx: Context<AcceptBankAuthority>) -> Result<()> {
+    check_authority(&ctx.accounts.pending_authority, ctx.remaining_accounts)?;
+
+    let bank = &mut ctx.accounts.bank;
+
+    bank.authority = ctx.accounts.pending_authority.key();
+    bank.pending_authority = None;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::*;
+
+    /// Market and bank of a fresh mint, with `authority` as the authority of the bank
+    fn bank(authority: Pubkey) -> [AccountInfo<'static>; 3] {
+        let (market, mint) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let bank = Bank {
+            authority,
+            mint_address: mint,
+            ..Default::default()
+        };
+
+        let lending_market = LendingMarket {
+            admin: Pubkey::new_unique(),
+            name: [0; 32],
+            guardian: None,
+            paused: 0,
+        };
+
+        [
+            program_account(market, &lending_market),
+            mint_account(mint),
+            program_account(bank_address(&market, &mint), &bank),
+        ]
+    }
+
+    fn propose(
+        authority: Pubkey,
+        [market, mint, bank]: &[AccountInfo<'static>; 3],
+        new_authority: Option<Pubkey>,
+    ) -> Result<()> {
+        process::<ProposeBankAuthority>(
+            vec![
+                signer(authority),
+                market.clone(),
+                mint.clone(),
+                bank.clone(),
+            ],
+            vec![],
+            |ctx| process_propose_bank_authority(ctx, new_authority),
+        )
+    }
+
+    fn accept(
+        pending_authority: Pubkey,
+        [market, mint, bank]: &[AccountInfo<'static>; 3],
+    ) -> Result<()> {
+        process::<AcceptBankAuthority>(
+            vec![
+                signer(pending_authority),
+                market.clone(),
+                mint.clone(),
+                bank.clone(),
+            ],
+            vec![],
+            process_accept_bank_authority,
+        )
+    }
+
+    #[test]
+    fn the_proposed_authority_takes_over_the_bank() {
+        let (authority, new_authority) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let accounts = bank(authority);
+
+        propose(authority, &accounts, Some(new_authority)).unwrap();
+        accept(new_authority, &accounts).unwrap();
+
+        let bank: Bank = read(&accounts[2]);
+        assert_eq!(bank.authority, new_authority);
+        assert_eq!(bank.pending_authority, None);
+    }
+
+    #[test]
+    fn only_the_authority_can_propose() {
+        let (authority, someone_else) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let accounts = bank(authority);
+
+        assert_error(
+            propose(someone_else, &accounts, Some(someone_else)),
+            ErrorCode::Unauthorized,
+        );
+        assert_eq!(read::<Bank>(&accounts[2]).pending_authority, None);
+    }
+
+    #[test]
+    fn only_the_proposed_authority_can_accept() {
+        let (authority, new_authority, someone_else) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let accounts = bank(authority);
+
+        propose(authority, &accounts, Some(new_authority)).unwrap();
+
+        assert_error(
+            accept(someone_else, &accounts),
+            ErrorCode::NotPendingAuthority,
+        );
+        assert_error(accept(authority, &accounts), ErrorCode::NotPendingAuthority);
+
+        let bank: Bank = read(&accounts[2]);
+        assert_eq!(bank.authority, authority);
+        assert_eq!(bank.pending_authority, Some(new_authority));
+    }
+
+    #[test]
+    fn a_cancelled_proposal_cannot_be_accepted() {
+        let (authority, new_authority) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let accounts = bank(authority);
+
+        propose(authority, &accounts, Some(new_authority)).unwrap();
+        propose(authority, &accounts, None).unwrap();
+        assert_eq!(read::<Bank>(&accounts[2]).pending_authority, None);
+
+        assert_error(
+            accept(new_authority, &accounts),
+            ErrorCode::NotPendingAuthority,
+        );
+        assert_eq!(read::<Bank>(&accounts[2]).authority, authority);
+    }
+}
@@ -0,0 +1,51 @@
+//! Handles the deposit and borrow caps of a bank.
+//!
+//! Caps only restrict new deposits and borrows and never touch existing positions, so unlike the risk parameters they take effect immediately instead of going through the config timelock.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::authority::check_authority;
+use crate::errors::ErrorCode;
+use crate::state::{Bank, LendingMarket};
+
+/// Define the struct needed for our context to create the instruction for updating the caps of a bank
+#[derive(Accounts)]
+pub struct UpdateBankCaps<'info> {
+    /// Only the authority of the bank can change its caps
+    ///
+    /// CHECK: either a key that signs or a `Multisig`, checked by `check_authority`
+    pub authority: UncheckedAccount<'info>,
+
+    /// The lending market the bank belongs to
+    pub market: Account<'info, LendingMarket>,
+
+    /// The mint address of the asset of the bank
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// The bank account to update
+    #[account(
+        mut,
+        seeds = [market.key().as_ref(), mint.key().as_ref()],
+        bump,
+        has_one = authority @ ErrorCode::Unauthorized,
+    )]
+    pub bank: Account<'info, Bank>,
+}
+
+/// Instruction to set the deposit and borrow caps of a bank, in tokens. 0 removes a cap.
+///
+/// A cap below the current total is allowed, it stops new deposits or borrows until the total drops below it.
+pub fn process_update_bank_caps(
+    ctx: Context<UpdateBankCaps>,
+    deposit_cap: u64,
+    borrow_cap: u64,
+) -> Result<()> {
+    check_authority(&ctx.accounts.authority, ctx.remaining_accounts)?;
+
+    let bank = &mut ctx.accounts.bank;
+    bank.deposit_cap = deposit_cap;
+    bank.borrow_cap = borrow_cap;
+
+    Ok(())
+}
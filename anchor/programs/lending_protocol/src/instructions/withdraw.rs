@@ -1,7 +1,5 @@
 //! Handles withdrawals from the protocol
 
-use std::f64::consts::E;
-
 use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
@@ -9,8 +7,11 @@ use anchor_spl::{
 };
 
 use crate::{
+    constants::PAUSE_WITHDRAW,
     errors::ErrorCode,
-    state::{Bank, TokenType, User},
+    health::compute_health,
+    math::Rounding,
+    state::{Bank, LendingMarket, User},
 };
 
 /// Define the struct needed for our context to create the instruction for withdrawing from a bank
@@ -20,13 +21,16 @@ pub struct Withdraw<'info> {
     #[account(mut)]
     pub signer: Signer<'info>,
 
+    /// The lending market the bank belongs to
+    pub market: Account<'info, LendingMarket>,
+
     /// The mint address of the asset to be withdrawn
     pub mint: InterfaceAccount<'info, Mint>,
 
     /// The bank account to withdraw the asset from
     #[account(
         mut,
-        seeds = [mint.key().as_ref()],
+        seeds = [market.key().as_ref(), mint.key().as_ref()],
         bump,
     )]
     pub bank: Account<'info, Bank>,
@@ -34,7 +38,7 @@ pub struct Withdraw<'info> {
     /// The bank token account which holds the asset to be withdrawn
     #[account(
         mut,
-        seeds = [b"Treasury", mint.key().as_ref()],
+        seeds = [b"Treasury", market.key().as_ref(), mint.key().as_ref()],
         bump,
     )]
     pub bank_token_account: InterfaceAccount<'info, TokenAccount>,
@@ -42,7 +46,7 @@ pub struct Withdraw<'info> {
     /// The user account requesting the withdrawal
     #[account(
         mut,
-        seeds = [signer.key().as_ref()],
+        seeds = [market.key().as_ref(), signer.key().as_ref()],
         bump,
     )]
     pub user_account: Account<'info, User>,
@@ -72,44 +76,34 @@ pub struct Withdraw<'info> {
 /// Instruction to process the withdrawal.
 ///
 /// Before processing the withdrawal, we need to check if the user has depossited enough tokens to be able to withdraw. User cannot withdraw tokens that they already deposited.
-pub fn process_withdraw(
-    ctx: Context<Withdraw>,
-    amount_to_withdraw: u64,
-    token_type: TokenType,
-) -> Result<()> {
+pub fn process_withdraw(ctx: Context<Withdraw>, amount_to_withdraw: u64) -> Result<()> {
+    ctx.accounts
+        .bank
+        .check_not_paused(&ctx.accounts.market, PAUSE_WITHDRAW)?;
+
     let bank_account = &mut ctx.accounts.bank;
     let user_account = &mut ctx.accounts.user_account;
 
-    // Verify that the user has deposited enough tokens to be able to withdraw
-    let deposited_tokens: u64;
-    match token_type {
-        TokenType::SOL => deposited_tokens = user_account.deposited_sol,
-        TokenType::USDC => deposited_tokens = user_account.deposited_usdc,
-    }
-
-    // Cal. the interest that the user has earned since the last time they deposited
+    // Accrue the interest the depositors earned so far before pricing the user's shares
+    bank_account.accrue_interest(Clock::get()?.unix_timestamp)?;
 
-    // Get the current time diffrance between the last time the user deposited and the current time
-    let time_diff = Clock::get()?.unix_timestamp - user_account.last_updated;
-
-    // Calculate the total deposits after interest has been applied
-    bank_account.total_deposits = (bank_account.total_deposits as f64
-        * E.powf(time_diff as f64 * bank_account.interest_rate as f64))
-        as u64;
-
-    // Calculate the current value of 1 share (unused but kept for clarity)
-    let _value_per_share =
-        bank_account.total_deposits as f64 / bank_account.total_deposits_shares as f64;
+    // Verify that the user has deposited enough tokens to be able to withdraw
+    let user_shares = user_account.deposit_shares(&bank_account.key());
 
-    // Calculate the current value of user's deposited tokens after interest
-    let current_deposited_value =
-        deposited_tokens as f64 * E.powf(time_diff as f64 * bank_account.interest_rate as f64);
+    // Current value of user's deposited tokens, interest included
+    let current_deposited_value = bank_account.deposit_amount(user_shares)?;
 
     // Check if user has enough to withdraw
-    if current_deposited_value < amount_to_withdraw as f64 {
+    if current_deposited_value < amount_to_withdraw {
         return Err(ErrorCode::InsufficientFunds.into());
     }
 
+    // The user's deposit may be lent out, in which case they have to wait for borrowers to repay
+    if amount_to_withdraw > bank_account.available_liquidity(ctx.accounts.bank_token_account.amount)
+    {
+        return Err(ErrorCode::InsufficientLiquidity.into());
+    }
+
     // Intiate the transfer to be able to withdraw from the bank
 
     // CPI Transfer
@@ -132,6 +126,7 @@ pub fn process_withdraw(
     // Since we are signing with the bank token account which is a PDA - we hv to define the signer seeds for this CPI to process
     let signer_seeds: &[&[&[u8]]] = &[&[
         b"Treasury",
+        ctx.accounts.market.to_account_info().key.as_ref(),
         ctx.accounts.mint.to_account_info().key.as_ref(),
         &[ctx.bumps.bank_token_account],
     ]];
@@ -146,49 +141,42 @@ pub fn process_withdraw(
 
     // Bank
     let bank_account = &mut ctx.accounts.bank;
-    let shares_to_withdraw = (amount_to_withdraw as f64 / bank_account.total_deposits as f64)
-        * bank_account.total_deposits_shares as f64;
 
-    // User
-    let user_account = &mut ctx.accounts.user_account;
-
-    // First update the user's deposited amount to reflect accrued interest
-    let user_shares: f64;
-    match token_type {
-        TokenType::USDC => {
-            user_shares = user_account.deposited_usdc_shares as f64;
-            // Update deposited amount with interest
-            user_account.deposited_usdc = ((user_account.deposited_usdc as f64)
-                * E.powf(time_diff as f64 * bank_account.interest_rate as f64))
-                as u64;
-        }
-        TokenType::SOL => {
-            user_shares = user_account.deposited_sol_shares as f64;
-            // Update deposited amount with interest
-            user_account.deposited_sol = ((user_account.deposited_sol as f64)
-                * E.powf(time_diff as f64 * bank_account.interest_rate as f64))
-                as u64;
-        }
-    }
+    // Shares are rounded up so that a withdrawal can never leave the user with more than they are owed
+    let shares_to_withdraw = bank_account.deposit_shares(amount_to_withdraw, Rounding::Up)?;
 
     // Ensure we don't withdraw more shares than the user has
     let actual_shares_to_withdraw = shares_to_withdraw.min(user_shares);
 
-    // Match the asset type and update the state of the user account
-    match token_type {
-        TokenType::USDC => {
-            user_account.deposited_usdc -= amount_to_withdraw;
-            user_account.deposited_usdc_shares -= actual_shares_to_withdraw as u64;
-        }
-        TokenType::SOL => {
-            user_account.deposited_sol -= amount_to_withdraw;
-            user_account.deposited_sol_shares -= actual_shares_to_withdraw as u64;
-        }
-    }
+    // Update the user's position in this bank, freeing its slot once it's empty
+    let user_account = &mut ctx.accounts.user_account;
+    user_account
+        .position_mut(&bank_account.key())?
+        .deposit_shares -= actual_shares_to_withdraw;
+    user_account.close_empty_positions();
 
     // Finally update the state of the bank account
-    bank_account.total_deposits -= amount_to_withdraw;
-    bank_account.total_deposits_shares -= actual_shares_to_withdraw as u64;
+    bank_account.total_deposits = bank_account
+        .total_deposits
+        .saturating_sub(amount_to_withdraw);
+    bank_account.total_deposits_shares -= actual_shares_to_withdraw;
+
+    user_account.last_updated = Clock::get()?.unix_timestamp;
+
+    // A user with debt can only withdraw the collateral that isn't needed to back it.
+    // The remaining accounts must then hold the banks and oracles of all the user's positions, see `compute_health`.
+    if user_account
+        .positions
+        .iter()
+        .any(|position| position.borrow_shares > 0)
+    {
+        // Withdrawing collateral adds risk, so it is refused while the oracles of any of the banks disagree
+        let health = compute_health(user_account, ctx.remaining_accounts, true)?;
+
+        if !health.is_within_borrow_limit() {
+            return Err(ErrorCode::InsufficientCollateral.into());
+        }
+    }
 
     Ok(())
 }
@@ -0,0 +1,88 @@
+//! Handles withdrawals from the insurance fund of a bank
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::authority::check_authority;
+use crate::errors::ErrorCode;
+use crate::state::{Bank, LendingMarket};
+
+/// Define the struct needed for our context to create the instruction for withdrawing from the insurance fund
+#[derive(Accounts)]
+pub struct WithdrawInsurance<'info> {
+    /// Only the authority of the bank can withdraw from its insurance fund
+    ///
+    /// CHECK: either a key that signs or a `Multisig`, checked by `check_authority`
+    pub authority: UncheckedAccount<'info>,
+
+    /// The lending market the bank belongs to
+    pub market: Account<'info, LendingMarket>,
+
+    /// The mint address of the asset of the bank
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// The bank account the insurance fund belongs to
+    #[account(
+        seeds = [market.key().as_ref(), mint.key().as_ref()],
+        bump,
+        has_one = authority @ ErrorCode::Unauthorized,
+    )]
+    pub bank: Account<'info, Bank>,
+
+    /// The insurance fund of the bank
+    #[account(
+        mut,
+        seeds = [b"Insurance", market.key().as_ref(), mint.key().as_ref()],
+        bump,
+    )]
+    pub insurance_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Token account the tokens are sent to. Can be owned by anyone chosen by the authority, e.g. to move the fund somewhere else.
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    pub receiver: InterfaceAccount<'info, TokenAccount>,
+
+    /// Token program to transfer the tokens
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Instruction to withdraw `amount` tokens from the insurance fund.
+///
+/// The fund can also be topped up by anyone with a plain transfer to the insurance token account.
+pub fn process_withdraw_insurance(ctx: Context<WithdrawInsurance>, amount: u64) -> Result<()> {
+    check_authority(&ctx.accounts.authority, ctx.remaining_accounts)?;
+
+    if amount > ctx.accounts.insurance_token_account.amount {
+        return Err(ErrorCode::InsufficientFunds.into());
+    }
+
+    let transfer_cpi_accounts = TransferChecked {
+        from: ctx.accounts.insurance_token_account.to_account_info(),
+        to: ctx.accounts.receiver.to_account_info(),
+        authority: ctx.accounts.insurance_token_account.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+    };
+
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+
+    // The insurance token account is a PDA and its own authority, same as the bank token account
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        b"Insurance",
+        ctx.accounts.market.to_account_info().key.as_ref(),
+        ctx.accounts.mint.to_account_info().key.as_ref(),
+        &[ctx.bumps.insurance_token_account],
+    ]];
+
+    let cpi_ctx = CpiContext::new(cpi_program, transfer_cpi_accounts).with_signer(signer_seeds);
+
+    let decimals = ctx.accounts.mint.decimals;
+
+    transfer_checked(cpi_ctx, amount, decimals)?;
+
+    Ok(())
+}
@@ -1,16 +1,33 @@
 use anchor_lang::prelude::*;
 use instructions::admin::*;
 use instructions::borrow::*;
+use instructions::collect_protocol_fees::*;
+use instructions::config_timelock::*;
 use instructions::deposit::*;
+use instructions::fund_insurance::*;
 use instructions::liquidate::*;
+use instructions::multisig::*;
+use instructions::pause::*;
+use instructions::refresh_bank::*;
 use instructions::repay::*;
+use instructions::socialize_bad_debt::*;
+use instructions::transfer_bank_authority::*;
+use instructions::update_bank_caps::*;
 use instructions::withdraw::*;
+use instructions::withdraw_insurance::*;
 
+mod authority;
 mod constants;
 mod errors;
+mod events;
+mod health;
 mod instructions;
+mod math;
+mod oracle;
 mod state;
-use crate::state::TokenType;
+#[cfg(test)]
+mod test_utils;
+use crate::state::{BankConfigUpdate, OracleConfig};
 
 declare_id!("9CoY42r3y5WFDJjQX97e9m9THcVGpvuVSKjBjGkiksMR");
 
@@ -18,48 +35,131 @@ declare_id!("9CoY42r3y5WFDJjQX97e9m9THcVGpvuVSKjBjGkiksMR");
 pub mod lending_protocol {
     use super::*;
 
+    pub fn initialize_market(ctx: Context<InitializeMarket>, name: [u8; 32]) -> Result<()> {
+        process_initialize_market(ctx, name)
+    }
+
     pub fn initialize_bank(
         ctx: Context<InitializeBank>,
         liquidation_threshold: u64,
         max_ltv: u64,
+        oracle: OracleConfig,
     ) -> Result<()> {
-        process_initialize_bank(ctx, liquidation_threshold, max_ltv)
+        process_initialize_bank(ctx, liquidation_threshold, max_ltv, oracle)
     }
 
     pub fn initialize_account(ctx: Context<InitializeAccount>) -> Result<()> {
         process_initialize_account(ctx)
     }
 
-    pub fn deposit(
-        ctx: Context<Deposit>,
-        amount_to_deposit: u64,
-        token_type: TokenType,
+    pub fn create_multisig(
+        ctx: Context<CreateMultisig>,
+        signers: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        process_create_multisig(ctx, signers, threshold)
+    }
+
+    pub fn set_multisig_signers(
+        ctx: Context<SetMultisigSigners>,
+        signers: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        process_set_multisig_signers(ctx, signers, threshold)
+    }
+
+    pub fn deposit(ctx: Context<Deposit>, amount_to_deposit: u64) -> Result<()> {
+        process_deposit(ctx, amount_to_deposit)
+    }
+
+    pub fn withdraw(ctx: Context<Withdraw>, amount_to_withdraw: u64) -> Result<()> {
+        process_withdraw(ctx, amount_to_withdraw)
+    }
+
+    pub fn borrow(ctx: Context<Borrow>, amount_to_borrow: u64) -> Result<()> {
+        process_borrow(ctx, amount_to_borrow)
+    }
+
+    pub fn repay(ctx: Context<Repay>, amount_to_repay: u64) -> Result<()> {
+        process_repay(ctx, amount_to_repay)
+    }
+
+    pub fn liquidate(
+        ctx: Context<Liquidate>,
+        repay_amount: u64,
+        min_collateral_out: u64,
+    ) -> Result<()> {
+        process_liquidate(ctx, repay_amount, min_collateral_out)
+    }
+
+    pub fn refresh_bank(ctx: Context<RefreshBank>) -> Result<()> {
+        process_refresh_bank(ctx)
+    }
+
+    pub fn collect_protocol_fees(ctx: Context<CollectProtocolFees>) -> Result<()> {
+        process_collect_protocol_fees(ctx)
+    }
+
+    pub fn socialize_bad_debt(ctx: Context<SocializeBadDebt>) -> Result<()> {
+        process_socialize_bad_debt(ctx)
+    }
+
+    pub fn fund_insurance(ctx: Context<FundInsurance>) -> Result<()> {
+        process_fund_insurance(ctx)
+    }
+
+    pub fn withdraw_insurance(ctx: Context<WithdrawInsurance>, amount: u64) -> Result<()> {
+        process_withdraw_insurance(ctx, amount)
+    }
+
+    pub fn queue_config_change(
+        ctx: Context<QueueConfigChange>,
+        update: BankConfigUpdate,
     ) -> Result<()> {
-        process_deposit(ctx, amount_to_deposit, token_type)
+        process_queue_config_change(ctx, update)
     }
 
-    pub fn withdraw(
-        ctx: Context<Withdraw>,
-        amount_to_withdraw: u64,
-        token_type: TokenType,
+    pub fn execute_config_change(ctx: Context<ExecuteConfigChange>, id: u64) -> Result<()> {
+        process_execute_config_change(ctx, id)
+    }
+
+    pub fn cancel_config_change(ctx: Context<CancelConfigChange>, id: u64) -> Result<()> {
+        process_cancel_config_change(ctx, id)
+    }
+
+    pub fn update_bank_caps(
+        ctx: Context<UpdateBankCaps>,
+        deposit_cap: u64,
+        borrow_cap: u64,
     ) -> Result<()> {
-        process_withdraw(ctx, amount_to_withdraw, token_type)
+        process_update_bank_caps(ctx, deposit_cap, borrow_cap)
     }
 
-    pub fn borrow(
-        ctx: Context<Borrow>,
-        amount_to_borrow: u64,
-        token_type: TokenType,
+    pub fn propose_bank_authority(
+        ctx: Context<ProposeBankAuthority>,
+        new_authority: Option<Pubkey>,
     ) -> Result<()> {
-        process_borrow(ctx, amount_to_borrow, token_type)
+        process_propose_bank_authority(ctx, new_authority)
     }
 
-    pub fn repay(ctx: Context<Repay>, amount_to_repay: u64, token_type: TokenType) -> Result<()> {
-        process_repay(ctx, amount_to_repay, token_type)
+    pub fn accept_bank_authority(ctx: Context<AcceptBankAuthority>) -> Result<()> {
+        process_accept_bank_authority(ctx)
+    }
+
+    pub fn set_guardian(ctx: Context<SetGuardian>, guardian: Option<Pubkey>) -> Result<()> {
+        process_set_guardian(ctx, guardian)
+    }
+
+    pub fn set_market_pause(
+        ctx: Context<SetMarketPause>,
+        operations: u8,
+        paused: bool,
+    ) -> Result<()> {
+        process_set_market_pause(ctx, operations, paused)
     }
 
-    pub fn liquidate(ctx: Context<Liquidate>, token_type: TokenType) -> Result<()> {
-        process_liquidate(ctx, token_type)
+    pub fn set_bank_pause(ctx: Context<SetBankPause>, operations: u8, paused: bool) -> Result<()> {
+        process_set_bank_pause(ctx, operations, paused)
     }
 }
 
@@ -0,0 +1,350 @@
+//! Fixed-point math used for interest, share and health calculations.
+//!
+//! Floats are not deterministic across validators and silently saturate when cast back to integers, so every value that has a fractional part is stored as a `u128` scaled by `10^DECIMALS`.
+//! All multiplications and divisions are checked and take an explicit `Rounding` so that the protocol can always round in its own favour.
+
+use anchor_lang::prelude::*;
+
+use crate::constants::SECONDS_PER_YEAR;
+use crate::errors::ErrorCode;
+
+/// Direction in which the result of a multiplication or division is rounded
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Rounding {
+    Down,
+    Up,
+}
+
+/// Unsigned fixed-point number with `DECIMALS` decimal places backed by a `u128`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Decimal<const DECIMALS: u32>(pub u128);
+
+/// 18 decimals fixed-point number used for token amounts, USD values and ratios such as the health factor.
+pub type Wad = Decimal<18>;
+
+/// 27 decimals (RAY) fixed-point number used for interest rates and cumulative indices.
+///
+/// Per second rates are tiny, so they need the extra precision to not round away to zero.
+pub type Rate = Decimal<27>;
+
+impl<const DECIMALS: u32> Decimal<DECIMALS> {
+    /// Raw value that represents `1`
+    pub const SCALE: u128 = 10u128.pow(DECIMALS);
+    pub const ONE: Self = Self(Self::SCALE);
+
+    /// Wraps a value that is already scaled, e.g. an index stored on an account
+    pub const fn from_raw(value: u128) -> Self {
+        Self(value)
+    }
+
+    /// Builds `numerator / denominator` as a fixed-point number
+    pub fn from_ratio(numerator: u128, denominator: u128, rounding: Rounding) -> Result<Self> {
+        mul_div(numerator, Self::SCALE, denominator, rounding).map(Self)
+    }
+
+    /// Converts a whole percentage (`80` == 80%)
+    pub fn from_percent(percent: u64) -> Result<Self> {
+        Self::from_ratio(percent as u128, 100, Rounding::Down)
+    }
+
+    /// Converts a value expressed in basis points (`8_000` == 80%)
+    pub fn from_bps(bps: u64) -> Result<Self> {
+        Self::from_ratio(bps as u128, 10_000, Rounding::Down)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn checked_add(self, rhs: Self) -> Result<Self> {
+        self.0
+            .checked_add(rhs.0)
+            .map(Self)
+            .ok_or_else(|| ErrorCode::MathOverflow.into())
+    }
+
+    pub fn checked_sub(self, rhs: Self) -> Result<Self> {
+        self.0
+            .checked_sub(rhs.0)
+            .map(Self)
+            .ok_or_else(|| ErrorCode::MathOverflow.into())
+    }
+
+    pub fn checked_mul(self, rhs: Self, rounding: Rounding) -> Result<Self> {
+        mul_div(self.0, rhs.0, Self::SCALE, rounding).map(Self)
+    }
+
+    pub fn checked_div(self, rhs: Self, rounding: Rounding) -> Result<Self> {
+        mul_div(self.0, Self::SCALE, rhs.0, rounding).map(Self)
+    }
+
+    pub fn checked_mul_u64(self, rhs: u64) -> Result<Self> {
+        self.0
+            .checked_mul(rhs as u128)
+            .map(Self)
+            .ok_or_else(|| ErrorCode::MathOverflow.into())
+    }
+
+    pub fn checked_div_u64(self, rhs: u64, rounding: Rounding) -> Result<Self> {
+        mul_div(self.0, 1, rhs as u128, rounding).map(Self)
+    }
+
+    /// Multiplies a raw token amount by this value, e.g. to apply an interest factor or a percentage to a balance
+    pub fn apply_to(self, amount: u64, rounding: Rounding) -> Result<u64> {
+        let value = mul_div(amount as u128, self.0, Self::SCALE, rounding)?;
+
+        u64::try_from(value).map_err(|_| ErrorCode::MathOverflow.into())
+    }
+
+    /// Divides a raw token amount by this value, the inverse of `apply_to`
+    pub fn apply_inverse_to(self, amount: u64, rounding: Rounding) -> Result<u64> {
+        let value = mul_div(amount as u128, Self::SCALE, self.0, rounding)?;
+
+        u64::try_from(value).map_err(|_| ErrorCode::MathOverflow.into())
+    }
+}
+
+/// Converts an annual rate in basis points into the per second rate used for compounding.
+pub fn per_second_rate(annual_rate_bps: u64) -> Result<Rate> {
+    Rate::from_bps(annual_rate_bps)?.checked_div_u64(SECONDS_PER_YEAR, Rounding::Down)
+}
+
+/// Growth factor `e^(rate * seconds)` of a continuously compounded balance, computed with a Taylor series.
+///
+/// Terms are added until they become smaller than the precision of a `Rate`, which for any realistic rate and elapsed time takes only a handful of iterations.
+pub fn compound_interest(rate_per_second: Rate, seconds: u64) -> Result<Rate> {
+    // Number of terms is bounded so that the compute cost of a malicious input stays bounded as well
+    const MAX_TERMS: u64 = 64;
+
+    let exponent = rate_per_second.checked_mul_u64(seconds)?;
+
+    let mut growth = Rate::ONE;
+    let mut term = Rate::ONE;
+
+    for n in 1..=MAX_TERMS {
+        // x^n / n! = (x^(n-1) / (n-1)!) * x / n
+        term = term
+            .checked_mul(exponent, Rounding::Down)?
+            .checked_div_u64(n, Rounding::Down)?;
+
+        if term.is_zero() {
+            break;
+        }
+
+        growth = growth.checked_add(term)?;
+    }
+
+    Ok(growth)
+}
+
+/// Computes `a * b / denominator` without overflowing on the intermediate product.
+///
+/// The product is kept as a 256-bit number split in two `u128` halves and only the quotient has to fit in a `u128`.
+pub fn mul_div(a: u128, b: u128, denominator: u128, rounding: Rounding) -> Result<u128> {
+    if denominator == 0 {
+        return Err(ErrorCode::DivisionByZero.into());
+    }
+
+    // Fast path when the product fits in 128 bits, which covers nearly every call
+    if let Some(product) = a.checked_mul(b) {
+        let quotient = product / denominator;
+
+        return if rounding == Rounding::Up && product % denominator != 0 {
+            quotient
+                .checked_add(1)
+                .ok_or_else(|| ErrorCode::MathOverflow.into())
+        } else {
+            Ok(quotient)
+        };
+    }
+
+    let (high, low) = full_mul(a, b);
+
+    // The quotient only fits in 128 bits if the high half is smaller than the denominator
+    if high >= denominator {
+        return Err(ErrorCode::MathOverflow.into());
+    }
+
+    // Binary long division of the 256-bit product by the denominator
+    let mut quotient: u128 = 0;
+    let mut remainder: u128 = high;
+
+    for i in (0..128).rev() {
+        let carry = remainder >> 127;
+        remainder = (remainder << 1) | ((low >> i) & 1);
+
+        if carry == 1 || remainder >= denominator {
+            remainder = remainder.wrapping_sub(denominator);
+            quotient |= 1 << i;
+        }
+    }
+
+    if rounding == Rounding::Up && remainder != 0 {
+        quotient = quotient.checked_add(1).ok_or(ErrorCode::MathOverflow)?;
+    }
+
+    Ok(quotient)
+}
+
+/// Full 256-bit product of two `u128`, returned as `(high, low)`
+fn full_mul(a: u128, b: u128) -> (u128, u128) {
+    const MASK: u128 = u64::MAX as u128;
+
+    let (a_high, a_low) = (a >> 64, a & MASK);
+    let (b_high, b_low) = (b >> 64, b & MASK);
+
+    let low_low = a_low * b_low;
+    let low_high = a_low * b_high;
+    let high_low = a_high * b_low;
+    let high_high = a_high * b_high;
+
+    // Sum of the middle terms and the carry out of the low half, kept in 128 bits by splitting them
+    let middle = (low_low >> 64) + (low_high & MASK) + (high_low & MASK);
+
+    let low = (middle << 64) | (low_low & MASK);
+    let high = high_high + (low_high >> 64) + (high_low >> 64) + (middle >> 64);
+
+    (high, low)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Reference values below were computed with arbitrary precision integers and 80 digit decimals
+
+    #[test]
+    fn full_mul_matches_the_256_bit_product() {
+        assert_eq!(full_mul(u128::MAX, u128::MAX), (u128::MAX - 1, 1));
+        assert_eq!(full_mul(u128::MAX, 1), (0, u128::MAX));
+        assert_eq!(full_mul(1 << 64, 1 << 64), (1, 0));
+
+        assert_eq!(
+            full_mul(
+                0x1234_5678_9abc_def0_0fed_cba9_8765_4321,
+                0xffff_ffff_ffff_ffff_0000_0000_0000_0001,
+            ),
+            (
+                0x1234_5678_9abc_deef_fdb9_7530_eca8_6431,
+                0x0246_8acf_1357_9bcf_0fed_cba9_8765_4321,
+            )
+        );
+    }
+
+    #[test]
+    fn mul_div_rounds_in_the_requested_direction() {
+        assert_eq!(mul_div(10, 1, 3, Rounding::Down).unwrap(), 3);
+        assert_eq!(mul_div(10, 1, 3, Rounding::Up).unwrap(), 4);
+
+        // Exact results are never rounded up
+        assert_eq!(mul_div(9, 1, 3, Rounding::Up).unwrap(), 3);
+    }
+
+    #[test]
+    fn mul_div_keeps_the_full_precision_of_a_256_bit_product() {
+        // 3e30 * 7e27 overflows a u128 but the quotient doesn't
+        let (a, b, denominator) = (3 * 10u128.pow(30), 7 * 10u128.pow(27), 10u128.pow(27) + 7);
+
+        assert_eq!(
+            mul_div(a, b, denominator, Rounding::Down).unwrap(),
+            20_999_999_999_999_999_999_999_999_853_000
+        );
+        assert_eq!(
+            mul_div(a, b, denominator, Rounding::Up).unwrap(),
+            20_999_999_999_999_999_999_999_999_853_001
+        );
+
+        assert_eq!(
+            mul_div(u128::MAX, u128::MAX, u128::MAX, Rounding::Down).unwrap(),
+            u128::MAX
+        );
+    }
+
+    #[test]
+    fn mul_div_fails_instead_of_wrapping() {
+        assert!(mul_div(u128::MAX, 2, 1, Rounding::Down).is_err());
+        assert!(mul_div(u128::MAX, u128::MAX, u128::MAX, Rounding::Up).is_ok());
+        assert!(mul_div(u128::MAX, 1, 1, Rounding::Up).is_ok());
+        assert!(mul_div(1, 1, 0, Rounding::Down).is_err());
+    }
+
+    #[test]
+    fn decimal_rounding_direction() {
+        assert_eq!(
+            Wad::from_ratio(1, 3, Rounding::Down).unwrap(),
+            Wad::from_raw(333_333_333_333_333_333)
+        );
+        assert_eq!(
+            Wad::from_ratio(1, 3, Rounding::Up).unwrap(),
+            Wad::from_raw(333_333_333_333_333_334)
+        );
+
+        let third = Wad::from_ratio(1, 3, Rounding::Down).unwrap();
+        assert_eq!(third.apply_to(10, Rounding::Down).unwrap(), 3);
+        assert_eq!(third.apply_to(10, Rounding::Up).unwrap(), 4);
+        assert_eq!(third.apply_inverse_to(10, Rounding::Down).unwrap(), 30);
+        assert_eq!(third.apply_inverse_to(10, Rounding::Up).unwrap(), 31);
+    }
+
+    /// Asserts that `actual` is at most `ulps` below `expected`, the exact value rounded down.
+    ///
+    /// Every term of the series is rounded down, so the result can only be below the exact value.
+    fn assert_below_within(actual: Rate, expected: u128, ulps: u128) {
+        assert!(
+            actual.0 <= expected && expected - actual.0 <= ulps,
+            "{} is not within {} below {}",
+            actual.0,
+            ulps,
+            expected
+        );
+    }
+
+    #[test]
+    fn per_second_rate_matches_reference() {
+        assert_eq!(per_second_rate(1_000).unwrap().0, 3_170_979_198_376_458_650);
+        assert_eq!(per_second_rate(0).unwrap(), Rate::from_raw(0));
+    }
+
+    #[test]
+    fn compound_interest_matches_reference() {
+        // 10% a year for a year, e^0.1
+        assert_below_within(
+            compound_interest(per_second_rate(1_000).unwrap(), SECONDS_PER_YEAR).unwrap(),
+            1_105_170_918_075_647_624_796_677_502,
+            64,
+        );
+
+        // 5% a year for a day
+        assert_below_within(
+            compound_interest(per_second_rate(500).unwrap(), 86_400).unwrap(),
+            1_000_136_995_684_421_689_475_211_509,
+            64,
+        );
+
+        // 300% a year for a year, e^3
+        assert_below_within(
+            compound_interest(per_second_rate(30_000).unwrap(), SECONDS_PER_YEAR).unwrap(),
+            20_085_536_923_187_667_740_334_640_498,
+            64,
+        );
+
+        // 0.01% a year for a second, where the second order term still shows up
+        assert_below_within(
+            compound_interest(per_second_rate(1).unwrap(), 1).unwrap(),
+            1_000_000_000_003_170_979_198_381_485,
+            64,
+        );
+    }
+
+    #[test]
+    fn compound_interest_without_time_or_rate_is_one() {
+        assert_eq!(
+            compound_interest(per_second_rate(1_000).unwrap(), 0).unwrap(),
+            Rate::ONE
+        );
+        assert_eq!(
+            compound_interest(Rate::from_raw(0), SECONDS_PER_YEAR).unwrap(),
+            Rate::ONE
+        );
+    }
+}
@@ -0,0 +1,516 @@
+//! Reads prices from the oracle configured on a bank.
+//!
+//! Every supported oracle has an adapter implementing `Oracle` that loads its account and converts its price into an `OraclePrice`, so the rest of the program doesn't care where a price comes from.
+
+mod pyth_legacy;
+mod pyth_pull;
+mod switchboard;
+
+use anchor_lang::prelude::*;
+
+use pyth_legacy::PythLegacyOracle;
+use pyth_pull::PythPullOracle;
+use switchboard::SwitchboardOracle;
+
+use crate::constants::{BASIS_POINTS, MAX_PRICE_AGE};
+use crate::errors::ErrorCode;
+use crate::math::{mul_div, Rounding, Wad};
+use crate::state::{Bank, OracleConfig, OracleType};
+
+/// Checks that an oracle configuration can be stored on a bank
+pub fn validate_oracle_config(oracle: &OracleConfig) -> Result<()> {
+    if oracle.feed_id == [0; 32]
+        || oracle.max_age == 0
+        || oracle.max_age > MAX_PRICE_AGE
+        || oracle.max_confidence == 0
+        || oracle.max_confidence > BASIS_POINTS
+    {
+        return Err(ErrorCode::InvalidOracleConfig.into());
+    }
+
+    Ok(())
+}
+
+/// Checks the secondary oracle of a bank and the divergence it is allowed to have with the primary one
+pub fn validate_secondary_oracle(
+    secondary_oracle: &Option<OracleConfig>,
+    max_oracle_divergence: u64,
+) -> Result<()> {
+    if let Some(secondary_oracle) = secondary_oracle {
+        validate_oracle_config(secondary_oracle)?;
+
+        if max_oracle_divergence == 0 || max_oracle_divergence > BASIS_POINTS {
+            return Err(ErrorCode::InvalidOracleConfig.into());
+        }
+    }
+
+    Ok(())
+}
+
+/// Price of an asset in USD, `price * 10^exponent` per whole token, with a confidence interval of `conf` in the same units
+#[derive(Clone, Copy, Debug)]
+pub struct OraclePrice {
+    pub price: i64,
+    pub conf: u64,
+    pub exponent: i32,
+}
+
+/// Adapter for a kind of oracle account
+pub trait Oracle {
+    /// Returns the latest price of the feed, failing if the account isn't for the configured feed or if the price is older than `max_age`
+    fn get_price(&self, config: &OracleConfig, clock: &Clock) -> Result<OraclePrice>;
+}
+
+/// Loads the price of a bank's asset from `account` with the adapter of the oracle configured on the bank.
+///
+/// The account has to be for the feed configured on the bank, otherwise anyone could price an asset with the feed of another one.
+pub fn get_price(
+    account: &AccountInfo,
+    config: &OracleConfig,
+    clock: &Clock,
+) -> Result<OraclePrice> {
+    let price = match config.oracle_type {
+        OracleType::PythPull => PythPullOracle::load(account)?.get_price(config, clock)?,
+        OracleType::PythLegacy => PythLegacyOracle::load(account)?.get_price(config, clock)?,
+        OracleType::Switchboard => SwitchboardOracle::load(account)?.get_price(config, clock)?,
+    };
+
+    check_confidence(&price, config)?;
+
+    Ok(price)
+}
+
+/// Price of a bank's asset, along with whether its two oracles disagree
+#[derive(Clone, Copy, Debug)]
+pub struct BankPrice {
+    pub price: OraclePrice,
+    pub diverged: bool,
+}
+
+impl BankPrice {
+    /// Price to use for actions that add risk to the protocol (borrowing, withdrawing collateral), which are refused while the oracles disagree
+    pub fn for_risk_increase(&self) -> Result<OraclePrice> {
+        if self.diverged {
+            return Err(ErrorCode::OracleDivergence.into());
+        }
+
+        Ok(self.price)
+    }
+}
+
+/// Loads the price of a bank's asset, falling back to the secondary oracle of the bank when the primary price is stale.
+///
+/// `secondary_account` has to be passed whenever the bank has a secondary oracle, even if the primary price is fine, so that the two prices can be compared.
+/// Both prices being available but further apart than `max_oracle_divergence` means one of them is wrong. We can't tell which, so the primary price is still returned but flagged as diverged.
+///
+/// Only `StalePrice` falls back: an oracle that is merely late will catch up, while any other error (wrong account, unverified update, confidence too wide) means the oracle can't be trusted and fails the instruction.
+pub fn get_bank_price(
+    bank: &Bank,
+    primary_account: &AccountInfo,
+    secondary_account: Option<&AccountInfo>,
+    clock: &Clock,
+) -> Result<BankPrice> {
+    let Some(secondary_oracle) = &bank.secondary_oracle else {
+        return Ok(BankPrice {
+            price: get_price(primary_account, &bank.oracle, clock)?,
+            diverged: false,
+        });
+    };
+
+    let secondary_account = secondary_account.ok_or(ErrorCode::MissingSecondaryOracle)?;
+
+    let primary_price = get_price(primary_account, &bank.oracle, clock);
+    let secondary_price = get_price(secondary_account, secondary_oracle, clock);
+
+    match (primary_price, secondary_price) {
+        (Ok(primary_price), Ok(secondary_price)) => Ok(BankPrice {
+            price: primary_price,
+            diverged: divergence(&primary_price, &secondary_price)? > bank.max_oracle_divergence,
+        }),
+        (Ok(price), Err(error)) | (Err(error), Ok(price)) if is_stale(&error) => Ok(BankPrice {
+            price,
+            diverged: false,
+        }),
+        // Report why the primary oracle failed when it did, it is the one that is expected to work
+        (Err(error), _) | (_, Err(error)) => Err(error),
+    }
+}
+
+fn is_stale(error: &Error) -> bool {
+    *error == ErrorCode::StalePrice.into()
+}
+
+/// Difference between two prices of the same asset, in basis points of the lower one
+fn divergence(a: &OraclePrice, b: &OraclePrice) -> Result<u64> {
+    // Prices of different oracles can have different exponents, so they are compared as the USD value of one whole token
+    let a = usd_value(1, 0, a, Rounding::Down)?;
+    let b = usd_value(1, 0, b, Rounding::Down)?;
+
+    let (low, high) = if a < b { (a, b) } else { (b, a) };
+
+    let divergence = mul_div(high.0 - low.0, BASIS_POINTS as u128, low.0, Rounding::Up)?;
+
+    Ok(u64::try_from(divergence).unwrap_or(u64::MAX))
+}
+
+/// Rejects a price whose confidence interval is wider than the bank accepts, which happens when the publishers disagree during volatile markets.
+fn check_confidence(price: &OraclePrice, oracle: &OracleConfig) -> Result<()> {
+    let price_value = positive_price(price)?;
+
+    if (price.conf as u128) * (BASIS_POINTS as u128) > price_value * (oracle.max_confidence as u128)
+    {
+        return Err(ErrorCode::PriceConfidenceTooWide.into());
+    }
+
+    Ok(())
+}
+
+/// Bottom of the confidence interval, used to value collateral so that it is never overestimated
+pub fn lower_bound_price(price: &OraclePrice) -> OraclePrice {
+    OraclePrice {
+        price: price.price.saturating_sub(price.conf as i64),
+        ..*price
+    }
+}
+
+/// Top of the confidence interval, used to value debt so that it is never underestimated
+pub fn upper_bound_price(price: &OraclePrice) -> OraclePrice {
+    OraclePrice {
+        price: price.price.saturating_add(price.conf as i64),
+        ..*price
+    }
+}
+
+/// USD value of `amount` base units of a token with `decimals` decimals, as a `Wad`.
+///
+/// Prices are `price * 10^exponent` USD per whole token, so both the exponent of the price and the decimals of the mint have to be taken into account before values of different assets can be compared.
+pub fn usd_value(
+    amount: u64,
+    decimals: u8,
+    price: &OraclePrice,
+    rounding: Rounding,
+) -> Result<Wad> {
+    let price_value = positive_price(price)?;
+
+    let numerator = (amount as u128)
+        .checked_mul(price_value)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    // value = amount / 10^decimals * price * 10^exponent, scaled by 10^18 to get a Wad
+    let shift = 18 + price.exponent - decimals as i32;
+
+    let value = if shift >= 0 {
+        mul_div(numerator, pow10(shift)?, 1, rounding)?
+    } else {
+        mul_div(numerator, 1, pow10(-shift)?, rounding)?
+    };
+
+    Ok(Wad::from_raw(value))
+}
+
+/// Number of base units of a token with `decimals` decimals worth `value` USD, the inverse of `usd_value`.
+pub fn token_amount(
+    value: Wad,
+    decimals: u8,
+    price: &OraclePrice,
+    rounding: Rounding,
+) -> Result<u64> {
+    let price_value = positive_price(price)?;
+
+    let shift = 18 + price.exponent - decimals as i32;
+
+    let amount = if shift >= 0 {
+        let denominator = price_value
+            .checked_mul(pow10(shift)?)
+            .ok_or(ErrorCode::MathOverflow)?;
+
+        mul_div(value.0, 1, denominator, rounding)?
+    } else {
+        mul_div(value.0, pow10(-shift)?, price_value, rounding)?
+    };
+
+    u64::try_from(amount).map_err(|_| ErrorCode::MathOverflow.into())
+}
+
+fn positive_price(price: &OraclePrice) -> Result<u128> {
+    if price.price <= 0 {
+        return Err(ErrorCode::InvalidPrice.into());
+    }
+
+    Ok(price.price as u128)
+}
+
+fn pow10(exponent: i32) -> Result<u128> {
+    10u128
+        .checked_pow(exponent as u32)
+        .ok_or_else(|| ErrorCode::MathOverflow.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::pyth_legacy::tests::price_account_data;
+    use super::pyth_pull::tests::{price_update_data, FEED_ID, NOW};
+    use super::*;
+
+    /// SOL at $150.12345678, with the exponent Pyth uses for it
+    const SOL_PRICE: OraclePrice = OraclePrice {
+        price: 15_012_345_678,
+        conf: 0,
+        exponent: -8,
+    };
+
+    /// USDC at $0.99995
+    const USDC_PRICE: OraclePrice = OraclePrice {
+        price: 99_995_000,
+        conf: 0,
+        exponent: -8,
+    };
+
+    /// A price small enough for `usd_value` to divide rather than multiply: $0.000000000001 per token
+    const TINY_PRICE: OraclePrice = OraclePrice {
+        price: 1,
+        conf: 0,
+        exponent: -12,
+    };
+
+    #[test]
+    fn usd_value_of_a_9_decimal_mint() {
+        // 2.5 SOL * $150.12345678 = $375.30864195
+        assert_eq!(
+            usd_value(2_500_000_000, 9, &SOL_PRICE, Rounding::Down).unwrap(),
+            Wad::from_raw(375_308_641_950_000_000_000)
+        );
+    }
+
+    #[test]
+    fn usd_value_of_a_6_decimal_mint() {
+        // 1234.567891 USDC * $0.99995 = $1234.50616260545
+        assert_eq!(
+            usd_value(1_234_567_891, 6, &USDC_PRICE, Rounding::Down).unwrap(),
+            Wad::from_raw(1_234_506_162_605_450_000_000)
+        );
+    }
+
+    #[test]
+    fn usd_value_rounds_when_the_exponent_is_below_the_wad_precision() {
+        // 1999 base units of a 9 decimal token at $10^-12 are worth 1.999 * 10^-18 USD
+        assert_eq!(
+            usd_value(1_999, 9, &TINY_PRICE, Rounding::Down).unwrap(),
+            Wad::from_raw(1)
+        );
+        assert_eq!(
+            usd_value(1_999, 9, &TINY_PRICE, Rounding::Up).unwrap(),
+            Wad::from_raw(2)
+        );
+    }
+
+    #[test]
+    fn token_amount_is_the_inverse_of_usd_value() {
+        let value = Wad::from_raw(375_308_641_950_000_000_000);
+        assert_eq!(
+            token_amount(value, 9, &SOL_PRICE, Rounding::Down).unwrap(),
+            2_500_000_000
+        );
+
+        let value = Wad::from_raw(1_234_506_162_605_450_000_000);
+        assert_eq!(
+            token_amount(value, 6, &USDC_PRICE, Rounding::Down).unwrap(),
+            1_234_567_891
+        );
+    }
+
+    #[test]
+    fn token_amount_rounds_in_the_requested_direction() {
+        // $1 / $150.12345678 = 0.0066611842... SOL
+        assert_eq!(
+            token_amount(Wad::ONE, 9, &SOL_PRICE, Rounding::Down).unwrap(),
+            6_661_184
+        );
+        assert_eq!(
+            token_amount(Wad::ONE, 9, &SOL_PRICE, Rounding::Up).unwrap(),
+            6_661_185
+        );
+
+        // 10^-17 USD / $7 * 10^-12 = 1428.57... base units of a 9 decimal token
+        let price = OraclePrice {
+            price: 7,
+            ..TINY_PRICE
+        };
+        assert_eq!(
+            token_amount(Wad::from_raw(10), 9, &price, Rounding::Down).unwrap(),
+            1_428
+        );
+        assert_eq!(
+            token_amount(Wad::from_raw(10), 9, &price, Rounding::Up).unwrap(),
+            1_429
+        );
+    }
+
+    #[test]
+    fn non_positive_prices_are_rejected() {
+        for price in [0, -1] {
+            let price = OraclePrice { price, ..SOL_PRICE };
+
+            assert_eq!(
+                usd_value(1, 9, &price, Rounding::Down).unwrap_err(),
+                ErrorCode::InvalidPrice.into()
+            );
+            assert_eq!(
+                token_amount(Wad::ONE, 9, &price, Rounding::Down).unwrap_err(),
+                ErrorCode::InvalidPrice.into()
+            );
+        }
+    }
+
+    /// Price of a bank priced by a Pyth pull feed with a legacy Pyth price account as secondary oracle, allowed to diverge by 2%
+    fn bank_price(
+        primary_data: &mut [u8],
+        secondary_data: Option<&mut [u128]>,
+    ) -> Result<BankPrice> {
+        let (primary_key, secondary_key) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let (mut primary_lamports, mut secondary_lamports) = (0, 0);
+        let primary_account = AccountInfo::new(
+            &primary_key,
+            false,
+            false,
+            &mut primary_lamports,
+            primary_data,
+            &pyth_solana_receiver_sdk::ID,
+            false,
+            0,
+        );
+        let secondary_account = secondary_data.map(|data| {
+            AccountInfo::new(
+                &secondary_key,
+                false,
+                false,
+                &mut secondary_lamports,
+                bytemuck::cast_slice_mut(data),
+                &secondary_key,
+                false,
+                0,
+            )
+        });
+
+        let bank = Bank {
+            oracle: pyth_pull::tests::config(FEED_ID),
+            secondary_oracle: Some(pyth_legacy::tests::config(&secondary_key)),
+            max_oracle_divergence: 200,
+            ..Bank::default()
+        };
+        let clock = Clock {
+            unix_timestamp: NOW,
+            ..Clock::default()
+        };
+
+        get_bank_price(&bank, &primary_account, secondary_account.as_ref(), &clock)
+    }
+
+    #[test]
+    fn bank_price_is_the_primary_price_when_both_oracles_agree() {
+        let mut primary = price_update_data(99_995_000, 25_000, -8, NOW);
+        let mut secondary = price_account_data(100_000_000, 30_000, -8, NOW);
+
+        let price = bank_price(&mut primary, Some(&mut secondary)).unwrap();
+
+        assert!(!price.diverged);
+        assert_eq!(price.for_risk_increase().unwrap().price, 99_995_000);
+    }
+
+    #[test]
+    fn bank_price_falls_back_to_the_secondary_oracle_when_the_primary_is_stale() {
+        let mut primary = price_update_data(99_995_000, 25_000, -8, NOW - 61);
+        let mut secondary = price_account_data(100_000_000, 30_000, -8, NOW);
+
+        let price = bank_price(&mut primary, Some(&mut secondary)).unwrap();
+
+        assert!(!price.diverged);
+        assert_eq!(price.price.price, 100_000_000);
+    }
+
+    #[test]
+    fn bank_price_ignores_a_stale_secondary_oracle() {
+        let mut primary = price_update_data(99_995_000, 25_000, -8, NOW);
+        let mut secondary = price_account_data(100_000_000, 30_000, -8, NOW - 61);
+
+        let price = bank_price(&mut primary, Some(&mut secondary)).unwrap();
+
+        assert!(!price.diverged);
+        assert_eq!(price.price.price, 99_995_000);
+    }
+
+    #[test]
+    fn bank_price_fails_when_both_oracles_are_stale() {
+        let mut primary = price_update_data(99_995_000, 25_000, -8, NOW - 61);
+        let mut secondary = price_account_data(100_000_000, 30_000, -8, NOW - 61);
+
+        assert_eq!(
+            bank_price(&mut primary, Some(&mut secondary)).unwrap_err(),
+            ErrorCode::StalePrice.into()
+        );
+    }
+
+    #[test]
+    fn bank_price_only_falls_back_on_stale_prices() {
+        // A confidence interval of 2% is wider than the 1% the bank accepts
+        let mut primary = price_update_data(99_995_000, 2_000_000, -8, NOW);
+        let mut secondary = price_account_data(100_000_000, 30_000, -8, NOW);
+
+        assert_eq!(
+            bank_price(&mut primary, Some(&mut secondary)).unwrap_err(),
+            ErrorCode::PriceConfidenceTooWide.into()
+        );
+
+        let mut primary = price_update_data(99_995_000, 25_000, -8, NOW);
+        let mut secondary = price_account_data(100_000_000, 2_000_000, -8, NOW);
+
+        assert_eq!(
+            bank_price(&mut primary, Some(&mut secondary)).unwrap_err(),
+            ErrorCode::PriceConfidenceTooWide.into()
+        );
+    }
+
+    #[test]
+    fn bank_price_is_flagged_when_the_oracles_diverge() {
+        // $1.03 is 3.005% above $0.99995, more than the 2% the bank allows
+        let mut primary = price_update_data(99_995_000, 25_000, -8, NOW);
+        let mut secondary = price_account_data(103_000_000, 30_000, -8, NOW);
+
+        let price = bank_price(&mut primary, Some(&mut secondary)).unwrap();
+
+        assert!(price.diverged);
+        assert_eq!(price.price.price, 99_995_000);
+        assert_eq!(
+            price.for_risk_increase().unwrap_err(),
+            ErrorCode::OracleDivergence.into()
+        );
+    }
+
+    #[test]
+    fn bank_price_needs_the_secondary_oracle_of_the_bank() {
+        let mut primary = price_update_data(99_995_000, 25_000, -8, NOW);
+
+        assert_eq!(
+            bank_price(&mut primary, None).unwrap_err(),
+            ErrorCode::MissingSecondaryOracle.into()
+        );
+    }
+
+    #[test]
+    fn divergence_is_in_basis_points_of_the_lower_price() {
+        let low = OraclePrice {
+            price: 100_000_000,
+            conf: 0,
+            exponent: -8,
+        };
+        // Same price with a different exponent
+        let high = OraclePrice {
+            price: 1_020_000,
+            conf: 0,
+            exponent: -6,
+        };
+
+        assert_eq!(divergence(&low, &high).unwrap(), 200);
+        assert_eq!(divergence(&high, &low).unwrap(), 200);
+    }
+}
@@ -0,0 +1,138 @@
+//! Adapter for the legacy Pyth push oracle, where each feed lives in its own price account updated by the publishers
+
+use anchor_lang::prelude::*;
+use pyth_sdk_solana::state::SolanaPriceAccount;
+use pyth_sdk_solana::PriceFeed;
+
+use super::{Oracle, OraclePrice};
+use crate::errors::ErrorCode;
+use crate::state::OracleConfig;
+
+pub struct PythLegacyOracle {
+    feed: PriceFeed,
+}
+
+impl PythLegacyOracle {
+    pub fn load(account: &AccountInfo) -> Result<Self> {
+        let feed = SolanaPriceAccount::account_info_to_feed(account)
+            .map_err(|_| ErrorCode::InvalidPriceFeed)?;
+
+        Ok(Self { feed })
+    }
+}
+
+impl Oracle for PythLegacyOracle {
+    fn get_price(&self, config: &OracleConfig, clock: &Clock) -> Result<OraclePrice> {
+        // The id of a push feed is the address of its price account
+        if self.feed.id.to_bytes() != config.feed_id {
+            return Err(ErrorCode::InvalidPriceFeed.into());
+        }
+
+        let price = self
+            .feed
+            .get_price_no_older_than(clock.unix_timestamp, config.max_age)
+            .ok_or(ErrorCode::StalePrice)?;
+
+        Ok(OraclePrice {
+            price: price.price,
+            conf: price.conf,
+            exponent: price.expo,
+        })
+    }
+}
+
+#[cfg(test)]
+pub(super) mod tests {
+    use std::mem::size_of;
+
+    use pyth_sdk_solana::state::{AccountType, PriceStatus, MAGIC, VERSION_2};
+
+    use super::*;
+
+    pub const NOW: i64 = 1_700_000_000;
+
+    /// Data of a price account whose aggregate price was published at `timestamp`.
+    /// It is backed by u128s because the SDK casts the data in place, which needs it to be aligned like the account struct.
+    pub fn price_account_data(price: i64, conf: u64, expo: i32, timestamp: i64) -> Vec<u128> {
+        let mut account = SolanaPriceAccount {
+            magic: MAGIC,
+            ver: VERSION_2,
+            atype: AccountType::Price as u32,
+            expo,
+            timestamp,
+            ..SolanaPriceAccount::default()
+        };
+        account.agg.price = price;
+        account.agg.conf = conf;
+        account.agg.status = PriceStatus::Trading;
+
+        let mut data = vec![0u128; size_of::<SolanaPriceAccount>().div_ceil(16)];
+        bytemuck::cast_slice_mut::<u128, u8>(&mut data)[..size_of::<SolanaPriceAccount>()]
+            .copy_from_slice(bytemuck::bytes_of(&account));
+        data
+    }
+
+    pub fn config(key: &Pubkey) -> OracleConfig {
+        OracleConfig {
+            oracle_type: crate::state::OracleType::PythLegacy,
+            feed_id: key.to_bytes(),
+            max_age: 60,
+            max_confidence: 100,
+        }
+    }
+
+    fn price_of(data: &mut [u128], config_key: Option<Pubkey>) -> Result<OraclePrice> {
+        let (key, owner) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut lamports = 0;
+        let data = bytemuck::cast_slice_mut(data);
+        let account = AccountInfo::new(&key, false, false, &mut lamports, data, &owner, false, 0);
+        let clock = Clock {
+            unix_timestamp: NOW,
+            ..Clock::default()
+        };
+
+        PythLegacyOracle::load(&account)?.get_price(&config(&config_key.unwrap_or(key)), &clock)
+    }
+
+    #[test]
+    fn reads_the_aggregate_price() {
+        let mut data = price_account_data(15_012_345_678, 1_500_000, -8, NOW - 10);
+
+        let price = price_of(&mut data, None).unwrap();
+
+        assert_eq!(price.price, 15_012_345_678);
+        assert_eq!(price.conf, 1_500_000);
+        assert_eq!(price.exponent, -8);
+    }
+
+    #[test]
+    fn rejects_accounts_that_are_not_price_accounts() {
+        let mut data = price_account_data(15_012_345_678, 1_500_000, -8, NOW);
+        bytemuck::cast_slice_mut::<u128, u8>(&mut data)[0] ^= 1;
+
+        assert_eq!(
+            price_of(&mut data, None).unwrap_err(),
+            ErrorCode::InvalidPriceFeed.into()
+        );
+    }
+
+    #[test]
+    fn rejects_the_feed_of_another_asset() {
+        let mut data = price_account_data(15_012_345_678, 1_500_000, -8, NOW);
+
+        assert_eq!(
+            price_of(&mut data, Some(Pubkey::new_unique())).unwrap_err(),
+            ErrorCode::InvalidPriceFeed.into()
+        );
+    }
+
+    #[test]
+    fn stale_prices_are_reported_as_stale() {
+        let mut data = price_account_data(15_012_345_678, 1_500_000, -8, NOW - 61);
+
+        assert_eq!(
+            price_of(&mut data, None).unwrap_err(),
+            ErrorCode::StalePrice.into()
+        );
+    }
+}
@@ -0,0 +1,168 @@
+//! Adapter for the Pyth pull oracle, where prices are posted on demand as `PriceUpdateV2` accounts by the pyth-solana-receiver program
+
+use anchor_lang::prelude::*;
+use pyth_solana_receiver_sdk::error::GetPriceError;
+use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
+
+use super::{Oracle, OraclePrice};
+use crate::errors::ErrorCode;
+use crate::state::OracleConfig;
+
+pub struct PythPullOracle {
+    price_update: PriceUpdateV2,
+}
+
+impl PythPullOracle {
+    /// Deserializes a `PriceUpdateV2` account, which has to be owned by the receiver program so that nobody can post their own price
+    pub fn load(account: &AccountInfo) -> Result<Self> {
+        if account.owner != &pyth_solana_receiver_sdk::ID {
+            return Err(ErrorCode::InvalidPriceFeed.into());
+        }
+
+        let data = account.try_borrow_data()?;
+        let price_update = PriceUpdateV2::try_deserialize(&mut &data[..])
+            .map_err(|_| ErrorCode::InvalidPriceFeed)?;
+
+        Ok(Self { price_update })
+    }
+}
+
+impl Oracle for PythPullOracle {
+    fn get_price(&self, config: &OracleConfig, clock: &Clock) -> Result<OraclePrice> {
+        // A single receiver account can hold any feed, so the feed id has to be checked explicitly
+        if self.price_update.price_message.feed_id != config.feed_id {
+            return Err(ErrorCode::InvalidPriceFeed.into());
+        }
+
+        // Only an old price is reported as stale, so that the bank can fall back to its secondary oracle.
+        // A partially verified update is a price that can't be trusted, not one that will be fixed by the next update.
+        let price = self
+            .price_update
+            .get_price_no_older_than(clock, config.max_age, &config.feed_id)
+            .map_err(|error| match error {
+                GetPriceError::PriceTooOld => ErrorCode::StalePrice,
+                _ => ErrorCode::InvalidPriceFeed,
+            })?;
+
+        Ok(OraclePrice {
+            price: price.price,
+            conf: price.conf,
+            exponent: price.exponent,
+        })
+    }
+}
+
+#[cfg(test)]
+pub(super) mod tests {
+    use pyth_solana_receiver_sdk::price_update::{PriceFeedMessage, VerificationLevel};
+
+    use super::*;
+
+    pub const NOW: i64 = 1_700_000_000;
+
+    pub const FEED_ID: [u8; 32] = [7; 32];
+
+    /// Fully verified price update of `FEED_ID` published at `publish_time`
+    pub fn price_update(price: i64, conf: u64, exponent: i32, publish_time: i64) -> PriceUpdateV2 {
+        PriceUpdateV2 {
+            write_authority: Pubkey::new_unique(),
+            verification_level: VerificationLevel::Full,
+            price_message: PriceFeedMessage {
+                feed_id: FEED_ID,
+                price,
+                conf,
+                exponent,
+                publish_time,
+                prev_publish_time: publish_time - 1,
+                ema_price: price,
+                ema_conf: conf,
+            },
+            posted_slot: 1_234,
+        }
+    }
+
+    /// Data of the account holding `price_update`
+    pub fn account_data(price_update: &PriceUpdateV2) -> Vec<u8> {
+        let mut data = Vec::with_capacity(PriceUpdateV2::LEN);
+        price_update.try_serialize(&mut data).unwrap();
+        data
+    }
+
+    pub fn price_update_data(price: i64, conf: u64, exponent: i32, publish_time: i64) -> Vec<u8> {
+        account_data(&price_update(price, conf, exponent, publish_time))
+    }
+
+    pub fn config(feed_id: [u8; 32]) -> OracleConfig {
+        OracleConfig {
+            oracle_type: crate::state::OracleType::PythPull,
+            feed_id,
+            max_age: 60,
+            max_confidence: 100,
+        }
+    }
+
+    fn price_of(data: &mut [u8], owner: &Pubkey, feed_id: [u8; 32]) -> Result<OraclePrice> {
+        let key = Pubkey::new_unique();
+        let mut lamports = 0;
+        let account = AccountInfo::new(&key, false, false, &mut lamports, data, owner, false, 0);
+        let clock = Clock {
+            unix_timestamp: NOW,
+            ..Clock::default()
+        };
+
+        PythPullOracle::load(&account)?.get_price(&config(feed_id), &clock)
+    }
+
+    #[test]
+    fn reads_the_posted_price() {
+        let mut data = price_update_data(99_995_000, 25_000, -8, NOW - 10);
+
+        let price = price_of(&mut data, &pyth_solana_receiver_sdk::ID, FEED_ID).unwrap();
+
+        assert_eq!(price.price, 99_995_000);
+        assert_eq!(price.conf, 25_000);
+        assert_eq!(price.exponent, -8);
+    }
+
+    #[test]
+    fn rejects_accounts_not_owned_by_the_receiver() {
+        let mut data = price_update_data(99_995_000, 25_000, -8, NOW);
+
+        assert_eq!(
+            price_of(&mut data, &Pubkey::new_unique(), FEED_ID).unwrap_err(),
+            ErrorCode::InvalidPriceFeed.into()
+        );
+    }
+
+    #[test]
+    fn rejects_the_feed_of_another_asset() {
+        let mut data = price_update_data(99_995_000, 25_000, -8, NOW);
+
+        assert_eq!(
+            price_of(&mut data, &pyth_solana_receiver_sdk::ID, [8; 32]).unwrap_err(),
+            ErrorCode::InvalidPriceFeed.into()
+        );
+    }
+
+    #[test]
+    fn old_prices_are_reported_as_stale() {
+        let mut data = price_update_data(99_995_000, 25_000, -8, NOW - 61);
+
+        assert_eq!(
+            price_of(&mut data, &pyth_solana_receiver_sdk::ID, FEED_ID).unwrap_err(),
+            ErrorCode::StalePrice.into()
+        );
+    }
+
+    #[test]
+    fn partially_verified_prices_are_not_reported_as_stale() {
+        let mut price_update = price_update(99_995_000, 25_000, -8, NOW);
+        price_update.verification_level = VerificationLevel::Partial { num_signatures: 13 };
+        let mut data = account_data(&price_update);
+
+        assert_eq!(
+            price_of(&mut data, &pyth_solana_receiver_sdk::ID, FEED_ID).unwrap_err(),
+            ErrorCode::InvalidPriceFeed.into()
+        );
+    }
+}
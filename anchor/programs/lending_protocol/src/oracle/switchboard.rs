@@ -0,0 +1,351 @@
+//! Adapter for Switchboard On-Demand pull feeds.
+//!
+//! We only need the current result of a feed, so instead of pulling in the whole Switchboard SDK the fields are read at their offsets in `PullFeedAccountData`.
+//! The account is a zero-copy `#[repr(C)]` struct behind an 8 bytes discriminator, so the offsets below are fixed for as long as Switchboard doesn't change the discriminator.
+
+use anchor_lang::prelude::*;
+
+use super::{Oracle, OraclePrice};
+use crate::errors::ErrorCode;
+use crate::state::OracleConfig;
+
+/// Switchboard On-Demand program on mainnet, the only one that can write pull feeds
+pub const SWITCHBOARD_ON_DEMAND_PROGRAM_ID: Pubkey =
+    pubkey!("SBondMDrcV3K4kxZR1HNVT7osZxAHVHgYXL5Ze1oMUv");
+
+/// Switchboard On-Demand program on devnet
+pub const SWITCHBOARD_ON_DEMAND_DEVNET_PROGRAM_ID: Pubkey =
+    pubkey!("Aio4gaXjXzJNVLtzwtNVmSqGKpANtXhybbkhtAC94ji2");
+
+/// Anchor discriminator of `PullFeedAccountData`
+const PULL_FEED_DISCRIMINATOR: [u8; 8] = [196, 27, 108, 196, 10, 215, 219, 40];
+
+/// Size of a pull feed account, discriminator included
+const PULL_FEED_LEN: usize = 8 + 3200;
+
+/// `result.value`, the median of the submissions as an i128 with 18 decimals
+const RESULT_VALUE_OFFSET: usize = 8 + 2256;
+
+/// `result.std_dev`, the standard deviation of the submissions with 18 decimals
+const RESULT_STD_DEV_OFFSET: usize = 8 + 2272;
+
+/// `result.submission_idx`, the submission the result was taken from
+const RESULT_SUBMISSION_IDX_OFFSET: usize = 8 + 2353;
+
+/// `result.slot`, zero until the feed got its first result
+const RESULT_SLOT_OFFSET: usize = 8 + 2360;
+
+/// `submission_timestamps`, the unix timestamp of each of the 32 submissions
+const SUBMISSION_TIMESTAMPS_OFFSET: usize = 8 + 2944;
+
+/// Number of decimals of the values of a pull feed
+const PULL_FEED_DECIMALS: i32 = 18;
+
+pub struct SwitchboardOracle {
+    key: Pubkey,
+    value: i128,
+    std_dev: i128,
+    slot: u64,
+    timestamp: i64,
+}
+
+impl SwitchboardOracle {
+    /// Reads the current result of a pull feed, which has to be owned by the Switchboard program so that nobody can write their own result
+    pub fn load(account: &AccountInfo) -> Result<Self> {
+        if account.owner != &SWITCHBOARD_ON_DEMAND_PROGRAM_ID
+            && account.owner != &SWITCHBOARD_ON_DEMAND_DEVNET_PROGRAM_ID
+        {
+            return Err(ErrorCode::InvalidPriceFeed.into());
+        }
+
+        let data = account.try_borrow_data()?;
+        if data.len() < PULL_FEED_LEN || data[..8] != PULL_FEED_DISCRIMINATOR {
+            return Err(ErrorCode::InvalidPriceFeed.into());
+        }
+
+        let submission_idx = data[RESULT_SUBMISSION_IDX_OFFSET] as usize;
+        if submission_idx >= 32 {
+            return Err(ErrorCode::InvalidPriceFeed.into());
+        }
+
+        Ok(Self {
+            key: account.key(),
+            value: i128::from_le_bytes(read(&data, RESULT_VALUE_OFFSET)),
+            std_dev: i128::from_le_bytes(read(&data, RESULT_STD_DEV_OFFSET)),
+            slot: u64::from_le_bytes(read(&data, RESULT_SLOT_OFFSET)),
+            timestamp: i64::from_le_bytes(read(
+                &data,
+                SUBMISSION_TIMESTAMPS_OFFSET + 8 * submission_idx,
+            )),
+        })
+    }
+}
+
+impl Oracle for SwitchboardOracle {
+    fn get_price(&self, config: &OracleConfig, clock: &Clock) -> Result<OraclePrice> {
+        // The id of a pull feed is the address of its account
+        if self.key.to_bytes() != config.feed_id {
+            return Err(ErrorCode::InvalidPriceFeed.into());
+        }
+
+        // A feed that never got a result has no price at all, which is a configuration problem rather than a stale price
+        if self.slot == 0 {
+            return Err(ErrorCode::InvalidPrice.into());
+        }
+
+        let age = clock.unix_timestamp.saturating_sub(self.timestamp);
+        if age > config.max_age as i64 {
+            return Err(ErrorCode::StalePrice.into());
+        }
+
+        if self.value <= 0 || self.std_dev < 0 {
+            return Err(ErrorCode::InvalidPrice.into());
+        }
+
+        // Values have 18 decimals, which doesn't fit an i64 for prices above ~$9, so decimals are dropped until it does.
+        // The price is rounded down and the confidence up, so the interval only gets wider.
+        let (mut value, mut std_dev, mut exponent) =
+            (self.value, self.std_dev, -PULL_FEED_DECIMALS);
+        while value > i64::MAX as i128 || std_dev > u64::MAX as i128 {
+            value /= 10;
+            std_dev = std_dev / 10 + i128::from(std_dev % 10 != 0);
+            exponent += 1;
+        }
+
+        Ok(OraclePrice {
+            price: value as i64,
+            conf: std_dev as u64,
+            exponent,
+        })
+    }
+}
+
+/// Reads `N` bytes at `offset`, which is in bounds once the length of the account has been checked
+fn read<const N: usize>(data: &[u8], offset: usize) -> [u8; N] {
+    let mut bytes = [0; N];
+    bytes.copy_from_slice(&data[offset..offset + N]);
+    bytes
+}
+
+#[cfg(test)]
+pub(super) mod tests {
+    use std::mem::{offset_of, size_of};
+
+    use super::*;
+
+    /// Layout of a pull feed as declared by `PullFeedAccountData` in switchboard-on-demand 0.13, only used to place the fields of test accounts.
+    #[allow(dead_code)]
+    #[repr(C)]
+    struct PullFeedAccountData {
+        submissions: [[u8; 64]; 32],
+        authority: Pubkey,
+        queue: Pubkey,
+        feed_hash: [u8; 32],
+        initialized_at: i64,
+        permissions: u64,
+        max_variance: u64,
+        min_responses: u32,
+        name: [u8; 32],
+        padding1: [u8; 1],
+        permit_write_by_authority: u8,
+        historical_result_idx: u8,
+        min_sample_size: u8,
+        last_update_timestamp: i64,
+        lut_slot: u64,
+        reserved1: [u8; 32],
+        result: CurrentResult,
+        max_staleness: u32,
+        padding2: [u8; 12],
+        historical_results: [[u8; 16]; 32],
+        ebuf4: [u8; 8],
+        ebuf3: [u8; 24],
+        submission_timestamps: [i64; 32],
+    }
+
+    #[allow(dead_code)]
+    #[repr(C)]
+    struct CurrentResult {
+        value: i128,
+        std_dev: i128,
+        mean: i128,
+        range: i128,
+        min_value: i128,
+        max_value: i128,
+        num_samples: u8,
+        submission_idx: u8,
+        padding1: [u8; 6],
+        slot: u64,
+        min_slot: u64,
+        max_slot: u64,
+    }
+
+    pub const NOW: i64 = 1_700_000_000;
+
+    /// Data of a pull feed account whose current result was submitted at `timestamp`
+    pub fn pull_feed_data(value: i128, std_dev: i128, timestamp: i64) -> Vec<u8> {
+        let result = 8 + offset_of!(PullFeedAccountData, result);
+        let submission_idx = 3;
+
+        let mut data = vec![0; 8 + size_of::<PullFeedAccountData>()];
+        let mut write = |offset: usize, bytes: &[u8]| {
+            data[offset..offset + bytes.len()].copy_from_slice(bytes);
+        };
+
+        write(0, &PULL_FEED_DISCRIMINATOR);
+        write(
+            result + offset_of!(CurrentResult, value),
+            &value.to_le_bytes(),
+        );
+        write(
+            result + offset_of!(CurrentResult, std_dev),
+            &std_dev.to_le_bytes(),
+        );
+        write(
+            result + offset_of!(CurrentResult, submission_idx),
+            &[submission_idx],
+        );
+        write(
+            result + offset_of!(CurrentResult, slot),
+            &1_234u64.to_le_bytes(),
+        );
+        write(
+            8 + offset_of!(PullFeedAccountData, submission_timestamps)
+                + 8 * submission_idx as usize,
+            &timestamp.to_le_bytes(),
+        );
+
+        data
+    }
+
+    pub fn config(key: &Pubkey) -> OracleConfig {
+        OracleConfig {
+            oracle_type: crate::state::OracleType::Switchboard,
+            feed_id: key.to_bytes(),
+            max_age: 60,
+            max_confidence: 100,
+        }
+    }
+
+    fn clock() -> Clock {
+        Clock {
+            unix_timestamp: NOW,
+            ..Clock::default()
+        }
+    }
+
+    fn price_of(
+        data: &mut [u8],
+        owner: &Pubkey,
+        config_key: Option<Pubkey>,
+    ) -> Result<OraclePrice> {
+        let key = Pubkey::new_unique();
+        let mut lamports = 0;
+        let account = AccountInfo::new(&key, false, false, &mut lamports, data, owner, false, 0);
+
+        SwitchboardOracle::load(&account)?.get_price(&config(&config_key.unwrap_or(key)), &clock())
+    }
+
+    #[test]
+    fn offsets_match_the_switchboard_layout() {
+        let result = 8 + offset_of!(PullFeedAccountData, result);
+
+        assert_eq!(PULL_FEED_LEN, 8 + size_of::<PullFeedAccountData>());
+        assert_eq!(
+            RESULT_VALUE_OFFSET,
+            result + offset_of!(CurrentResult, value)
+        );
+        assert_eq!(
+            RESULT_STD_DEV_OFFSET,
+            result + offset_of!(CurrentResult, std_dev)
+        );
+        assert_eq!(
+            RESULT_SUBMISSION_IDX_OFFSET,
+            result + offset_of!(CurrentResult, submission_idx)
+        );
+        assert_eq!(RESULT_SLOT_OFFSET, result + offset_of!(CurrentResult, slot));
+        assert_eq!(
+            SUBMISSION_TIMESTAMPS_OFFSET,
+            8 + offset_of!(PullFeedAccountData, submission_timestamps)
+        );
+    }
+
+    #[test]
+    fn reads_the_current_result() {
+        // $150.123456789012345678 doesn't fit an i64 with 18 decimals, so it is read with 16
+        let mut data = pull_feed_data(
+            150_123_456_789_012_345_678,
+            25_000_000_000_000_001,
+            NOW - 10,
+        );
+
+        let price = price_of(&mut data, &SWITCHBOARD_ON_DEMAND_PROGRAM_ID, None).unwrap();
+
+        assert_eq!(price.price, 1_501_234_567_890_123_456);
+        assert_eq!(price.conf, 250_000_000_000_001);
+        assert_eq!(price.exponent, -16);
+    }
+
+    #[test]
+    fn keeps_all_the_decimals_of_small_prices() {
+        let mut data = pull_feed_data(999_950_000_000_000_000, 0, NOW);
+
+        let price = price_of(&mut data, &SWITCHBOARD_ON_DEMAND_DEVNET_PROGRAM_ID, None).unwrap();
+
+        assert_eq!(price.price, 999_950_000_000_000_000);
+        assert_eq!(price.exponent, -18);
+    }
+
+    #[test]
+    fn rejects_accounts_not_owned_by_switchboard() {
+        let mut data = pull_feed_data(150 * 10i128.pow(18), 0, NOW);
+
+        assert_eq!(
+            price_of(&mut data, &Pubkey::new_unique(), None).unwrap_err(),
+            ErrorCode::InvalidPriceFeed.into()
+        );
+    }
+
+    #[test]
+    fn rejects_other_accounts_of_the_switchboard_program() {
+        let mut data = pull_feed_data(150 * 10i128.pow(18), 0, NOW);
+        data[0] ^= 1;
+
+        assert_eq!(
+            price_of(&mut data, &SWITCHBOARD_ON_DEMAND_PROGRAM_ID, None).unwrap_err(),
+            ErrorCode::InvalidPriceFeed.into()
+        );
+
+        let mut data = pull_feed_data(150 * 10i128.pow(18), 0, NOW);
+        data.truncate(PULL_FEED_LEN - 1);
+
+        assert_eq!(
+            price_of(&mut data, &SWITCHBOARD_ON_DEMAND_PROGRAM_ID, None).unwrap_err(),
+            ErrorCode::InvalidPriceFeed.into()
+        );
+    }
+
+    #[test]
+    fn rejects_the_feed_of_another_asset() {
+        let mut data = pull_feed_data(150 * 10i128.pow(18), 0, NOW);
+
+        assert_eq!(
+            price_of(
+                &mut data,
+                &SWITCHBOARD_ON_DEMAND_PROGRAM_ID,
+                Some(Pubkey::new_unique())
+            )
+            .unwrap_err(),
+            ErrorCode::InvalidPriceFeed.into()
+        );
+    }
+
+    #[test]
+    fn stale_results_are_reported_as_stale() {
+        let mut data = pull_feed_data(150 * 10i128.pow(18), 0, NOW - 61);
+
+        assert_eq!(
+            price_of(&mut data, &SWITCHBOARD_ON_DEMAND_PROGRAM_ID, None).unwrap_err(),
+            ErrorCode::StalePrice.into()
+        );
+    }
+}
@@ -1,58 +1,1132 @@
 use anchor_lang::prelude::*;
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
-pub enum TokenType {
-    SOL,
-    USDC,
+use crate::authority::check_authority;
+use crate::constants::{
+    BASIS_POINTS, MAX_ACCRUAL_PERIOD, MAX_CONFIG_TIMELOCK, MAX_MULTISIG_SIGNERS,
+    MAX_PENDING_CONFIG_CHANGES, MAX_POSITIONS, MAX_RATE,
+};
+use crate::errors::ErrorCode;
+use crate::math::{compound_interest, per_second_rate, Rate, Rounding, Wad};
+use crate::oracle::{validate_oracle_config, validate_secondary_oracle};
+
+/// Kind of account a bank reads its price from
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, InitSpace)]
+pub enum OracleType {
+    /// `PriceUpdateV2` account posted by the Pyth pull oracle (pyth-solana-receiver)
+    #[default]
+    PythPull,
+    /// Legacy Pyth push price account, the feed id is the address of the account
+    PythLegacy,
+    /// Switchboard On-Demand pull feed account, the feed id is the address of the account
+    Switchboard,
+}
+
+/// Oracle a bank prices its asset with
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, InitSpace)]
+pub struct OracleConfig {
+    pub oracle_type: OracleType,
+    /// Id of the price feed, e.g. the Pyth SOL/USD feed id, or the address of the price account for push oracles
+    pub feed_id: [u8; 32],
+    /// Maximum age in seconds of a price before it is considered stale
+    pub max_age: u64,
+    /// Maximum width of the confidence interval, in basis points of the price, before a price is considered unreliable
+    pub max_confidence: u64,
+}
+
+/// A lending market groups banks whose assets can be used as collateral for each other.
+///
+/// Markets are isolated from each other: banks and users are seeded by their market, so the same mint can be listed in several markets with different risk profiles and a user has a separate account in each of them.
+#[account]
+#[derive(InitSpace)]
+pub struct LendingMarket {
+    /// Admin of the market
+    pub admin: Pubkey,
+    /// Name of the market, e.g. to tell a main market from an isolated one, padded with zeros
+    pub name: [u8; 32],
+    /// Emergency key that can pause the market and its banks but never unpause them
+    pub guardian: Option<Pubkey>,
+    /// Operations paused for every bank of the market, see the `PAUSE_*` constants
+    pub paused: u8,
+}
+
+impl LendingMarket {
+    /// Checks that `admin` is the admin of the market and approved the instruction, see `check_authority`
+    pub fn check_admin(&self, admin: &AccountInfo, signers: &[AccountInfo]) -> Result<()> {
+        if self.admin != admin.key() {
+            return Err(ErrorCode::NotMarketAdmin.into());
+        }
+
+        check_authority(admin, signers)
+    }
+
+    /// Whether `signer` can pause the market or one of its banks
+    pub fn can_pause(&self, signer: &Pubkey) -> bool {
+        self.admin == *signer || self.guardian == Some(*signer)
+    }
+}
+
+/// M-of-N multisig that can be set as the admin of a market or the authority of a bank instead of a single key.
+///
+/// It's a PDA so nobody holds its private key: admin instructions accept it as the authority once `threshold` of its `signers` signed the transaction, see `check_authority`.
+#[account]
+#[derive(InitSpace)]
+pub struct Multisig {
+    #[max_len(MAX_MULTISIG_SIGNERS)]
+    pub signers: Vec<Pubkey>,
+    /// Number of signers needed to act as the multisig
+    pub threshold: u8,
+}
+
+impl Multisig {
+    /// Checks that the signers are distinct and that the threshold can be met
+    pub fn validate(&self) -> Result<()> {
+        let signers = &self.signers;
+
+        if signers.is_empty() || signers.len() > MAX_MULTISIG_SIGNERS {
+            return Err(ErrorCode::InvalidMultisig.into());
+        }
+
+        if signers
+            .iter()
+            .enumerate()
+            .any(|(i, signer)| signers[..i].contains(signer))
+        {
+            return Err(ErrorCode::InvalidMultisig.into());
+        }
+
+        if self.threshold == 0 || self.threshold as usize > signers.len() {
+            return Err(ErrorCode::InvalidMultisig.into());
+        }
+
+        Ok(())
+    }
+
+    /// Checks that at least `threshold` distinct signers of the multisig are among the signing `accounts`
+    pub fn check_signatures(&self, accounts: &[AccountInfo]) -> Result<()> {
+        let approvals = self
+            .signers
+            .iter()
+            .filter(|signer| {
+                accounts
+                    .iter()
+                    .any(|account| account.is_signer && account.key == *signer)
+            })
+            .count();
+
+        if approvals < self.threshold as usize {
+            return Err(ErrorCode::MultisigThresholdNotMet.into());
+        }
+
+        Ok(())
+    }
 }
 
 #[account]
 #[derive(InitSpace)]
 pub struct User {
     pub owner: Pubkey,
+    /// The lending market this account holds positions in
+    pub market: Pubkey,
 
-    // TODO: use a more robust approach to store currencies
-    pub deposited_sol: u64,
-    pub deposited_sol_shares: u64,
-    pub borrowed_sol: u64,
-    pub borrowed_sol_shares: u64,
-
-    pub deposited_usdc: u64,
-    pub deposited_usdc_shares: u64,
-    pub borrowed_usdc: u64,
-    pub borrowed_usdc_shares: u64,
+    /// One position per bank the user interacted with. A new bank can be listed for any mint without a change to this account.
+    #[max_len(MAX_POSITIONS)]
+    pub positions: Vec<Position>,
 
     pub last_updated: i64,
 }
 
-/// Since there will be a bank for each asset on the lending protocol - we need to keep it's state after intialization
+/// Deposit and borrow of a user in a single bank
+///
+/// Only shares are stored. The token amounts they are worth are derived from the indices of the bank, so they always include the accrued interest.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct Position {
+    /// The bank account this position is held in
+    pub bank: Pubkey,
+    pub deposit_shares: u64,
+    pub borrow_shares: u64,
+}
+
+impl User {
+    /// Position of the user in `bank`, if they have one
+    pub fn position(&self, bank: &Pubkey) -> Option<&Position> {
+        self.positions
+            .iter()
+            .find(|position| position.bank == *bank)
+    }
+
+    /// Mutable position of the user in `bank`, opening an empty one if they don't have one yet
+    pub fn position_mut(&mut self, bank: &Pubkey) -> Result<&mut Position> {
+        let index = match self.positions.iter().position(|p| p.bank == *bank) {
+            Some(index) => index,
+            None => {
+                if self.positions.len() >= MAX_POSITIONS {
+                    return Err(ErrorCode::MaxPositionsReached.into());
+                }
+
+                self.positions.push(Position {
+                    bank: *bank,
+                    deposit_shares: 0,
+                    borrow_shares: 0,
+                });
+
+                self.positions.len() - 1
+            }
+        };
+
+        Ok(&mut self.positions[index])
+    }
+
+    /// Deposit shares the user holds in `bank`
+    pub fn deposit_shares(&self, bank: &Pubkey) -> u64 {
+        self.position(bank)
+            .map_or(0, |position| position.deposit_shares)
+    }
+
+    /// Borrow shares the user owes to `bank`
+    pub fn borrow_shares(&self, bank: &Pubkey) -> u64 {
+        self.position(bank)
+            .map_or(0, |position| position.borrow_shares)
+    }
+
+    /// Drops the positions that have been fully withdrawn and repaid so their slot can be reused for another bank
+    pub fn close_empty_positions(&mut self) {
+        self.positions
+            .retain(|position| position.deposit_shares > 0 || position.borrow_shares > 0);
+    }
+}
+
+/// Changes to the risk parameters, interest rate model and oracles of a bank. Fields left as `None` keep their current value.
+///
+/// Units are the same as the matching fields of `Bank`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, InitSpace)]
+pub struct BankConfigUpdate {
+    pub max_ltv: Option<u64>,
+    pub liquidation_threshold: Option<u64>,
+    pub liquidation_close_factor: Option<u64>,
+    pub min_liquidation_bonus: Option<u64>,
+    pub max_liquidation_bonus: Option<u64>,
+    pub liquidation_bonus_floor_health: Option<u64>,
+    pub base_rate: Option<u64>,
+    pub optimal_utilization: Option<u64>,
+    pub slope_1: Option<u64>,
+    pub slope_2: Option<u64>,
+    pub max_utilization: Option<u64>,
+    pub reserve_factor: Option<u64>,
+    pub insurance_factor: Option<u64>,
+    pub liquidation_insurance_fee: Option<u64>,
+    pub config_timelock: Option<u64>,
+    pub oracle: Option<OracleConfig>,
+    /// `Some(None)` removes the secondary oracle
+    pub secondary_oracle: Option<Option<OracleConfig>>,
+    pub max_oracle_divergence: Option<u64>,
+}
+
+/// Configuration changes of a bank waiting for their timelock to expire, stored in a PDA per bank so that anyone can see them coming
 #[account]
 #[derive(InitSpace)]
+pub struct ConfigQueue {
+    /// The bank the changes apply to
+    pub bank: Pubkey,
+    /// Id given to the next queued change
+    pub next_id: u64,
+    #[max_len(MAX_PENDING_CONFIG_CHANGES)]
+    pub changes: Vec<PendingConfigChange>,
+}
+
+/// A configuration change queued by the authority of a bank
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct PendingConfigChange {
+    pub id: u64,
+    /// Time after which the change can be executed
+    pub eta: i64,
+    pub update: BankConfigUpdate,
+}
+
+/// Since there will be a bank for each asset on the lending protocol - we need to keep it's state after intialization
+#[account]
+#[derive(Default, InitSpace)]
 pub struct Bank {
+    /// The lending market the bank is listed in
+    pub market: Pubkey,
     pub authority: Pubkey,
+    /// Authority proposed by the current one, it only takes over once it accepts. See `propose_bank_authority`.
+    pub pending_authority: Option<Pubkey>,
+    /// Operations paused for this bank only, see the `PAUSE_*` constants
+    pub paused: u8,
     /// The mint address of the asset. Mint is a spl token program used to create the asset.
     pub mint_address: Pubkey,
-    /// The total amount of deposits in the bank.
+    /// Decimals of the mint, kept on the bank so that positions can be valued without loading the mint account.
+    pub decimals: u8,
+    /// The total amount of deposits in the bank, interest included as of `last_updated`.
     pub total_deposits: u64,
     /// The total amount of deposits shares in the bank.
     pub total_deposits_shares: u64,
-    /// The total amount of borrows in the bank.
+    /// The total amount of borrows in the bank, interest included as of `last_updated`.
     pub total_borrows: u64,
     /// The total amount of borrows shares in the bank.
     pub total_borrows_shares: u64,
+    /// Maximum of `total_deposits` after a deposit, 0 for no cap. Lets a new asset be listed with a bounded exposure first.
+    pub deposit_cap: u64,
+    /// Maximum of `total_borrows` after a borrow, 0 for no cap.
+    pub borrow_cap: u64,
 
     /// Since all the accounts in the protocal can be liquidated, we need all these constants to calculate whether an account is healthy or not.
     ///
     /// Loan to value at which a loan is defined as under-collateralized and can be liquidated.
     pub liquidation_threshold: u64,
-    /// % of bonus that will be given to the liquidator as bonus for processing the liquidation.
-    pub liquidation_bonus: u64,
+    /// Bonus, in basis points of the repaid debt, that will be given to the liquidator for processing the liquidation.
+    ///
+    /// It works like a dutch auction: the bonus starts at `min_liquidation_bonus` when the health factor falls below 1 and grows linearly to `max_liquidation_bonus` as the health factor drops to `liquidation_bonus_floor_health`.
+    pub min_liquidation_bonus: u64,
+    pub max_liquidation_bonus: u64,
+    /// Health factor, in basis points, at and below which the liquidator gets `max_liquidation_bonus`
+    pub liquidation_bonus_floor_health: u64,
     /// % of collateral that can be liquidated
     pub liquidation_close_factor: u64,
     /// Maximum percentage of collateral that can be borrowed for a specific asset. Collateral is the asset that is used to secure the loan
     pub max_ltv: u64,
 
-    pub interest_rate: u64,
+    /// Oracle used to price the asset of the bank, both as collateral and as debt.
+    pub oracle: OracleConfig,
+
+    /// Optional second oracle for the same asset, used only when the primary price is stale. Any other failure of the primary fails the instruction.
+    pub secondary_oracle: Option<OracleConfig>,
 
+    /// Maximum difference, in basis points, between the primary and secondary prices before the bank stops accepting risk-increasing actions
+    pub max_oracle_divergence: u64,
+
+    /// Interest rate model of the bank. All rates are annualized and expressed in basis points.
+    ///
+    /// Borrow rate charged when nothing is borrowed.
+    pub base_rate: u64,
+    /// Utilization at which the rate curve kinks and `slope_2` takes over.
+    pub optimal_utilization: u64,
+    /// Rate added on top of `base_rate` as utilization climbs from 0 to `optimal_utilization`.
+    pub slope_1: u64,
+    /// Rate added on top of `base_rate + slope_1` as utilization climbs from `optimal_utilization` to 100%.
+    pub slope_2: u64,
+    /// Highest utilization a borrow can take the bank to, in basis points. Keeps some liquidity in the bank token account for the depositors to withdraw.
+    pub max_utilization: u64,
+
+    /// Share of the interest paid by borrowers that goes to the protocol instead of the depositors, in basis points.
+    pub reserve_factor: u64,
+    /// Protocol fees accrued and not yet collected, in tokens. These tokens sit in the bank token account but do not belong to the depositors.
+    pub accumulated_protocol_fees: u64,
+
+    /// Share of the interest paid by borrowers that funds the insurance fund of the bank, in basis points.
+    pub insurance_factor: u64,
+    /// Share of the liquidation bonus that goes to the insurance fund of the collateral bank instead of the liquidator, in basis points.
+    pub liquidation_insurance_fee: u64,
+    /// Insurance fees accrued and not yet moved to the insurance fund, in tokens. Like the protocol fees they sit in the bank token account until then.
+    pub accumulated_insurance_fees: u64,
+
+    /// Delay, in seconds, between queuing a configuration change and being able to execute it. Gives users time to react to new risk parameters.
+    pub config_timelock: u64,
+
+    /// Amount of tokens owed per borrow share, as a `Rate`. Compounds every second at the borrow rate.
+    pub cumulative_borrow_index: u128,
+    /// Amount of tokens owned per deposit share, as a `Rate`. Grows by the interest paid by the borrowers.
+    pub supply_index: u128,
+
+    /// Last time interest was accrued into the indices.
     pub last_updated: i64,
 }
+
+impl Bank {
+    /// Share of the deposits that is currently borrowed, in basis points.
+    pub fn utilization_rate(&self) -> u64 {
+        if self.total_deposits == 0 {
+            return 0;
+        }
+
+        let utilization =
+            (self.total_borrows as u128 * BASIS_POINTS as u128) / self.total_deposits as u128;
+
+        utilization.min(BASIS_POINTS as u128) as u64
+    }
+
+    /// Annual borrow rate for the current utilization.
+    ///
+    /// The curve is linear up to `optimal_utilization` and gets a much steeper slope after it, pushing borrowers to repay (and depositors to supply) before the bank runs out of liquidity.
+    pub fn borrow_rate(&self) -> u64 {
+        let utilization = self.utilization_rate();
+
+        if utilization <= self.optimal_utilization {
+            if self.optimal_utilization == 0 {
+                return self.base_rate;
+            }
+
+            self.base_rate + self.slope_1 * utilization / self.optimal_utilization
+        } else {
+            let excess_utilization = utilization - self.optimal_utilization;
+            let max_excess_utilization = BASIS_POINTS - self.optimal_utilization;
+
+            self.base_rate
+                + self.slope_1
+                + self.slope_2 * excess_utilization / max_excess_utilization
+        }
+    }
+
+    /// Fails if `operation` is paused, either for this bank or for its whole market
+    pub fn check_not_paused(&self, market: &LendingMarket, operation: u8) -> Result<()> {
+        if (self.paused | market.paused) & operation != 0 {
+            return Err(ErrorCode::ProtocolPaused.into());
+        }
+
+        Ok(())
+    }
+
+    /// Fails if `total_deposits` is above the deposit cap of the bank
+    pub fn check_deposit_cap(&self) -> Result<()> {
+        if self.deposit_cap != 0 && self.total_deposits > self.deposit_cap {
+            return Err(ErrorCode::DepositCapExceeded.into());
+        }
+
+        Ok(())
+    }
+
+    /// Fails if `total_borrows` is above the borrow cap of the bank
+    pub fn check_borrow_cap(&self) -> Result<()> {
+        if self.borrow_cap != 0 && self.total_borrows > self.borrow_cap {
+            return Err(ErrorCode::BorrowCapExceeded.into());
+        }
+
+        Ok(())
+    }
+
+    /// Fails if the borrows are above `max_utilization` of the deposits.
+    ///
+    /// Unlike `utilization_rate` the ratio is not capped at 100%, so that a bank with more borrows than deposits is rejected too.
+    pub fn check_max_utilization(&self) -> Result<()> {
+        if self.total_borrows as u128 * BASIS_POINTS as u128
+            > self.total_deposits as u128 * self.max_utilization as u128
+        {
+            return Err(ErrorCode::MaxUtilizationExceeded.into());
+        }
+
+        Ok(())
+    }
+
+    /// Applies `update` to the configuration of the bank and checks that the result is consistent.
+    ///
+    /// Interest has to be accrued before, otherwise the new rate model would apply to the time elapsed under the old one.
+    pub fn apply_config_update(&mut self, update: &BankConfigUpdate) -> Result<()> {
+        let fields = [
+            (&mut self.max_ltv, update.max_ltv),
+            (
+                &mut self.liquidation_threshold,
+                update.liquidation_threshold,
+            ),
+            (
+                &mut self.liquidation_close_factor,
+                update.liquidation_close_factor,
+            ),
+            (
+                &mut self.min_liquidation_bonus,
+                update.min_liquidation_bonus,
+            ),
+            (
+                &mut self.max_liquidation_bonus,
+                update.max_liquidation_bonus,
+            ),
+            (
+                &mut self.liquidation_bonus_floor_health,
+                update.liquidation_bonus_floor_health,
+            ),
+            (&mut self.base_rate, update.base_rate),
+            (&mut self.optimal_utilization, update.optimal_utilization),
+            (&mut self.slope_1, update.slope_1),
+            (&mut self.slope_2, update.slope_2),
+            (&mut self.max_utilization, update.max_utilization),
+            (&mut self.reserve_factor, update.reserve_factor),
+            (&mut self.insurance_factor, update.insurance_factor),
+            (
+                &mut self.liquidation_insurance_fee,
+                update.liquidation_insurance_fee,
+            ),
+            (&mut self.config_timelock, update.config_timelock),
+            (
+                &mut self.max_oracle_divergence,
+                update.max_oracle_divergence,
+            ),
+        ];
+
+        for (field, value) in fields {
+            if let Some(value) = value {
+                *field = value;
+            }
+        }
+
+        if let Some(oracle) = &update.oracle {
+            self.oracle = oracle.clone();
+        }
+
+        if let Some(secondary_oracle) = &update.secondary_oracle {
+            self.secondary_oracle = secondary_oracle.clone();
+        }
+
+        validate_oracle_config(&self.oracle)?;
+        validate_secondary_oracle(&self.secondary_oracle, self.max_oracle_divergence)?;
+
+        self.validate_config()
+    }
+
+    /// Checks the invariants between the risk parameters and the interest rate model of the bank
+    pub fn validate_config(&self) -> Result<()> {
+        // A loan must be liquidatable before it is under-collateralized, and it must be possible to borrow before it is liquidatable
+        if self.liquidation_threshold == 0 || self.liquidation_threshold > 100 {
+            return Err(ErrorCode::InvalidLiquidationThreshold.into());
+        }
+
+        if self.max_ltv == 0 || self.max_ltv >= self.liquidation_threshold {
+            return Err(ErrorCode::InvalidMaxLtv.into());
+        }
+
+        if self.liquidation_close_factor == 0 || self.liquidation_close_factor > 100 {
+            return Err(ErrorCode::InvalidCloseFactor.into());
+        }
+
+        // Seizing `repaid * (1 + bonus)` of collateral removes `repaid * (1 + bonus) * liquidation_threshold` of borrowing power for `repaid` of debt.
+        // Liquidations only improve the health of an account if that is less than the debt repaid, even at the maximum bonus.
+        if self.min_liquidation_bonus > self.max_liquidation_bonus
+            || self.liquidation_bonus_floor_health >= BASIS_POINTS
+            || self
+                .liquidation_threshold
+                .saturating_mul(BASIS_POINTS.saturating_add(self.max_liquidation_bonus))
+                >= 100 * BASIS_POINTS
+        {
+            return Err(ErrorCode::InvalidLiquidationBonus.into());
+        }
+
+        // Rates are added and multiplied by the utilization in `borrow_rate`, then compounded in `accrue_interest`, so they have to be bounded
+        if self.optimal_utilization == 0
+            || self.optimal_utilization > BASIS_POINTS
+            || self.base_rate > MAX_RATE
+            || self.slope_1 > MAX_RATE
+            || self.slope_2 > MAX_RATE
+        {
+            return Err(ErrorCode::InvalidInterestRateModel.into());
+        }
+
+        if self.max_utilization == 0 || self.max_utilization > BASIS_POINTS {
+            return Err(ErrorCode::InvalidMaxUtilization.into());
+        }
+
+        if self.reserve_factor.saturating_add(self.insurance_factor) > BASIS_POINTS
+            || self.liquidation_insurance_fee > BASIS_POINTS
+        {
+            return Err(ErrorCode::InvalidFeeConfig.into());
+        }
+
+        // A timelock that is too long would lock the configuration of the bank for good
+        if self.config_timelock > MAX_CONFIG_TIMELOCK {
+            return Err(ErrorCode::InvalidConfigTimelock.into());
+        }
+
+        Ok(())
+    }
+
+    /// Bonus given to the liquidator of an account with `health_factor`, as a ratio of the repaid debt.
+    ///
+    /// Barely unhealthy accounts only lose the minimum bonus, while the bonus of deeply underwater ones keeps growing until some liquidator finds it worth it.
+    pub fn liquidation_bonus(&self, health_factor: Wad) -> Result<Wad> {
+        let min_bonus = Wad::from_bps(self.min_liquidation_bonus)?;
+        let max_bonus = Wad::from_bps(self.max_liquidation_bonus)?;
+        let floor_health = Wad::from_bps(self.liquidation_bonus_floor_health)?;
+
+        if health_factor >= Wad::ONE {
+            return Ok(min_bonus);
+        }
+
+        if health_factor <= floor_health || max_bonus <= min_bonus {
+            return Ok(max_bonus.max(min_bonus));
+        }
+
+        // How far the health factor went from 1 towards the floor, between 0 and 1
+        let progress = Wad::ONE
+            .checked_sub(health_factor)?
+            .checked_div(Wad::ONE.checked_sub(floor_health)?, Rounding::Down)?;
+
+        min_bonus.checked_add(
+            max_bonus
+                .checked_sub(min_bonus)?
+                .checked_mul(progress, Rounding::Down)?,
+        )
+    }
+
+    /// Accrues interest from `last_updated` up to `current_time` into the indices and the totals.
+    ///
+    /// Must be called before any instruction reads or changes the balances of the bank, so that every share is priced with the interest up to this second.
+    /// Interest is charged for `MAX_ACCRUAL_PERIOD` at most, longer gaps would overflow the growth of the index at the highest rates and lock the bank for good.
+    pub fn accrue_interest(&mut self, current_time: i64) -> Result<()> {
+        let elapsed = current_time.saturating_sub(self.last_updated);
+
+        if elapsed <= 0 {
+            return Ok(());
+        }
+
+        self.last_updated = current_time;
+
+        if self.total_borrows_shares == 0 {
+            return Ok(());
+        }
+
+        let growth = compound_interest(
+            per_second_rate(self.borrow_rate())?,
+            (elapsed as u64).min(MAX_ACCRUAL_PERIOD),
+        )?;
+
+        self.cumulative_borrow_index = Rate::from_raw(self.cumulative_borrow_index)
+            .checked_mul(growth, Rounding::Up)?
+            .0;
+
+        let total_borrows = self.borrow_amount(self.total_borrows_shares, Rounding::Up)?;
+        let interest = total_borrows.saturating_sub(self.total_borrows);
+
+        self.total_borrows = total_borrows;
+
+        // The reserve factor of the interest is set aside for the protocol and the insurance factor for the insurance fund
+        let protocol_fee =
+            Wad::from_bps(self.reserve_factor)?.apply_to(interest, Rounding::Down)?;
+        let insurance_fee =
+            Wad::from_bps(self.insurance_factor)?.apply_to(interest, Rounding::Down)?;
+        let depositors_interest = interest.saturating_sub(protocol_fee + insurance_fee);
+
+        self.accumulated_protocol_fees = self
+            .accumulated_protocol_fees
+            .checked_add(protocol_fee)
+            .ok_or(ErrorCode::MathOverflow)?;
+        self.accumulated_insurance_fees = self
+            .accumulated_insurance_fees
+            .checked_add(insurance_fee)
+            .ok_or(ErrorCode::MathOverflow)?;
+
+        // The rest of the interest paid by the borrowers is distributed to the depositors through the supply index
+        if self.total_deposits_shares > 0 && self.total_deposits > 0 {
+            let total_deposits = self
+                .total_deposits
+                .checked_add(depositors_interest)
+                .ok_or(ErrorCode::MathOverflow)?;
+
+            self.supply_index = Rate::from_raw(self.supply_index)
+                .checked_mul(
+                    Rate::from_ratio(
+                        total_deposits as u128,
+                        self.total_deposits as u128,
+                        Rounding::Down,
+                    )?,
+                    Rounding::Down,
+                )?
+                .0;
+
+            self.total_deposits = self.deposit_amount(self.total_deposits_shares)?;
+        }
+
+        Ok(())
+    }
+
+    /// Spreads a loss of `amount` tokens over every depositor of the bank by lowering the supply index, the opposite of how interest is distributed.
+    ///
+    /// A loss of all the deposits would take the supply index to 0, and every later deposit divides by it to mint shares.
+    /// So the depositors always keep at least one token between them, the part of the loss above that can't be taken from anyone anyway.
+    pub fn socialize_loss(&mut self, amount: u64) -> Result<()> {
+        let loss = amount.min(self.total_deposits.saturating_sub(1));
+        if loss == 0 {
+            return Ok(());
+        }
+
+        let remaining_deposits = self.total_deposits - loss;
+
+        // Rounding can't take the index to 0 either, even after many losses
+        self.supply_index = Rate::from_raw(self.supply_index)
+            .checked_mul(
+                Rate::from_ratio(
+                    remaining_deposits as u128,
+                    self.total_deposits as u128,
+                    Rounding::Down,
+                )?,
+                Rounding::Down,
+            )?
+            .0
+            .max(1);
+
+        self.total_deposits = self.deposit_amount(self.total_deposits_shares)?;
+
+        Ok(())
+    }
+
+    /// Amount of tokens the given deposit shares are currently worth. Rounded down, in favour of the bank.
+    pub fn deposit_amount(&self, shares: u64) -> Result<u64> {
+        Rate::from_raw(self.supply_index).apply_to(shares, Rounding::Down)
+    }
+
+    /// Number of deposit shares worth `amount` tokens.
+    pub fn deposit_shares(&self, amount: u64, rounding: Rounding) -> Result<u64> {
+        Rate::from_raw(self.supply_index).apply_inverse_to(amount, rounding)
+    }
+
+    /// Amount of tokens owed for the given borrow shares.
+    pub fn borrow_amount(&self, shares: u64, rounding: Rounding) -> Result<u64> {
+        Rate::from_raw(self.cumulative_borrow_index).apply_to(shares, rounding)
+    }
+
+    /// Number of borrow shares that represent a debt of `amount` tokens.
+    pub fn borrow_shares(&self, amount: u64, rounding: Rounding) -> Result<u64> {
+        Rate::from_raw(self.cumulative_borrow_index).apply_inverse_to(amount, rounding)
+    }
+
+    /// Protocol fees that can be collected right now.
+    ///
+    /// Fees accrue as part of the debt, so until borrowers repay, the tokens backing them may not be in the bank token account yet.
+    /// The tokens the depositors can withdraw (deposits minus borrows) are never counted as collectable.
+    pub fn collectable_protocol_fees(&self, bank_token_balance: u64) -> u64 {
+        self.fee_liquidity(bank_token_balance)
+            .min(self.accumulated_protocol_fees)
+    }
+
+    /// Insurance fees that can be moved to the insurance fund right now, same as `collectable_protocol_fees`
+    pub fn collectable_insurance_fees(&self, bank_token_balance: u64) -> u64 {
+        self.fee_liquidity(bank_token_balance)
+            .min(self.accumulated_insurance_fees)
+    }
+
+    /// Tokens of the bank token account that can be borrowed or withdrawn, the fees waiting in it belong to the treasury and the insurance fund
+    pub fn available_liquidity(&self, bank_token_balance: u64) -> u64 {
+        bank_token_balance - self.fee_liquidity(bank_token_balance)
+    }
+
+    /// Tokens of the bank token account that don't belong to the depositors
+    fn fee_liquidity(&self, bank_token_balance: u64) -> u64 {
+        let depositors_liquidity = self.total_deposits.saturating_sub(self.total_borrows);
+
+        bank_token_balance.saturating_sub(depositors_liquidity)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{account, signer};
+
+    /// Bank with the default rate model and a non-zero base rate, so that every part of the curve shows up in the rate
+    fn bank_with_rate_model() -> Bank {
+        Bank {
+            base_rate: 200,
+            optimal_utilization: 8_000,
+            slope_1: 400,
+            slope_2: 7_500,
+            total_deposits: 1_000_000,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn borrow_rate_is_base_rate_without_borrows() {
+        let bank = bank_with_rate_model();
+
+        assert_eq!(bank.utilization_rate(), 0);
+        assert_eq!(bank.borrow_rate(), 200);
+    }
+
+    #[test]
+    fn borrow_rate_at_the_kink_adds_slope_1() {
+        let bank = Bank {
+            total_borrows: 800_000,
+            ..bank_with_rate_model()
+        };
+
+        assert_eq!(bank.utilization_rate(), 8_000);
+        assert_eq!(bank.borrow_rate(), 200 + 400);
+    }
+
+    #[test]
+    fn borrow_rate_is_linear_on_each_side_of_the_kink() {
+        let below = Bank {
+            total_borrows: 400_000,
+            ..bank_with_rate_model()
+        };
+        let above = Bank {
+            total_borrows: 900_000,
+            ..bank_with_rate_model()
+        };
+
+        assert_eq!(below.borrow_rate(), 200 + 400 / 2);
+        assert_eq!(above.borrow_rate(), 200 + 400 + 7_500 / 2);
+    }
+
+    #[test]
+    fn borrow_rate_at_full_utilization_adds_both_slopes() {
+        let bank = Bank {
+            total_borrows: 1_000_000,
+            ..bank_with_rate_model()
+        };
+
+        assert_eq!(bank.utilization_rate(), BASIS_POINTS);
+        assert_eq!(bank.borrow_rate(), 200 + 400 + 7_500);
+    }
+
+    #[test]
+    fn utilization_is_capped_at_100_percent() {
+        // Interest accrued on the borrows can briefly take them above the deposits
+        let bank = Bank {
+            total_borrows: 1_200_000,
+            ..bank_with_rate_model()
+        };
+
+        assert_eq!(bank.utilization_rate(), BASIS_POINTS);
+        assert_eq!(bank.borrow_rate(), 200 + 400 + 7_500);
+    }
+
+    /// Bank with the default bonus curve: 2% at a health factor of 1 growing to 10% at 0.8
+    fn bank_with_bonus_curve() -> Bank {
+        Bank {
+            min_liquidation_bonus: 200,
+            max_liquidation_bonus: 1_000,
+            liquidation_bonus_floor_health: 8_000,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn liquidation_bonus_is_the_minimum_for_healthy_accounts() {
+        let bank = bank_with_bonus_curve();
+
+        for health_factor in [Wad::ONE, Wad::from_bps(15_000).unwrap()] {
+            assert_eq!(
+                bank.liquidation_bonus(health_factor).unwrap(),
+                Wad::from_bps(200).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn liquidation_bonus_grows_linearly_towards_the_floor() {
+        let bank = bank_with_bonus_curve();
+
+        // A quarter of the way from 1 to 0.8
+        assert_eq!(
+            bank.liquidation_bonus(Wad::from_bps(9_500).unwrap())
+                .unwrap(),
+            Wad::from_bps(400).unwrap()
+        );
+        // Halfway
+        assert_eq!(
+            bank.liquidation_bonus(Wad::from_bps(9_000).unwrap())
+                .unwrap(),
+            Wad::from_bps(600).unwrap()
+        );
+        // The increase is rounded down, so just below 1 the bonus is still the minimum
+        assert_eq!(
+            bank.liquidation_bonus(Wad::from_raw(Wad::ONE.0 - 1))
+                .unwrap(),
+            Wad::from_bps(200).unwrap()
+        );
+    }
+
+    #[test]
+    fn liquidation_bonus_is_the_maximum_at_and_below_the_floor() {
+        let bank = bank_with_bonus_curve();
+
+        for health_factor in [
+            Wad::from_bps(8_000).unwrap(),
+            Wad::from_bps(5_000).unwrap(),
+            Wad::from_raw(0),
+        ] {
+            assert_eq!(
+                bank.liquidation_bonus(health_factor).unwrap(),
+                Wad::from_bps(1_000).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn liquidation_bonus_without_a_curve_is_flat() {
+        let bank = Bank {
+            max_liquidation_bonus: 200,
+            ..bank_with_bonus_curve()
+        };
+
+        assert_eq!(
+            bank.liquidation_bonus(Wad::from_bps(9_000).unwrap())
+                .unwrap(),
+            Wad::from_bps(200).unwrap()
+        );
+    }
+
+    /// Bank holding 1,000,000 tokens of deposits, one share per token
+    fn bank_with_deposits() -> Bank {
+        Bank {
+            total_deposits: 1_000_000,
+            total_deposits_shares: 1_000_000,
+            supply_index: Rate::ONE.0,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn socialized_loss_lowers_every_deposit() {
+        let mut bank = bank_with_deposits();
+
+        bank.socialize_loss(250_000).unwrap();
+
+        assert_eq!(bank.supply_index, Rate::ONE.0 / 4 * 3);
+        assert_eq!(bank.total_deposits, 750_000);
+        assert_eq!(bank.deposit_amount(400_000).unwrap(), 300_000);
+    }
+
+    #[test]
+    fn socialized_loss_above_the_deposits_keeps_the_bank_usable() {
+        let mut bank = bank_with_deposits();
+
+        bank.socialize_loss(5_000_000).unwrap();
+
+        assert!(bank.supply_index > 0);
+        assert_eq!(bank.total_deposits, 1);
+        // New deposits still mint shares, priced at what the old ones are now worth
+        assert_eq!(bank.deposit_shares(1, Rounding::Down).unwrap(), 1_000_000);
+    }
+
+    #[test]
+    fn socialized_loss_never_rounds_the_supply_index_to_zero() {
+        // An index already lowered by earlier losses
+        let mut bank = Bank {
+            supply_index: 10,
+            ..bank_with_deposits()
+        };
+
+        bank.socialize_loss(999_990).unwrap();
+
+        assert_eq!(bank.supply_index, 1);
+    }
+
+    /// Bank with the default configuration of a new bank
+    fn bank_with_default_config() -> Bank {
+        use crate::constants::*;
+
+        Bank {
+            liquidation_threshold: 80,
+            max_ltv: 70,
+            liquidation_close_factor: DEFAULT_LIQUIDATION_CLOSE_FACTOR,
+            min_liquidation_bonus: DEFAULT_MIN_LIQUIDATION_BONUS,
+            max_liquidation_bonus: DEFAULT_MAX_LIQUIDATION_BONUS,
+            liquidation_bonus_floor_health: DEFAULT_LIQUIDATION_BONUS_FLOOR_HEALTH,
+            base_rate: DEFAULT_BASE_RATE,
+            optimal_utilization: DEFAULT_OPTIMAL_UTILIZATION,
+            slope_1: DEFAULT_SLOPE_1,
+            slope_2: DEFAULT_SLOPE_2,
+            max_utilization: DEFAULT_MAX_UTILIZATION,
+            reserve_factor: DEFAULT_RESERVE_FACTOR,
+            insurance_factor: DEFAULT_INSURANCE_FACTOR,
+            liquidation_insurance_fee: DEFAULT_LIQUIDATION_INSURANCE_FEE,
+            config_timelock: DEFAULT_CONFIG_TIMELOCK,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn rates_above_the_maximum_are_rejected() {
+        assert!(bank_with_default_config().validate_config().is_ok());
+
+        for bank in [
+            Bank {
+                base_rate: MAX_RATE + 1,
+                ..bank_with_default_config()
+            },
+            Bank {
+                slope_1: MAX_RATE + 1,
+                ..bank_with_default_config()
+            },
+            Bank {
+                slope_2: MAX_RATE + 1,
+                ..bank_with_default_config()
+            },
+        ] {
+            assert_eq!(
+                bank.validate_config().unwrap_err(),
+                ErrorCode::InvalidInterestRateModel.into()
+            );
+        }
+    }
+
+    #[test]
+    fn a_year_at_the_maximum_rates_does_not_overflow() {
+        let mut bank = Bank {
+            base_rate: MAX_RATE,
+            slope_1: MAX_RATE,
+            slope_2: MAX_RATE,
+            total_deposits: 1_000_000,
+            total_deposits_shares: 1_000_000,
+            total_borrows: 1_000_000,
+            total_borrows_shares: 1_000_000,
+            cumulative_borrow_index: Rate::ONE.0,
+            supply_index: Rate::ONE.0,
+            ..bank_with_default_config()
+        };
+        assert!(bank.validate_config().is_ok());
+        assert_eq!(bank.borrow_rate(), 3 * MAX_RATE);
+
+        bank.accrue_interest(crate::constants::SECONDS_PER_YEAR as i64)
+            .unwrap();
+
+        // e^9 = 8103.08...
+        assert_eq!(bank.cumulative_borrow_index / Rate::ONE.0, 8_103);
+    }
+
+    #[test]
+    fn a_gap_of_several_years_only_accrues_the_maximum_period() {
+        let mut bank = Bank {
+            base_rate: MAX_RATE,
+            slope_1: MAX_RATE,
+            slope_2: MAX_RATE,
+            total_deposits: 1_000_000,
+            total_deposits_shares: 1_000_000,
+            total_borrows: 1_000_000,
+            total_borrows_shares: 1_000_000,
+            cumulative_borrow_index: Rate::ONE.0,
+            supply_index: Rate::ONE.0,
+            ..bank_with_default_config()
+        };
+
+        // e^27 doesn't fit in a `Rate`
+        assert!(compound_interest(
+            per_second_rate(bank.borrow_rate()).unwrap(),
+            3 * crate::constants::SECONDS_PER_YEAR
+        )
+        .is_err());
+
+        let five_years = 5 * crate::constants::SECONDS_PER_YEAR as i64;
+        bank.accrue_interest(five_years).unwrap();
+
+        assert_eq!(bank.cumulative_borrow_index / Rate::ONE.0, 8_103);
+        assert_eq!(bank.last_updated, five_years);
+
+        // The bank keeps working afterwards
+        bank.accrue_interest(five_years + 60).unwrap();
+    }
+
+    fn pyth_oracle(feed_id: u8) -> OracleConfig {
+        OracleConfig {
+            oracle_type: OracleType::PythPull,
+            feed_id: [feed_id; 32],
+            max_age: 60,
+            max_confidence: 100,
+        }
+    }
+
+    #[test]
+    fn a_config_update_replaces_the_oracles() {
+        let mut bank = Bank {
+            oracle: pyth_oracle(1),
+            ..bank_with_default_config()
+        };
+
+        bank.apply_config_update(&BankConfigUpdate {
+            oracle: Some(pyth_oracle(2)),
+            secondary_oracle: Some(Some(pyth_oracle(3))),
+            max_oracle_divergence: Some(500),
+            ..Default::default()
+        })
+        .unwrap();
+        assert_eq!(bank.oracle.feed_id, [2; 32]);
+        assert_eq!(bank.secondary_oracle.as_ref().unwrap().feed_id, [3; 32]);
+        assert_eq!(bank.max_oracle_divergence, 500);
+
+        // Fields left as `None` keep the current oracles
+        bank.apply_config_update(&BankConfigUpdate::default())
+            .unwrap();
+        assert_eq!(bank.oracle.feed_id, [2; 32]);
+        assert!(bank.secondary_oracle.is_some());
+
+        bank.apply_config_update(&BankConfigUpdate {
+            secondary_oracle: Some(None),
+            ..Default::default()
+        })
+        .unwrap();
+        assert!(bank.secondary_oracle.is_none());
+    }
+
+    #[test]
+    fn a_config_update_rejects_an_invalid_oracle() {
+        let bank = Bank {
+            oracle: pyth_oracle(1),
+            ..bank_with_default_config()
+        };
+
+        let stale = OracleConfig {
+            max_age: 0,
+            ..pyth_oracle(2)
+        };
+        assert_eq!(
+            bank.clone()
+                .apply_config_update(&BankConfigUpdate {
+                    oracle: Some(stale),
+                    ..Default::default()
+                })
+                .unwrap_err(),
+            ErrorCode::InvalidOracleConfig.into()
+        );
+
+        // A secondary oracle needs a divergence limit
+        assert_eq!(
+            bank.clone()
+                .apply_config_update(&BankConfigUpdate {
+                    secondary_oracle: Some(Some(pyth_oracle(3))),
+                    ..Default::default()
+                })
+                .unwrap_err(),
+            ErrorCode::InvalidOracleConfig.into()
+        );
+    }
+
+    #[test]
+    fn fees_waiting_in_the_bank_token_account_are_not_available() {
+        let bank = Bank {
+            total_deposits: 1_000,
+            total_borrows: 400,
+            accumulated_protocol_fees: 30,
+            accumulated_insurance_fees: 20,
+            ..Default::default()
+        };
+
+        // The depositors' 600 tokens plus 50 of fees repaid by the borrowers
+        assert_eq!(bank.available_liquidity(650), 600);
+        assert_eq!(bank.collectable_protocol_fees(650), 30);
+        assert_eq!(bank.collectable_insurance_fees(650), 20);
+
+        // Fees not repaid yet don't take anything from the depositors
+        assert_eq!(bank.available_liquidity(500), 500);
+    }
+
+    fn market(admin: Pubkey) -> LendingMarket {
+        LendingMarket {
+            admin,
+            name: [0; 32],
+            guardian: None,
+            paused: 0,
+        }
+    }
+
+    #[test]
+    fn only_the_admin_passes_the_admin_check() {
+        let (admin, someone_else) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let market = market(admin);
+
+        assert!(market.check_admin(&signer(admin), &[]).is_ok());
+        assert_eq!(
+            market.check_admin(&signer(someone_else), &[]).unwrap_err(),
+            ErrorCode::NotMarketAdmin.into()
+        );
+    }
+
+    #[test]
+    fn the_admin_has_to_approve() {
+        let admin = Pubkey::new_unique();
+
+        assert_eq!(
+            market(admin)
+                .check_admin(&account(admin, System::id(), vec![]), &[])
+                .unwrap_err(),
+            ErrorCode::Unauthorized.into()
+        );
+    }
+}
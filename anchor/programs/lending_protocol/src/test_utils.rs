@@ -0,0 +1,104 @@
+//! Helpers to run instructions against accounts built in tests.
+//!
+//! An instruction runs through the same steps as in the program, account validation, handler and exit, so tests cover its constraints too.
+//! The accounts are leaked to get the `'info` lifetime Anchor ties them to, which is fine for the short life of a test.
+//! Anchor CPIs only run on chain, so instructions that create accounts or move tokens can't be run this way.
+
+use std::collections::BTreeSet;
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_pack::Pack;
+use anchor_lang::{AccountsExit, Bumps};
+use anchor_spl::token::spl_token;
+
+use crate::errors::ErrorCode;
+
+/// Account owned by `owner` holding `data`
+pub fn account(key: Pubkey, owner: Pubkey, data: Vec<u8>) -> AccountInfo<'static> {
+    AccountInfo::new(
+        Box::leak(Box::new(key)),
+        false,
+        true,
+        Box::leak(Box::new(1_000_000_000)),
+        Box::leak(data.into_boxed_slice()),
+        Box::leak(Box::new(owner)),
+        false,
+        0,
+    )
+}
+
+/// Key that signs the instruction
+pub fn signer(key: Pubkey) -> AccountInfo<'static> {
+    let mut signer = account(key, System::id(), vec![]);
+    signer.is_signer = true;
+    signer
+}
+
+/// Account of the program holding `value`, with room for the largest value of its type
+pub fn program_account<T: AccountSerialize + Space>(
+    key: Pubkey,
+    value: &T,
+) -> AccountInfo<'static> {
+    let mut data = vec![0; 8 + T::INIT_SPACE];
+    value.try_serialize(&mut &mut data[..]).unwrap();
+
+    account(key, crate::ID, data)
+}
+
+/// SPL token mint with 9 decimals
+pub fn mint_account(key: Pubkey) -> AccountInfo<'static> {
+    let mut data = vec![0; spl_token::state::Mint::LEN];
+    spl_token::state::Mint {
+        decimals: 9,
+        is_initialized: true,
+        ..Default::default()
+    }
+    .pack_into_slice(&mut data);
+
+    account(key, spl_token::ID, data)
+}
+
+/// Bank of `mint` in `market`
+pub fn bank_address(market: &Pubkey, mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[market.as_ref(), mint.as_ref()], &crate::ID).0
+}
+
+/// Runs `handler` on `accounts`, validated as `T`, and writes the accounts back like the program does after a successful instruction
+pub fn process<T>(
+    accounts: Vec<AccountInfo<'static>>,
+    remaining_accounts: Vec<AccountInfo<'static>>,
+    handler: impl FnOnce(Context<T>) -> Result<()>,
+) -> Result<()>
+where
+    T: Accounts<'static, T::Bumps> + AccountsExit<'static> + Bumps,
+    T::Bumps: Default,
+{
+    let mut infos: &'static [AccountInfo<'static>] = accounts.leak();
+    let mut bumps = T::Bumps::default();
+    let mut validated = T::try_accounts(
+        &crate::ID,
+        &mut infos,
+        &[],
+        &mut bumps,
+        &mut BTreeSet::new(),
+    )?;
+
+    handler(Context::new(
+        &crate::ID,
+        &mut validated,
+        remaining_accounts.leak(),
+        bumps,
+    ))?;
+
+    validated.exit(&crate::ID)
+}
+
+/// Reads and deserializes an account of the program
+pub fn read<T: AccountDeserialize>(account: &AccountInfo) -> T {
+    T::try_deserialize(&mut &account.data.borrow()[..]).unwrap()
+}
+
+/// Asserts that an instruction failed with `error`
+pub fn assert_error(result: Result<()>, error: ErrorCode) {
+    assert_eq!(result.unwrap_err(), error.into(), "expected {error:?}");
+}
@@ -1,5 +1,13 @@
 #!/usr/bin/env tsx
-import { Connection, Keypair, PublicKey, LAMPORTS_PER_SOL, Transaction, sendAndConfirmTransaction } from '@solana/web3.js'
+import {
+  Connection,
+  Keypair,
+  PublicKey,
+  LAMPORTS_PER_SOL,
+  Transaction,
+  TransactionInstruction,
+  sendAndConfirmTransaction,
+} from '@solana/web3.js'
 import {
   createMint,
   getOrCreateAssociatedTokenAccount,
@@ -7,14 +15,34 @@ import {
   mintTo,
 } from '@solana/spl-token'
 import {
+  getInitializeMarketInstruction,
   getInitializeBankInstructionAsync,
   getInitializeAccountInstructionAsync,
   getDepositInstructionAsync
 } from '../src/client/js/generated/instructions'
-import { address } from 'gill'
+import { OracleType, type OracleConfigArgs } from '../src/client/js/generated/types'
+import { address, getBase16Encoder, isSignerRole, isWritableRole, type Instruction } from 'gill'
 import { createKeyPairSignerFromBytes, KeyPairSigner } from '@solana/signers'
 import fs from 'fs'
-import { TokenType } from '@project/anchor'
+
+// PROGRAM that owns/controls all PDAs
+const PROGRAM_ID = new PublicKey("9CoY42r3y5WFDJjQX97e9m9THcVGpvuVSKjBjGkiksMR")
+
+/*
+  Pyth sponsored price feeds the banks are priced with.
+  The program reads the PriceUpdateV2 account of a feed, so on localnet it has to be cloned from mainnet when starting the validator.
+*/
+const SOL_USD_FEED_ID = 'ef0d8b6fda2ceba41da15d4095d1da392a0d2f8ed0c6c7bc0f4cfac8c280b56d'
+const USDC_USD_FEED_ID = 'eaa020c61cc479712813461ce153894a96a6c00b21ed0cfc2798d1f9a9e9c94a'
+
+function pythOracle(feedId: string): OracleConfigArgs {
+  return {
+    oracleType: OracleType.PythPull,
+    feedId: getBase16Encoder().encode(feedId),
+    maxAge: 60, // 1 minute
+    maxConfidence: 200, // 2% of the price
+  }
+}
 
 async function main() {
   const connection = new Connection('http://127.0.0.1:8899', 'confirmed')
@@ -73,16 +101,29 @@ async function main() {
     )
     console.log('✅ USDC Mint created:', USDC_MINT.toBase58())
   }
-  
+
+  // Banks are listed in a lending market, administered by the deployer
+  let MARKET: PublicKey
+
+  try {
+    MARKET = new PublicKey(process.env.MARKET!)
+    console.log('✅ Using existing market:', MARKET.toBase58())
+  } catch {
+    console.log('🏛️ Creating lending market...')
+    MARKET = await initializeMarket(connection, deployer, deployerSigner)
+    console.log('✅ Market created:', MARKET.toBase58())
+  }
+
   // Initialize SOL Bank
   console.log('🏦 Initializing SOL Bank...')
   await initializeAndFundBank(
     connection,
     deployer,
     deployerSigner,
-    USDC_MINT,
+    MARKET,
     SOL_MINT,
-    50 * LAMPORTS_PER_SOL, // 100 SOL
+    pythOracle(SOL_USD_FEED_ID),
+    50 * LAMPORTS_PER_SOL, // 50 SOL
     'SOL'
   )
 
@@ -92,14 +133,16 @@ async function main() {
     connection,
     deployer,
     deployerSigner,
+    MARKET,
     USDC_MINT,
-    SOL_MINT,
-    50 * 1_000_000, // 100 USDC (6 decimals)
+    pythOracle(USDC_USD_FEED_ID),
+    50 * 1_000_000, // 50 USDC (6 decimals)
     'USDC'
   )
 
   // Save configuration
   const config = {
+    MARKET: MARKET.toBase58(),
     SOL_MINT: SOL_MINT.toBase58(),
     USDC_MINT: USDC_MINT.toBase58(),
     SOL_MINT_AUTHORITY: deployer.publicKey.toBase58(), // Mint authority is the deployer
@@ -175,90 +218,93 @@ async function mintTokensToUser(
   console.log('✅ Minted 1000 USDC to user')
 }
 
+/*
+  Convert a Gill instruction (from Codama) to a web3 instruction for solana
+
+  Gill instruction structure:
+    ix = {
+      accounts: [
+        { address: "payer_wallet", role: AccountRole.WRITABLE_SIGNER },
+        { address: "mint_address", role: AccountRole.READONLY },
+        { address: "bank_pda", role: AccountRole.WRITABLE },
+        // ... more accounts
+      ],
+      programAddress: "lending_program_id",
+      data: Uint8Array([...])  // Serialized instruction data
+    }
+
+  Web3 instruction structure:
+    web3Ix = {
+      keys: [{ pubkey: new PublicKey("payer_wallet"), isSigner: true, isWritable: true }, ...],
+      programId: new PublicKey("lending_program_id"),
+      data: Buffer.from(instruction_data)  // Raw bytes for program
+    }
+*/
+function toWeb3Instruction(ix: Instruction) {
+  return new TransactionInstruction({
+    keys: (ix.accounts ?? []).map((acc) => ({
+      pubkey: new PublicKey(acc.address),
+      isSigner: isSignerRole(acc.role),
+      isWritable: isWritableRole(acc.role),
+    })),
+    programId: new PublicKey(ix.programAddress),
+    data: Buffer.from(ix.data ?? []),
+  })
+}
+
+async function sendInstruction(connection: Connection, ix: Instruction, signers: Keypair[]) {
+  const tx = new Transaction().add(toWeb3Instruction(ix))
+  const { blockhash } = await connection.getLatestBlockhash()
+  tx.recentBlockhash = blockhash
+  tx.feePayer = signers[0].publicKey
+
+  return sendAndConfirmTransaction(connection, tx, signers)
+}
+
+async function initializeMarket(connection: Connection, deployer: Keypair, deployerSigner: KeyPairSigner) {
+  // The market is a regular account, so its new keypair signs its creation
+  const market = Keypair.generate()
+  const marketSigner = await createKeyPairSignerFromBytes(market.secretKey)
+
+  // Name of the market, padded with zeros to 32 bytes
+  const name = new Uint8Array(32)
+  name.set(new TextEncoder().encode('Main'))
+
+  const marketIx = getInitializeMarketInstruction({
+    payer: deployerSigner,
+    admin: deployerSigner.address,
+    market: marketSigner,
+    name,
+  })
+
+  await sendInstruction(connection, marketIx, [deployer, market])
+
+  return market.publicKey
+}
+
 async function initializeAndFundBank(
   connection: Connection,
   deployer: Keypair,
   deployerSigner: KeyPairSigner,
-  usdcMint: PublicKey,
-  solMint: PublicKey,
+  market: PublicKey,
+  mint: PublicKey,
+  oracle: OracleConfigArgs,
   amount: number,
   tokenName: string
 ) {
-  const mint = tokenName === 'SOL' ? solMint : usdcMint;
-
-  // 1. Initialize the bank. This creates a new bank account for the token (Gill instruction from Codama)
+  // 1. Initialize the bank. This creates a new bank account for the token in the market, the deployer signs as the admin of the market.
   const bankIx = await getInitializeBankInstructionAsync({
-    signer: deployerSigner,
+    payer: deployerSigner,
+    admin: deployerSigner.address,
+    market: address(market.toString()),
     mint: address(mint.toString()),
     liquidationThreshold: 80, // 80%
     maxLtv: 70, // 70%
+    oracle,
   })
 
-  /*
-    Convert the Gill instruction to a web3 instruction for solana
-
-    Current gill instruction structure:
-      bankIx = {
-        accounts: [
-          { address: "signer_wallet", role: "signer" },
-          { address: "mint_address", role: "readonly" }, 
-          { address: "bank_pda", role: "writable" },
-          { address: "bank_token_account_pda", role: "writable" },
-          { address: "token_program", role: "readonly" },
-          { address: "system_program", role: "readonly" }
-        ],
-        programAddress: "lending_program_id",
-        data: Uint8Array([...])  // Serialized instruction data
-      }
-
-    Required web3 instruction structure:
-      web3BankIx = {
-        keys: [  // AccountMeta array for web3.js
-          {
-            pubkey: new PublicKey("signer_wallet"),
-            isSigner: true,    // signer(0) = deployer wallet
-            isWritable: true   // signer account gets modified (pays rent)
-          },
-          {
-            pubkey: new PublicKey("mint_address"), 
-            isSigner: false,   // mint doesn't sign
-            isWritable: false  // mint account doesn't change
-          },
-          {
-            pubkey: new PublicKey("bank_pda"),
-            isSigner: false,   // PDA doesn't sign
-            isWritable: true   // bank account gets created/modified
-          },
-          // ... more accounts
-        ],
-        programId: new PublicKey("lending_program_id"),
-        data: Buffer.from(instruction_data)  // Raw bytes for program
-      }    
-  */
-
-  const web3BankIx = {
-    keys: bankIx.accounts.map((acc: any, index: number) => ({
-      pubkey: new PublicKey(acc.address),
-      isSigner: index === 0,
-      isWritable: index === 0 || index === 2 || index === 3 // signer, bank, bank_token_account are writable
-    })),
-    programId: new PublicKey(bankIx.programAddress),
-    data: Buffer.from(bankIx.data)
-  }
-
-  // Send bank initialization
-  const bankTx = new Transaction().add(web3BankIx)
-  const { blockhash } = await connection.getLatestBlockhash()
-  bankTx.recentBlockhash = blockhash
-  bankTx.feePayer = deployer.publicKey
-  bankTx.sign(deployer)
-
-  const bankSig = await sendAndConfirmTransaction(connection, bankTx, [deployer])
-
-  // Extract bank address from instruction (should be accounts[2]) for logging
-  const bankAddress = bankIx.accounts[2]?.address
+  const bankSig = await sendInstruction(connection, bankIx, [deployer])
   console.log(`✅ ${tokenName} Bank initialized: ${bankSig}`)
-  console.log(`📍 ${tokenName} Bank address: ${bankAddress}`)
 
   // 2. Create deployer's token account to fund it since we are providing initial liquidity to the bank.
   const deployerTokenAccount = await getOrCreateAssociatedTokenAccount(
@@ -280,60 +326,37 @@ async function initializeAndFundBank(
   const displayAmount = tokenName === 'SOL' ? amount / LAMPORTS_PER_SOL : amount / 1_000_000
   console.log(`✅ Minted ${displayAmount} ${tokenName} to deployer`)
 
-  // 3. Initialize deployer user account (if not exists) - since a user account is required (in the lending protocol) to deposit the tokens into the bank.
+  // 3. Initialize deployer user account in the market (if not exists) - since a user account is required (in the lending protocol) to deposit the tokens into the bank.
   try {
-    // Try to initialize user account - this might fail if it already exists
-    // Use the current mint for user account initialization (doesn't matter which one we use)
+    // Try to initialize user account - this fails for the second bank since the account already exists
     const userAccountIx = await getInitializeAccountInstructionAsync({
-      signer: deployerSigner
+      signer: deployerSigner,
+      market: address(market.toString()),
     })
 
-    // Convert Gill instruction to web3 with correct account permissions
-    // InitializeAccount accounts: [signer, user_account, system_program]
-    // Writable: signer(0), user_account(1)
-    // Signer: signer(0)
-    const web3UserAccountIx = {
-      keys: userAccountIx.accounts.map((acc: any, index: number) => ({
-        pubkey: new PublicKey(acc.address),
-        isSigner: index === 0, // Only signer is a signer
-        isWritable: index === 0 || index === 1 // signer and user_account are writable
-      })),
-      programId: new PublicKey(userAccountIx.programAddress),
-      data: Buffer.from(userAccountIx.data)
-    }
-
-    const userAccountTx = new Transaction().add(web3UserAccountIx)
-    const { blockhash: userBlockhash } = await connection.getLatestBlockhash()
-    userAccountTx.recentBlockhash = userBlockhash
-    userAccountTx.feePayer = deployer.publicKey
-    userAccountTx.sign(deployer)
-
-    const userAccountSig = await sendAndConfirmTransaction(connection, userAccountTx, [deployer])
+    const userAccountSig = await sendInstruction(connection, userAccountIx, [deployer])
     console.log(`✅ Deployer user account initialized: ${userAccountSig}`)
   } catch (e) {
     console.log(`ℹ️  Deployer user account may already exist, continuing...`)
   }
 
   // 4. Deposit tokens into the bank using the lending protocol deposit instruction
-  console.log(`💰 Depositing ${amount / (tokenName === 'SOL' ? LAMPORTS_PER_SOL : 1_000_000)} ${tokenName} into bank...`)
+  console.log(`💰 Depositing ${displayAmount} ${tokenName} into bank...`)
 
-  // PROGRAM that owns/controls all PDAs
-  const PROGRAM_ID = "9CoY42r3y5WFDJjQX97e9m9THcVGpvuVSKjBjGkiksMR"
-
-  // Derive the required PDA addresses
+  // Derive the required PDA addresses, all of them are seeded by the market first
   const [derivedBankAddress] = PublicKey.findProgramAddressSync(
-    [mint.toBuffer()],
-    new PublicKey(PROGRAM_ID)
+    [market.toBuffer(), mint.toBuffer()],
+    PROGRAM_ID
   )
 
   const [derivedBankTokenAccountAddress] = PublicKey.findProgramAddressSync(
-    [Buffer.from("Treasury"), mint.toBuffer()],
-    new PublicKey(PROGRAM_ID)
+    [Buffer.from("Treasury"), market.toBuffer(), mint.toBuffer()],
+    PROGRAM_ID
   )
 
   const [derivedUserAccountAddress] = PublicKey.findProgramAddressSync(
-    [deployer.publicKey.toBuffer()],
-    new PublicKey(PROGRAM_ID)
+    [market.toBuffer(), deployer.publicKey.toBuffer()],
+    PROGRAM_ID
   )
 
   const derivedUserTokenAccount = await getAssociatedTokenAddress(mint, deployer.publicKey)
@@ -344,50 +367,19 @@ async function initializeAndFundBank(
   console.log('  User Account:', derivedUserAccountAddress.toString())
   console.log('  User Token Account:', derivedUserTokenAccount.toString())
 
-  // Verify addresses match what we expect
-  console.log('Expected bank address:', bankAddress)
-  console.log('Derived bank address:', derivedBankAddress.toString())
-  console.log('Addresses match:', derivedBankAddress.toString() === bankAddress)
-
   // Create deposit instruction with explicit PDA addresses
   const depositIx = await getDepositInstructionAsync({
     signer: deployerSigner,
+    market: address(market.toString()),
     mint: address(mint.toString()),
     bank: address(derivedBankAddress.toString()),
     bankTokenAccount: address(derivedBankTokenAccountAddress.toString()),
     userAccount: address(derivedUserAccountAddress.toString()),
     userTokenAccount: address(derivedUserTokenAccount.toString()),
     amountToDeposit: amount,
-    tokenType: tokenName === 'SOL' ? TokenType.SOL : TokenType.USDC
   })
 
-  console.log('Deposit instruction accounts:')
-  depositIx.accounts.forEach((acc: any, index: number) => {
-    console.log(`  ${index}: ${acc.address}`)
-  })
-
-  // Convert Gill instruction to web3 with correct account permissions
-  // Deposit accounts: [signer, mint, bank, bank_token_account, user_account, user_token_account, associated_token_program, token_program, system_program]
-  // Writable: signer(0), bank(2), bank_token_account(3), user_account(4), user_token_account(5)
-  // Signer: signer(0)
-  const web3DepositIx = {
-    keys: depositIx.accounts.map((acc: any, index: number) => ({
-      pubkey: new PublicKey(acc.address),
-      isSigner: index === 0, // Only signer is a signer
-      isWritable: index === 0 || index === 2 || index === 3 || index === 4 || index === 5 // signer, bank, bank_token_account, user_account, user_token_account are writable
-    })),
-    programId: new PublicKey(depositIx.programAddress),
-    data: Buffer.from(depositIx.data)
-  }
-
-  // Send the deposit transaction
-  const depositTx = new Transaction().add(web3DepositIx)
-  const { blockhash: depositBlockhash } = await connection.getLatestBlockhash()
-  depositTx.recentBlockhash = depositBlockhash
-  depositTx.feePayer = deployer.publicKey
-  depositTx.sign(deployer)
-
-  const depositSig = await sendAndConfirmTransaction(connection, depositTx, [deployer])
+  const depositSig = await sendInstruction(connection, depositIx, [deployer])
   console.log(`✅ ${tokenName} deposited into bank: ${depositSig}`)
 }
 
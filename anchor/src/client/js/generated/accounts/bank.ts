@@ -21,23 +21,37 @@ import {
   getBytesEncoder,
   getI64Decoder,
   getI64Encoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU128Decoder,
+  getU128Encoder,
   getU64Decoder,
   getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
   transformEncoder,
   type Account,
   type Address,
+  type Codec,
+  type Decoder,
   type EncodedAccount,
+  type Encoder,
   type FetchAccountConfig,
   type FetchAccountsConfig,
-  type FixedSizeCodec,
-  type FixedSizeDecoder,
-  type FixedSizeEncoder,
   type MaybeAccount,
   type MaybeEncodedAccount,
+  type Option,
+  type OptionOrNullable,
   type ReadonlyUint8Array,
 } from 'gill';
+import {
+  getOracleConfigDecoder,
+  getOracleConfigEncoder,
+  type OracleConfig,
+  type OracleConfigArgs,
+} from '../types';
 
 export const BANK_DISCRIMINATOR = new Uint8Array([
   142, 49, 166, 242, 50, 66, 97, 188,
@@ -49,101 +63,257 @@ export function getBankDiscriminatorBytes() {
 
 export type Bank = {
   discriminator: ReadonlyUint8Array;
+  /** The lending market the bank is listed in */
+  market: Address;
   authority: Address;
+  /** Authority proposed by the current one, it only takes over once it accepts. See `propose_bank_authority`. */
+  pendingAuthority: Option<Address>;
+  /** Operations paused for this bank only, see the `PAUSE_*` constants */
+  paused: number;
   /** The mint address of the asset. Mint is a spl token program used to create the asset. */
   mintAddress: Address;
-  /** The total amount of deposits in the bank. */
+  /** Decimals of the mint, kept on the bank so that positions can be valued without loading the mint account. */
+  decimals: number;
+  /** The total amount of deposits in the bank, interest included as of `last_updated`. */
   totalDeposits: bigint;
   /** The total amount of deposits shares in the bank. */
   totalDepositsShares: bigint;
-  /** The total amount of borrows in the bank. */
+  /** The total amount of borrows in the bank, interest included as of `last_updated`. */
   totalBorrows: bigint;
   /** The total amount of borrows shares in the bank. */
   totalBorrowsShares: bigint;
+  /** Maximum of `total_deposits` after a deposit, 0 for no cap. Lets a new asset be listed with a bounded exposure first. */
+  depositCap: bigint;
+  /** Maximum of `total_borrows` after a borrow, 0 for no cap. */
+  borrowCap: bigint;
   /**
    * Since all the accounts in the protocal can be liquidated, we need all these constants to calculate whether an account is healthy or not.
    *
    * Loan to value at which a loan is defined as under-collateralized and can be liquidated.
    */
   liquidationThreshold: bigint;
-  /** % of bonus that will be given to the liquidator as bonus for processing the liquidation. */
-  liquidationBonus: bigint;
+  /**
+   * Bonus, in basis points of the repaid debt, that will be given to the liquidator for processing the liquidation.
+   *
+   * It works like a dutch auction: the bonus starts at `min_liquidation_bonus` when the health factor falls below 1 and grows linearly to `max_liquidation_bonus` as the health factor drops to `liquidation_bonus_floor_health`.
+   */
+  minLiquidationBonus: bigint;
+  maxLiquidationBonus: bigint;
+  /** Health factor, in basis points, at and below which the liquidator gets `max_liquidation_bonus` */
+  liquidationBonusFloorHealth: bigint;
   /** % of collateral that can be liquidated */
   liquidationCloseFactor: bigint;
   /** Maximum percentage of collateral that can be borrowed for a specific asset. Collateral is the asset that is used to secure the loan */
   maxLtv: bigint;
-  interestRate: bigint;
+  /** Oracle used to price the asset of the bank, both as collateral and as debt. */
+  oracle: OracleConfig;
+  /** Optional second oracle for the same asset, used only when the primary price is stale. Any other failure of the primary fails the instruction. */
+  secondaryOracle: Option<OracleConfig>;
+  /** Maximum difference, in basis points, between the primary and secondary prices before the bank stops accepting risk-increasing actions */
+  maxOracleDivergence: bigint;
+  /**
+   * Interest rate model of the bank. All rates are annualized and expressed in basis points.
+   *
+   * Borrow rate charged when nothing is borrowed.
+   */
+  baseRate: bigint;
+  /** Utilization at which the rate curve kinks and `slope_2` takes over. */
+  optimalUtilization: bigint;
+  /** Rate added on top of `base_rate` as utilization climbs from 0 to `optimal_utilization`. */
+  slope1: bigint;
+  /** Rate added on top of `base_rate + slope_1` as utilization climbs from `optimal_utilization` to 100%. */
+  slope2: bigint;
+  /** Highest utilization a borrow can take the bank to, in basis points. Keeps some liquidity in the bank token account for the depositors to withdraw. */
+  maxUtilization: bigint;
+  /** Share of the interest paid by borrowers that goes to the protocol instead of the depositors, in basis points. */
+  reserveFactor: bigint;
+  /** Protocol fees accrued and not yet collected, in tokens. These tokens sit in the bank token account but do not belong to the depositors. */
+  accumulatedProtocolFees: bigint;
+  /** Share of the interest paid by borrowers that funds the insurance fund of the bank, in basis points. */
+  insuranceFactor: bigint;
+  /** Share of the liquidation bonus that goes to the insurance fund of the collateral bank instead of the liquidator, in basis points. */
+  liquidationInsuranceFee: bigint;
+  /** Insurance fees accrued and not yet moved to the insurance fund, in tokens. Like the protocol fees they sit in the bank token account until then. */
+  accumulatedInsuranceFees: bigint;
+  /** Delay, in seconds, between queuing a configuration change and being able to execute it. Gives users time to react to new risk parameters. */
+  configTimelock: bigint;
+  /** Amount of tokens owed per borrow share, as a `Rate`. Compounds every second at the borrow rate. */
+  cumulativeBorrowIndex: bigint;
+  /** Amount of tokens owned per deposit share, as a `Rate`. Grows by the interest paid by the borrowers. */
+  supplyIndex: bigint;
+  /** Last time interest was accrued into the indices. */
   lastUpdated: bigint;
 };
 
 export type BankArgs = {
+  /** The lending market the bank is listed in */
+  market: Address;
   authority: Address;
+  /** Authority proposed by the current one, it only takes over once it accepts. See `propose_bank_authority`. */
+  pendingAuthority: OptionOrNullable<Address>;
+  /** Operations paused for this bank only, see the `PAUSE_*` constants */
+  paused: number;
   /** The mint address of the asset. Mint is a spl token program used to create the asset. */
   mintAddress: Address;
-  /** The total amount of deposits in the bank. */
+  /** Decimals of the mint, kept on the bank so that positions can be valued without loading the mint account. */
+  decimals: number;
+  /** The total amount of deposits in the bank, interest included as of `last_updated`. */
   totalDeposits: number | bigint;
   /** The total amount of deposits shares in the bank. */
   totalDepositsShares: number | bigint;
-  /** The total amount of borrows in the bank. */
+  /** The total amount of borrows in the bank, interest included as of `last_updated`. */
   totalBorrows: number | bigint;
   /** The total amount of borrows shares in the bank. */
   totalBorrowsShares: number | bigint;
+  /** Maximum of `total_deposits` after a deposit, 0 for no cap. Lets a new asset be listed with a bounded exposure first. */
+  depositCap: number | bigint;
+  /** Maximum of `total_borrows` after a borrow, 0 for no cap. */
+  borrowCap: number | bigint;
   /**
    * Since all the accounts in the protocal can be liquidated, we need all these constants to calculate whether an account is healthy or not.
    *
    * Loan to value at which a loan is defined as under-collateralized and can be liquidated.
    */
   liquidationThreshold: number | bigint;
-  /** % of bonus that will be given to the liquidator as bonus for processing the liquidation. */
-  liquidationBonus: number | bigint;
+  /**
+   * Bonus, in basis points of the repaid debt, that will be given to the liquidator for processing the liquidation.
+   *
+   * It works like a dutch auction: the bonus starts at `min_liquidation_bonus` when the health factor falls below 1 and grows linearly to `max_liquidation_bonus` as the health factor drops to `liquidation_bonus_floor_health`.
+   */
+  minLiquidationBonus: number | bigint;
+  maxLiquidationBonus: number | bigint;
+  /** Health factor, in basis points, at and below which the liquidator gets `max_liquidation_bonus` */
+  liquidationBonusFloorHealth: number | bigint;
   /** % of collateral that can be liquidated */
   liquidationCloseFactor: number | bigint;
   /** Maximum percentage of collateral that can be borrowed for a specific asset. Collateral is the asset that is used to secure the loan */
   maxLtv: number | bigint;
-  interestRate: number | bigint;
+  /** Oracle used to price the asset of the bank, both as collateral and as debt. */
+  oracle: OracleConfigArgs;
+  /** Optional second oracle for the same asset, used only when the primary price is stale. Any other failure of the primary fails the instruction. */
+  secondaryOracle: OptionOrNullable<OracleConfigArgs>;
+  /** Maximum difference, in basis points, between the primary and secondary prices before the bank stops accepting risk-increasing actions */
+  maxOracleDivergence: number | bigint;
+  /**
+   * Interest rate model of the bank. All rates are annualized and expressed in basis points.
+   *
+   * Borrow rate charged when nothing is borrowed.
+   */
+  baseRate: number | bigint;
+  /** Utilization at which the rate curve kinks and `slope_2` takes over. */
+  optimalUtilization: number | bigint;
+  /** Rate added on top of `base_rate` as utilization climbs from 0 to `optimal_utilization`. */
+  slope1: number | bigint;
+  /** Rate added on top of `base_rate + slope_1` as utilization climbs from `optimal_utilization` to 100%. */
+  slope2: number | bigint;
+  /** Highest utilization a borrow can take the bank to, in basis points. Keeps some liquidity in the bank token account for the depositors to withdraw. */
+  maxUtilization: number | bigint;
+  /** Share of the interest paid by borrowers that goes to the protocol instead of the depositors, in basis points. */
+  reserveFactor: number | bigint;
+  /** Protocol fees accrued and not yet collected, in tokens. These tokens sit in the bank token account but do not belong to the depositors. */
+  accumulatedProtocolFees: number | bigint;
+  /** Share of the interest paid by borrowers that funds the insurance fund of the bank, in basis points. */
+  insuranceFactor: number | bigint;
+  /** Share of the liquidation bonus that goes to the insurance fund of the collateral bank instead of the liquidator, in basis points. */
+  liquidationInsuranceFee: number | bigint;
+  /** Insurance fees accrued and not yet moved to the insurance fund, in tokens. Like the protocol fees they sit in the bank token account until then. */
+  accumulatedInsuranceFees: number | bigint;
+  /** Delay, in seconds, between queuing a configuration change and being able to execute it. Gives users time to react to new risk parameters. */
+  configTimelock: number | bigint;
+  /** Amount of tokens owed per borrow share, as a `Rate`. Compounds every second at the borrow rate. */
+  cumulativeBorrowIndex: number | bigint;
+  /** Amount of tokens owned per deposit share, as a `Rate`. Grows by the interest paid by the borrowers. */
+  supplyIndex: number | bigint;
+  /** Last time interest was accrued into the indices. */
   lastUpdated: number | bigint;
 };
 
-export function getBankEncoder(): FixedSizeEncoder<BankArgs> {
+export function getBankEncoder(): Encoder<BankArgs> {
   return transformEncoder(
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
+      ['market', getAddressEncoder()],
       ['authority', getAddressEncoder()],
+      ['pendingAuthority', getOptionEncoder(getAddressEncoder())],
+      ['paused', getU8Encoder()],
       ['mintAddress', getAddressEncoder()],
+      ['decimals', getU8Encoder()],
       ['totalDeposits', getU64Encoder()],
       ['totalDepositsShares', getU64Encoder()],
       ['totalBorrows', getU64Encoder()],
       ['totalBorrowsShares', getU64Encoder()],
+      ['depositCap', getU64Encoder()],
+      ['borrowCap', getU64Encoder()],
       ['liquidationThreshold', getU64Encoder()],
-      ['liquidationBonus', getU64Encoder()],
+      ['minLiquidationBonus', getU64Encoder()],
+      ['maxLiquidationBonus', getU64Encoder()],
+      ['liquidationBonusFloorHealth', getU64Encoder()],
       ['liquidationCloseFactor', getU64Encoder()],
       ['maxLtv', getU64Encoder()],
-      ['interestRate', getU64Encoder()],
+      ['oracle', getOracleConfigEncoder()],
+      ['secondaryOracle', getOptionEncoder(getOracleConfigEncoder())],
+      ['maxOracleDivergence', getU64Encoder()],
+      ['baseRate', getU64Encoder()],
+      ['optimalUtilization', getU64Encoder()],
+      ['slope1', getU64Encoder()],
+      ['slope2', getU64Encoder()],
+      ['maxUtilization', getU64Encoder()],
+      ['reserveFactor', getU64Encoder()],
+      ['accumulatedProtocolFees', getU64Encoder()],
+      ['insuranceFactor', getU64Encoder()],
+      ['liquidationInsuranceFee', getU64Encoder()],
+      ['accumulatedInsuranceFees', getU64Encoder()],
+      ['configTimelock', getU64Encoder()],
+      ['cumulativeBorrowIndex', getU128Encoder()],
+      ['supplyIndex', getU128Encoder()],
       ['lastUpdated', getI64Encoder()],
     ]),
     (value) => ({ ...value, discriminator: BANK_DISCRIMINATOR })
   );
 }
 
-export function getBankDecoder(): FixedSizeDecoder<Bank> {
+export function getBankDecoder(): Decoder<Bank> {
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
+    ['market', getAddressDecoder()],
     ['authority', getAddressDecoder()],
+    ['pendingAuthority', getOptionDecoder(getAddressDecoder())],
+    ['paused', getU8Decoder()],
     ['mintAddress', getAddressDecoder()],
+    ['decimals', getU8Decoder()],
     ['totalDeposits', getU64Decoder()],
     ['totalDepositsShares', getU64Decoder()],
     ['totalBorrows', getU64Decoder()],
     ['totalBorrowsShares', getU64Decoder()],
+    ['depositCap', getU64Decoder()],
+    ['borrowCap', getU64Decoder()],
     ['liquidationThreshold', getU64Decoder()],
-    ['liquidationBonus', getU64Decoder()],
+    ['minLiquidationBonus', getU64Decoder()],
+    ['maxLiquidationBonus', getU64Decoder()],
+    ['liquidationBonusFloorHealth', getU64Decoder()],
     ['liquidationCloseFactor', getU64Decoder()],
     ['maxLtv', getU64Decoder()],
-    ['interestRate', getU64Decoder()],
+    ['oracle', getOracleConfigDecoder()],
+    ['secondaryOracle', getOptionDecoder(getOracleConfigDecoder())],
+    ['maxOracleDivergence', getU64Decoder()],
+    ['baseRate', getU64Decoder()],
+    ['optimalUtilization', getU64Decoder()],
+    ['slope1', getU64Decoder()],
+    ['slope2', getU64Decoder()],
+    ['maxUtilization', getU64Decoder()],
+    ['reserveFactor', getU64Decoder()],
+    ['accumulatedProtocolFees', getU64Decoder()],
+    ['insuranceFactor', getU64Decoder()],
+    ['liquidationInsuranceFee', getU64Decoder()],
+    ['accumulatedInsuranceFees', getU64Decoder()],
+    ['configTimelock', getU64Decoder()],
+    ['cumulativeBorrowIndex', getU128Decoder()],
+    ['supplyIndex', getU128Decoder()],
     ['lastUpdated', getI64Decoder()],
   ]);
 }
 
-export function getBankCodec(): FixedSizeCodec<BankArgs, Bank> {
+export function getBankCodec(): Codec<BankArgs, Bank> {
   return combineCodec(getBankEncoder(), getBankDecoder());
 }
 
@@ -199,7 +369,3 @@ export async function fetchAllMaybeBank(
   const maybeAccounts = await fetchEncodedAccounts(rpc, addresses, config);
   return maybeAccounts.map((maybeAccount) => decodeBank(maybeAccount));
 }
-
-export function getBankSize(): number {
-  return 152;
-}
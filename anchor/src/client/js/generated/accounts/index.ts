@@ -7,5 +7,7 @@
  */
 
 export * from './bank';
-export * from './priceUpdateV2';
+export * from './configQueue';
+export * from './lendingMarket';
+export * from './multisig';
 export * from './user';
@@ -17,27 +17,33 @@ import {
   fixEncoderSize,
   getAddressDecoder,
   getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
   getBytesDecoder,
   getBytesEncoder,
   getI64Decoder,
   getI64Encoder,
   getStructDecoder,
   getStructEncoder,
-  getU64Decoder,
-  getU64Encoder,
   transformEncoder,
   type Account,
   type Address,
+  type Codec,
+  type Decoder,
   type EncodedAccount,
+  type Encoder,
   type FetchAccountConfig,
   type FetchAccountsConfig,
-  type FixedSizeCodec,
-  type FixedSizeDecoder,
-  type FixedSizeEncoder,
   type MaybeAccount,
   type MaybeEncodedAccount,
   type ReadonlyUint8Array,
 } from 'gill';
+import {
+  getPositionDecoder,
+  getPositionEncoder,
+  type Position,
+  type PositionArgs,
+} from '../types';
 
 export const USER_DISCRIMINATOR = new Uint8Array([
   159, 117, 95, 227, 239, 151, 58, 236,
@@ -50,66 +56,46 @@ export function getUserDiscriminatorBytes() {
 export type User = {
   discriminator: ReadonlyUint8Array;
   owner: Address;
-  depositedSol: bigint;
-  depositedSolShares: bigint;
-  borrowedSol: bigint;
-  borrowedSolShares: bigint;
-  depositedUsdc: bigint;
-  depositedUsdcShares: bigint;
-  borrowedUsdc: bigint;
-  borrowedUsdcShares: bigint;
+  /** The lending market this account holds positions in */
+  market: Address;
+  /** One position per bank the user interacted with. A new bank can be listed for any mint without a change to this account. */
+  positions: Array<Position>;
   lastUpdated: bigint;
 };
 
 export type UserArgs = {
   owner: Address;
-  depositedSol: number | bigint;
-  depositedSolShares: number | bigint;
-  borrowedSol: number | bigint;
-  borrowedSolShares: number | bigint;
-  depositedUsdc: number | bigint;
-  depositedUsdcShares: number | bigint;
-  borrowedUsdc: number | bigint;
-  borrowedUsdcShares: number | bigint;
+  /** The lending market this account holds positions in */
+  market: Address;
+  /** One position per bank the user interacted with. A new bank can be listed for any mint without a change to this account. */
+  positions: Array<PositionArgs>;
   lastUpdated: number | bigint;
 };
 
-export function getUserEncoder(): FixedSizeEncoder<UserArgs> {
+export function getUserEncoder(): Encoder<UserArgs> {
   return transformEncoder(
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
       ['owner', getAddressEncoder()],
-      ['depositedSol', getU64Encoder()],
-      ['depositedSolShares', getU64Encoder()],
-      ['borrowedSol', getU64Encoder()],
-      ['borrowedSolShares', getU64Encoder()],
-      ['depositedUsdc', getU64Encoder()],
-      ['depositedUsdcShares', getU64Encoder()],
-      ['borrowedUsdc', getU64Encoder()],
-      ['borrowedUsdcShares', getU64Encoder()],
+      ['market', getAddressEncoder()],
+      ['positions', getArrayEncoder(getPositionEncoder())],
       ['lastUpdated', getI64Encoder()],
     ]),
     (value) => ({ ...value, discriminator: USER_DISCRIMINATOR })
   );
 }
 
-export function getUserDecoder(): FixedSizeDecoder<User> {
+export function getUserDecoder(): Decoder<User> {
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
     ['owner', getAddressDecoder()],
-    ['depositedSol', getU64Decoder()],
-    ['depositedSolShares', getU64Decoder()],
-    ['borrowedSol', getU64Decoder()],
-    ['borrowedSolShares', getU64Decoder()],
-    ['depositedUsdc', getU64Decoder()],
-    ['depositedUsdcShares', getU64Decoder()],
-    ['borrowedUsdc', getU64Decoder()],
-    ['borrowedUsdcShares', getU64Decoder()],
+    ['market', getAddressDecoder()],
+    ['positions', getArrayDecoder(getPositionDecoder())],
     ['lastUpdated', getI64Decoder()],
   ]);
 }
 
-export function getUserCodec(): FixedSizeCodec<UserArgs, User> {
+export function getUserCodec(): Codec<UserArgs, User> {
   return combineCodec(getUserEncoder(), getUserDecoder());
 }
 
@@ -165,7 +151,3 @@ export async function fetchAllMaybeUser(
   const maybeAccounts = await fetchEncodedAccounts(rpc, addresses, config);
   return maybeAccounts.map((maybeAccount) => decodeUser(maybeAccount));
 }
-
-export function getUserSize(): number {
-  return 112;
-}
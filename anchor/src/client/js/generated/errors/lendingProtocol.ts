@@ -22,22 +22,178 @@ export const LENDING_PROTOCOL_ERROR__OVER_BORROWABLE_AMOUNT = 0x1771; // 6001
 export const LENDING_PROTOCOL_ERROR__OVER_REPAY = 0x1772; // 6002
 /** AccountNotUnhealthy: User's account is not unhealthy */
 export const LENDING_PROTOCOL_ERROR__ACCOUNT_NOT_UNHEALTHY = 0x1773; // 6003
+/** InsufficientCollateral: Withdrawal would leave the user's debt above their borrow limit */
+export const LENDING_PROTOCOL_ERROR__INSUFFICIENT_COLLATERAL = 0x1774; // 6004
+/** InvalidHealthAccounts: Accounts passed to compute the health of the user don't match their positions */
+export const LENDING_PROTOCOL_ERROR__INVALID_HEALTH_ACCOUNTS = 0x1775; // 6005
+/** SameCollateralAndBorrowedAsset: Collateral and borrowed asset of a liquidation must be different */
+export const LENDING_PROTOCOL_ERROR__SAME_COLLATERAL_AND_BORROWED_ASSET = 0x1776; // 6006
+/** LiquidationSlippageExceeded: Liquidation would seize less collateral than the liquidator's minimum */
+export const LENDING_PROTOCOL_ERROR__LIQUIDATION_SLIPPAGE_EXCEEDED = 0x1777; // 6007
+/** AccountHasCollateral: Account still has collateral and must be liquidated instead */
+export const LENDING_PROTOCOL_ERROR__ACCOUNT_HAS_COLLATERAL = 0x1778; // 6008
+/** NoBadDebt: Account has no debt in this bank to write off */
+export const LENDING_PROTOCOL_ERROR__NO_BAD_DEBT = 0x1779; // 6009
+/** MaxPositionsReached: User already has a position in the maximum number of banks */
+export const LENDING_PROTOCOL_ERROR__MAX_POSITIONS_REACHED = 0x177a; // 6010
+/** Unauthorized: Signer is not the authority of the bank */
+export const LENDING_PROTOCOL_ERROR__UNAUTHORIZED = 0x177b; // 6011
+/** NotPendingAuthority: Signer is not the pending authority of the bank */
+export const LENDING_PROTOCOL_ERROR__NOT_PENDING_AUTHORITY = 0x177c; // 6012
+/** NotMarketAdmin: Signer is not the admin of the lending market */
+export const LENDING_PROTOCOL_ERROR__NOT_MARKET_ADMIN = 0x177d; // 6013
+/** ProtocolPaused: Operation is paused */
+export const LENDING_PROTOCOL_ERROR__PROTOCOL_PAUSED = 0x177e; // 6014
+/** InvalidPauseFlags: Pause flags contain an unknown operation */
+export const LENDING_PROTOCOL_ERROR__INVALID_PAUSE_FLAGS = 0x177f; // 6015
+/** InvalidOracleConfig: Oracle configuration is invalid */
+export const LENDING_PROTOCOL_ERROR__INVALID_ORACLE_CONFIG = 0x1780; // 6016
+/** InvalidPriceFeed: Price account does not match the oracle configured on the bank */
+export const LENDING_PROTOCOL_ERROR__INVALID_PRICE_FEED = 0x1781; // 6017
+/** InvalidPrice: Oracle price is not positive */
+export const LENDING_PROTOCOL_ERROR__INVALID_PRICE = 0x1782; // 6018
+/** PriceConfidenceTooWide: Oracle confidence interval is too wide for the price to be reliable */
+export const LENDING_PROTOCOL_ERROR__PRICE_CONFIDENCE_TOO_WIDE = 0x1783; // 6019
+/** StalePrice: Oracle price is older than the maximum age configured on the bank */
+export const LENDING_PROTOCOL_ERROR__STALE_PRICE = 0x1784; // 6020
+/** OracleDivergence: Primary and secondary oracle prices diverge too much to take on more risk */
+export const LENDING_PROTOCOL_ERROR__ORACLE_DIVERGENCE = 0x1785; // 6021
+/** MissingSecondaryOracle: Secondary oracle account is required when a secondary oracle is configured */
+export const LENDING_PROTOCOL_ERROR__MISSING_SECONDARY_ORACLE = 0x1786; // 6022
+/** InvalidLiquidationThreshold: Liquidation threshold must be between 1 and 100 percent */
+export const LENDING_PROTOCOL_ERROR__INVALID_LIQUIDATION_THRESHOLD = 0x1787; // 6023
+/** InvalidMaxLtv: Max LTV must be positive and below the liquidation threshold */
+export const LENDING_PROTOCOL_ERROR__INVALID_MAX_LTV = 0x1788; // 6024
+/** InvalidCloseFactor: Liquidation close factor must be between 1 and 100 percent */
+export const LENDING_PROTOCOL_ERROR__INVALID_CLOSE_FACTOR = 0x1789; // 6025
+/** InvalidLiquidationBonus: Liquidation bonus is too high for liquidations to improve the health of an account */
+export const LENDING_PROTOCOL_ERROR__INVALID_LIQUIDATION_BONUS = 0x178a; // 6026
+/** InvalidInterestRateModel: Optimal utilization must be between 1 and 10000 basis points and each rate at most MAX_RATE */
+export const LENDING_PROTOCOL_ERROR__INVALID_INTEREST_RATE_MODEL = 0x178b; // 6027
+/** InvalidMaxUtilization: Max utilization must be between 1 and 10000 basis points */
+export const LENDING_PROTOCOL_ERROR__INVALID_MAX_UTILIZATION = 0x178c; // 6028
+/** InvalidFeeConfig: Reserve factor, insurance factor and liquidation insurance fee must not exceed 100 percent */
+export const LENDING_PROTOCOL_ERROR__INVALID_FEE_CONFIG = 0x178d; // 6029
+/** InvalidConfigTimelock: Config timelock is longer than the maximum allowed */
+export const LENDING_PROTOCOL_ERROR__INVALID_CONFIG_TIMELOCK = 0x178e; // 6030
+/** ConfigQueueFull: Bank already has the maximum number of queued config changes */
+export const LENDING_PROTOCOL_ERROR__CONFIG_QUEUE_FULL = 0x178f; // 6031
+/** ConfigChangeNotFound: No queued config change with this id */
+export const LENDING_PROTOCOL_ERROR__CONFIG_CHANGE_NOT_FOUND = 0x1790; // 6032
+/** ConfigChangeNotReady: Timelock of the config change has not expired yet */
+export const LENDING_PROTOCOL_ERROR__CONFIG_CHANGE_NOT_READY = 0x1791; // 6033
+/** InvalidMultisig: Multisig needs between 1 and the maximum number of distinct signers, and a threshold between 1 and the number of signers */
+export const LENDING_PROTOCOL_ERROR__INVALID_MULTISIG = 0x1792; // 6034
+/** MultisigThresholdNotMet: Not enough signers of the multisig signed the transaction */
+export const LENDING_PROTOCOL_ERROR__MULTISIG_THRESHOLD_NOT_MET = 0x1793; // 6035
+/** DepositCapExceeded: Deposit would take the total deposits of the bank above its deposit cap */
+export const LENDING_PROTOCOL_ERROR__DEPOSIT_CAP_EXCEEDED = 0x1794; // 6036
+/** BorrowCapExceeded: Borrow would take the total borrows of the bank above its borrow cap */
+export const LENDING_PROTOCOL_ERROR__BORROW_CAP_EXCEEDED = 0x1795; // 6037
+/** MaxUtilizationExceeded: Borrow would take the utilization of the bank above its maximum */
+export const LENDING_PROTOCOL_ERROR__MAX_UTILIZATION_EXCEEDED = 0x1796; // 6038
+/** InsufficientLiquidity: Not enough liquidity in the bank token account */
+export const LENDING_PROTOCOL_ERROR__INSUFFICIENT_LIQUIDITY = 0x1797; // 6039
+/** MathOverflow: Math operation overflowed */
+export const LENDING_PROTOCOL_ERROR__MATH_OVERFLOW = 0x1798; // 6040
+/** DivisionByZero: Division by zero */
+export const LENDING_PROTOCOL_ERROR__DIVISION_BY_ZERO = 0x1799; // 6041
+/** NoDebtToLiquidate: Borrower has no debt in the borrowed bank to liquidate */
+export const LENDING_PROTOCOL_ERROR__NO_DEBT_TO_LIQUIDATE = 0x179a; // 6042
 
 export type LendingProtocolError =
+  | typeof LENDING_PROTOCOL_ERROR__ACCOUNT_HAS_COLLATERAL
   | typeof LENDING_PROTOCOL_ERROR__ACCOUNT_NOT_UNHEALTHY
+  | typeof LENDING_PROTOCOL_ERROR__BORROW_CAP_EXCEEDED
+  | typeof LENDING_PROTOCOL_ERROR__CONFIG_CHANGE_NOT_FOUND
+  | typeof LENDING_PROTOCOL_ERROR__CONFIG_CHANGE_NOT_READY
+  | typeof LENDING_PROTOCOL_ERROR__CONFIG_QUEUE_FULL
+  | typeof LENDING_PROTOCOL_ERROR__DEPOSIT_CAP_EXCEEDED
+  | typeof LENDING_PROTOCOL_ERROR__DIVISION_BY_ZERO
+  | typeof LENDING_PROTOCOL_ERROR__INSUFFICIENT_COLLATERAL
   | typeof LENDING_PROTOCOL_ERROR__INSUFFICIENT_FUNDS
+  | typeof LENDING_PROTOCOL_ERROR__INSUFFICIENT_LIQUIDITY
+  | typeof LENDING_PROTOCOL_ERROR__INVALID_CLOSE_FACTOR
+  | typeof LENDING_PROTOCOL_ERROR__INVALID_CONFIG_TIMELOCK
+  | typeof LENDING_PROTOCOL_ERROR__INVALID_FEE_CONFIG
+  | typeof LENDING_PROTOCOL_ERROR__INVALID_HEALTH_ACCOUNTS
+  | typeof LENDING_PROTOCOL_ERROR__INVALID_INTEREST_RATE_MODEL
+  | typeof LENDING_PROTOCOL_ERROR__INVALID_LIQUIDATION_BONUS
+  | typeof LENDING_PROTOCOL_ERROR__INVALID_LIQUIDATION_THRESHOLD
+  | typeof LENDING_PROTOCOL_ERROR__INVALID_MAX_LTV
+  | typeof LENDING_PROTOCOL_ERROR__INVALID_MAX_UTILIZATION
+  | typeof LENDING_PROTOCOL_ERROR__INVALID_MULTISIG
+  | typeof LENDING_PROTOCOL_ERROR__INVALID_ORACLE_CONFIG
+  | typeof LENDING_PROTOCOL_ERROR__INVALID_PAUSE_FLAGS
+  | typeof LENDING_PROTOCOL_ERROR__INVALID_PRICE
+  | typeof LENDING_PROTOCOL_ERROR__INVALID_PRICE_FEED
+  | typeof LENDING_PROTOCOL_ERROR__LIQUIDATION_SLIPPAGE_EXCEEDED
+  | typeof LENDING_PROTOCOL_ERROR__MATH_OVERFLOW
+  | typeof LENDING_PROTOCOL_ERROR__MAX_POSITIONS_REACHED
+  | typeof LENDING_PROTOCOL_ERROR__MAX_UTILIZATION_EXCEEDED
+  | typeof LENDING_PROTOCOL_ERROR__MISSING_SECONDARY_ORACLE
+  | typeof LENDING_PROTOCOL_ERROR__MULTISIG_THRESHOLD_NOT_MET
+  | typeof LENDING_PROTOCOL_ERROR__NOT_MARKET_ADMIN
+  | typeof LENDING_PROTOCOL_ERROR__NOT_PENDING_AUTHORITY
+  | typeof LENDING_PROTOCOL_ERROR__NO_BAD_DEBT
+  | typeof LENDING_PROTOCOL_ERROR__NO_DEBT_TO_LIQUIDATE
+  | typeof LENDING_PROTOCOL_ERROR__ORACLE_DIVERGENCE
   | typeof LENDING_PROTOCOL_ERROR__OVER_BORROWABLE_AMOUNT
-  | typeof LENDING_PROTOCOL_ERROR__OVER_REPAY;
+  | typeof LENDING_PROTOCOL_ERROR__OVER_REPAY
+  | typeof LENDING_PROTOCOL_ERROR__PRICE_CONFIDENCE_TOO_WIDE
+  | typeof LENDING_PROTOCOL_ERROR__PROTOCOL_PAUSED
+  | typeof LENDING_PROTOCOL_ERROR__SAME_COLLATERAL_AND_BORROWED_ASSET
+  | typeof LENDING_PROTOCOL_ERROR__STALE_PRICE
+  | typeof LENDING_PROTOCOL_ERROR__UNAUTHORIZED;
 
 let lendingProtocolErrorMessages:
   | Record<LendingProtocolError, string>
   | undefined;
 if (process.env.NODE_ENV !== 'production') {
   lendingProtocolErrorMessages = {
+    [LENDING_PROTOCOL_ERROR__ACCOUNT_HAS_COLLATERAL]: `Account still has collateral and must be liquidated instead`,
     [LENDING_PROTOCOL_ERROR__ACCOUNT_NOT_UNHEALTHY]: `User's account is not unhealthy`,
+    [LENDING_PROTOCOL_ERROR__BORROW_CAP_EXCEEDED]: `Borrow would take the total borrows of the bank above its borrow cap`,
+    [LENDING_PROTOCOL_ERROR__CONFIG_CHANGE_NOT_FOUND]: `No queued config change with this id`,
+    [LENDING_PROTOCOL_ERROR__CONFIG_CHANGE_NOT_READY]: `Timelock of the config change has not expired yet`,
+    [LENDING_PROTOCOL_ERROR__CONFIG_QUEUE_FULL]: `Bank already has the maximum number of queued config changes`,
+    [LENDING_PROTOCOL_ERROR__DEPOSIT_CAP_EXCEEDED]: `Deposit would take the total deposits of the bank above its deposit cap`,
+    [LENDING_PROTOCOL_ERROR__DIVISION_BY_ZERO]: `Division by zero`,
+    [LENDING_PROTOCOL_ERROR__INSUFFICIENT_COLLATERAL]: `Withdrawal would leave the user's debt above their borrow limit`,
     [LENDING_PROTOCOL_ERROR__INSUFFICIENT_FUNDS]: `User has not deposited enough tokens to withdraw`,
+    [LENDING_PROTOCOL_ERROR__INSUFFICIENT_LIQUIDITY]: `Not enough liquidity in the bank token account`,
+    [LENDING_PROTOCOL_ERROR__INVALID_CLOSE_FACTOR]: `Liquidation close factor must be between 1 and 100 percent`,
+    [LENDING_PROTOCOL_ERROR__INVALID_CONFIG_TIMELOCK]: `Config timelock is longer than the maximum allowed`,
+    [LENDING_PROTOCOL_ERROR__INVALID_FEE_CONFIG]: `Reserve factor, insurance factor and liquidation insurance fee must not exceed 100 percent`,
+    [LENDING_PROTOCOL_ERROR__INVALID_HEALTH_ACCOUNTS]: `Accounts passed to compute the health of the user don't match their positions`,
+    [LENDING_PROTOCOL_ERROR__INVALID_INTEREST_RATE_MODEL]: `Optimal utilization must be between 1 and 10000 basis points and each rate at most MAX_RATE`,
+    [LENDING_PROTOCOL_ERROR__INVALID_LIQUIDATION_BONUS]: `Liquidation bonus is too high for liquidations to improve the health of an account`,
+    [LENDING_PROTOCOL_ERROR__INVALID_LIQUIDATION_THRESHOLD]: `Liquidation threshold must be between 1 and 100 percent`,
+    [LENDING_PROTOCOL_ERROR__INVALID_MAX_LTV]: `Max LTV must be positive and below the liquidation threshold`,
+    [LENDING_PROTOCOL_ERROR__INVALID_MAX_UTILIZATION]: `Max utilization must be between 1 and 10000 basis points`,
+    [LENDING_PROTOCOL_ERROR__INVALID_MULTISIG]: `Multisig needs between 1 and the maximum number of distinct signers, and a threshold between 1 and the number of signers`,
+    [LENDING_PROTOCOL_ERROR__INVALID_ORACLE_CONFIG]: `Oracle configuration is invalid`,
+    [LENDING_PROTOCOL_ERROR__INVALID_PAUSE_FLAGS]: `Pause flags contain an unknown operation`,
+    [LENDING_PROTOCOL_ERROR__INVALID_PRICE]: `Oracle price is not positive`,
+    [LENDING_PROTOCOL_ERROR__INVALID_PRICE_FEED]: `Price account does not match the oracle configured on the bank`,
+    [LENDING_PROTOCOL_ERROR__LIQUIDATION_SLIPPAGE_EXCEEDED]: `Liquidation would seize less collateral than the liquidator's minimum`,
+    [LENDING_PROTOCOL_ERROR__MATH_OVERFLOW]: `Math operation overflowed`,
+    [LENDING_PROTOCOL_ERROR__MAX_POSITIONS_REACHED]: `User already has a position in the maximum number of banks`,
+    [LENDING_PROTOCOL_ERROR__MAX_UTILIZATION_EXCEEDED]: `Borrow would take the utilization of the bank above its maximum`,
+    [LENDING_PROTOCOL_ERROR__MISSING_SECONDARY_ORACLE]: `Secondary oracle account is required when a secondary oracle is configured`,
+    [LENDING_PROTOCOL_ERROR__MULTISIG_THRESHOLD_NOT_MET]: `Not enough signers of the multisig signed the transaction`,
+    [LENDING_PROTOCOL_ERROR__NOT_MARKET_ADMIN]: `Signer is not the admin of the lending market`,
+    [LENDING_PROTOCOL_ERROR__NOT_PENDING_AUTHORITY]: `Signer is not the pending authority of the bank`,
+    [LENDING_PROTOCOL_ERROR__NO_BAD_DEBT]: `Account has no debt in this bank to write off`,
+    [LENDING_PROTOCOL_ERROR__NO_DEBT_TO_LIQUIDATE]: `Borrower has no debt in the borrowed bank to liquidate`,
+    [LENDING_PROTOCOL_ERROR__ORACLE_DIVERGENCE]: `Primary and secondary oracle prices diverge too much to take on more risk`,
     [LENDING_PROTOCOL_ERROR__OVER_BORROWABLE_AMOUNT]: `Requested amount to borrow is greater than the borrowable amount`,
     [LENDING_PROTOCOL_ERROR__OVER_REPAY]: `User has not borrowed enough tokens to repay`,
+    [LENDING_PROTOCOL_ERROR__PRICE_CONFIDENCE_TOO_WIDE]: `Oracle confidence interval is too wide for the price to be reliable`,
+    [LENDING_PROTOCOL_ERROR__PROTOCOL_PAUSED]: `Operation is paused`,
+    [LENDING_PROTOCOL_ERROR__SAME_COLLATERAL_AND_BORROWED_ASSET]: `Collateral and borrowed asset of a liquidation must be different`,
+    [LENDING_PROTOCOL_ERROR__STALE_PRICE]: `Oracle price is older than the maximum age configured on the bank`,
+    [LENDING_PROTOCOL_ERROR__UNAUTHORIZED]: `Signer is not the authority of the bank`,
   };
 }
 
@@ -40,12 +40,6 @@ import {
   getAccountMetaFactory,
   type ResolvedAccount,
 } from '../shared';
-import {
-  getTokenTypeDecoder,
-  getTokenTypeEncoder,
-  type TokenType,
-  type TokenTypeArgs,
-} from '../types';
 
 export const BORROW_DISCRIMINATOR = new Uint8Array([
   228, 253, 131, 202, 207, 116, 89, 18,
@@ -58,12 +52,12 @@ export function getBorrowDiscriminatorBytes() {
 export type BorrowInstruction<
   TProgram extends string = typeof LENDING_PROTOCOL_PROGRAM_ADDRESS,
   TAccountSigner extends string | AccountMeta<string> = string,
+  TAccountMarket extends string | AccountMeta<string> = string,
   TAccountMint extends string | AccountMeta<string> = string,
   TAccountBank extends string | AccountMeta<string> = string,
   TAccountBankTokenAccount extends string | AccountMeta<string> = string,
   TAccountUserAccount extends string | AccountMeta<string> = string,
   TAccountUserTokenAccount extends string | AccountMeta<string> = string,
-  TAccountPriceUpdate extends string | AccountMeta<string> = string,
   TAccountAssociatedTokenProgram extends
     | string
     | AccountMeta<string> = 'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL',
@@ -82,6 +76,9 @@ export type BorrowInstruction<
         ? WritableSignerAccount<TAccountSigner> &
             AccountSignerMeta<TAccountSigner>
         : TAccountSigner,
+      TAccountMarket extends string
+        ? ReadonlyAccount<TAccountMarket>
+        : TAccountMarket,
       TAccountMint extends string
         ? ReadonlyAccount<TAccountMint>
         : TAccountMint,
@@ -97,9 +94,6 @@ export type BorrowInstruction<
       TAccountUserTokenAccount extends string
         ? WritableAccount<TAccountUserTokenAccount>
         : TAccountUserTokenAccount,
-      TAccountPriceUpdate extends string
-        ? ReadonlyAccount<TAccountPriceUpdate>
-        : TAccountPriceUpdate,
       TAccountAssociatedTokenProgram extends string
         ? ReadonlyAccount<TAccountAssociatedTokenProgram>
         : TAccountAssociatedTokenProgram,
@@ -116,12 +110,10 @@ export type BorrowInstruction<
 export type BorrowInstructionData = {
   discriminator: ReadonlyUint8Array;
   amountToBorrow: bigint;
-  tokenType: TokenType;
 };
 
 export type BorrowInstructionDataArgs = {
   amountToBorrow: number | bigint;
-  tokenType: TokenTypeArgs;
 };
 
 export function getBorrowInstructionDataEncoder(): FixedSizeEncoder<BorrowInstructionDataArgs> {
@@ -129,7 +121,6 @@ export function getBorrowInstructionDataEncoder(): FixedSizeEncoder<BorrowInstru
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
       ['amountToBorrow', getU64Encoder()],
-      ['tokenType', getTokenTypeEncoder()],
     ]),
     (value) => ({ ...value, discriminator: BORROW_DISCRIMINATOR })
   );
@@ -139,7 +130,6 @@ export function getBorrowInstructionDataDecoder(): FixedSizeDecoder<BorrowInstru
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
     ['amountToBorrow', getU64Decoder()],
-    ['tokenType', getTokenTypeDecoder()],
   ]);
 }
 
@@ -155,18 +145,20 @@ export function getBorrowInstructionDataCodec(): FixedSizeCodec<
 
 export type BorrowAsyncInput<
   TAccountSigner extends string = string,
+  TAccountMarket extends string = string,
   TAccountMint extends string = string,
   TAccountBank extends string = string,
   TAccountBankTokenAccount extends string = string,
   TAccountUserAccount extends string = string,
   TAccountUserTokenAccount extends string = string,
-  TAccountPriceUpdate extends string = string,
   TAccountAssociatedTokenProgram extends string = string,
   TAccountTokenProgram extends string = string,
   TAccountSystemProgram extends string = string,
 > = {
   /** The signer of the transaction */
   signer: TransactionSigner<TAccountSigner>;
+  /** The lending market the bank belongs to */
+  market: Address<TAccountMarket>;
   /** The mint address of the asset to be borrowed */
   mint: Address<TAccountMint>;
   /** The bank account of the mint that the user wants to borrow */
@@ -181,8 +173,6 @@ export type BorrowAsyncInput<
    * Since we can't guarantee that the user will have a token account for the mint that they are looking to borrow, we will initialize it if needed.
    */
   userTokenAccount?: Address<TAccountUserTokenAccount>;
-  /** Since the user will be borrowing a different asset than the one they deposited - we need to calculate the price corresponding to the asset they deposited using pyth-solana-receiver-sdk */
-  priceUpdate: Address<TAccountPriceUpdate>;
   /** Associated token program because it's referenced in the instruction */
   associatedTokenProgram?: Address<TAccountAssociatedTokenProgram>;
   /** Token program because it's referenced in the instruction */
@@ -190,17 +180,16 @@ export type BorrowAsyncInput<
   /** System program to POTENTIALLY create a new account and also because it's required by the instruction */
   systemProgram?: Address<TAccountSystemProgram>;
   amountToBorrow: BorrowInstructionDataArgs['amountToBorrow'];
-  tokenType: BorrowInstructionDataArgs['tokenType'];
 };
 
 export async function getBorrowInstructionAsync<
   TAccountSigner extends string,
+  TAccountMarket extends string,
   TAccountMint extends string,
   TAccountBank extends string,
   TAccountBankTokenAccount extends string,
   TAccountUserAccount extends string,
   TAccountUserTokenAccount extends string,
-  TAccountPriceUpdate extends string,
   TAccountAssociatedTokenProgram extends string,
   TAccountTokenProgram extends string,
   TAccountSystemProgram extends string,
@@ -208,12 +197,12 @@ export async function getBorrowInstructionAsync<
 >(
   input: BorrowAsyncInput<
     TAccountSigner,
+    TAccountMarket,
     TAccountMint,
     TAccountBank,
     TAccountBankTokenAccount,
     TAccountUserAccount,
     TAccountUserTokenAccount,
-    TAccountPriceUpdate,
     TAccountAssociatedTokenProgram,
     TAccountTokenProgram,
     TAccountSystemProgram
@@ -223,12 +212,12 @@ export async function getBorrowInstructionAsync<
   BorrowInstruction<
     TProgramAddress,
     TAccountSigner,
+    TAccountMarket,
     TAccountMint,
     TAccountBank,
     TAccountBankTokenAccount,
     TAccountUserAccount,
     TAccountUserTokenAccount,
-    TAccountPriceUpdate,
     TAccountAssociatedTokenProgram,
     TAccountTokenProgram,
     TAccountSystemProgram
@@ -241,6 +230,7 @@ export async function getBorrowInstructionAsync<
   // Original accounts.
   const originalAccounts = {
     signer: { value: input.signer ?? null, isWritable: true },
+    market: { value: input.market ?? null, isWritable: false },
     mint: { value: input.mint ?? null, isWritable: false },
     bank: { value: input.bank ?? null, isWritable: true },
     bankTokenAccount: {
@@ -252,7 +242,6 @@ export async function getBorrowInstructionAsync<
       value: input.userTokenAccount ?? null,
       isWritable: true,
     },
-    priceUpdate: { value: input.priceUpdate ?? null, isWritable: false },
     associatedTokenProgram: {
       value: input.associatedTokenProgram ?? null,
       isWritable: false,
@@ -272,7 +261,10 @@ export async function getBorrowInstructionAsync<
   if (!accounts.bank.value) {
     accounts.bank.value = await getProgramDerivedAddress({
       programAddress,
-      seeds: [getAddressEncoder().encode(expectAddress(accounts.mint.value))],
+      seeds: [
+        getAddressEncoder().encode(expectAddress(accounts.market.value)),
+        getAddressEncoder().encode(expectAddress(accounts.mint.value)),
+      ],
     });
   }
   if (!accounts.bankTokenAccount.value) {
@@ -282,6 +274,7 @@ export async function getBorrowInstructionAsync<
         getBytesEncoder().encode(
           new Uint8Array([84, 114, 101, 97, 115, 117, 114, 121])
         ),
+        getAddressEncoder().encode(expectAddress(accounts.market.value)),
         getAddressEncoder().encode(expectAddress(accounts.mint.value)),
       ],
     });
@@ -289,7 +282,10 @@ export async function getBorrowInstructionAsync<
   if (!accounts.userAccount.value) {
     accounts.userAccount.value = await getProgramDerivedAddress({
       programAddress,
-      seeds: [getAddressEncoder().encode(expectAddress(accounts.signer.value))],
+      seeds: [
+        getAddressEncoder().encode(expectAddress(accounts.market.value)),
+        getAddressEncoder().encode(expectAddress(accounts.signer.value)),
+      ],
     });
   }
   if (!accounts.tokenProgram.value) {
@@ -320,12 +316,12 @@ export async function getBorrowInstructionAsync<
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.signer),
+      getAccountMeta(accounts.market),
       getAccountMeta(accounts.mint),
       getAccountMeta(accounts.bank),
       getAccountMeta(accounts.bankTokenAccount),
       getAccountMeta(accounts.userAccount),
       getAccountMeta(accounts.userTokenAccount),
-      getAccountMeta(accounts.priceUpdate),
       getAccountMeta(accounts.associatedTokenProgram),
       getAccountMeta(accounts.tokenProgram),
       getAccountMeta(accounts.systemProgram),
@@ -337,12 +333,12 @@ export async function getBorrowInstructionAsync<
   } as BorrowInstruction<
     TProgramAddress,
     TAccountSigner,
+    TAccountMarket,
     TAccountMint,
     TAccountBank,
     TAccountBankTokenAccount,
     TAccountUserAccount,
     TAccountUserTokenAccount,
-    TAccountPriceUpdate,
     TAccountAssociatedTokenProgram,
     TAccountTokenProgram,
     TAccountSystemProgram
@@ -351,18 +347,20 @@ export async function getBorrowInstructionAsync<
 
 export type BorrowInput<
   TAccountSigner extends string = string,
+  TAccountMarket extends string = string,
   TAccountMint extends string = string,
   TAccountBank extends string = string,
   TAccountBankTokenAccount extends string = string,
   TAccountUserAccount extends string = string,
   TAccountUserTokenAccount extends string = string,
-  TAccountPriceUpdate extends string = string,
   TAccountAssociatedTokenProgram extends string = string,
   TAccountTokenProgram extends string = string,
   TAccountSystemProgram extends string = string,
 > = {
   /** The signer of the transaction */
   signer: TransactionSigner<TAccountSigner>;
+  /** The lending market the bank belongs to */
+  market: Address<TAccountMarket>;
   /** The mint address of the asset to be borrowed */
   mint: Address<TAccountMint>;
   /** The bank account of the mint that the user wants to borrow */
@@ -377,8 +375,6 @@ export type BorrowInput<
    * Since we can't guarantee that the user will have a token account for the mint that they are looking to borrow, we will initialize it if needed.
    */
   userTokenAccount: Address<TAccountUserTokenAccount>;
-  /** Since the user will be borrowing a different asset than the one they deposited - we need to calculate the price corresponding to the asset they deposited using pyth-solana-receiver-sdk */
-  priceUpdate: Address<TAccountPriceUpdate>;
   /** Associated token program because it's referenced in the instruction */
   associatedTokenProgram?: Address<TAccountAssociatedTokenProgram>;
   /** Token program because it's referenced in the instruction */
@@ -386,17 +382,16 @@ export type BorrowInput<
   /** System program to POTENTIALLY create a new account and also because it's required by the instruction */
   systemProgram?: Address<TAccountSystemProgram>;
   amountToBorrow: BorrowInstructionDataArgs['amountToBorrow'];
-  tokenType: BorrowInstructionDataArgs['tokenType'];
 };
 
 export function getBorrowInstruction<
   TAccountSigner extends string,
+  TAccountMarket extends string,
   TAccountMint extends string,
   TAccountBank extends string,
   TAccountBankTokenAccount extends string,
   TAccountUserAccount extends string,
   TAccountUserTokenAccount extends string,
-  TAccountPriceUpdate extends string,
   TAccountAssociatedTokenProgram extends string,
   TAccountTokenProgram extends string,
   TAccountSystemProgram extends string,
@@ -404,12 +399,12 @@ export function getBorrowInstruction<
 >(
   input: BorrowInput<
     TAccountSigner,
+    TAccountMarket,
     TAccountMint,
     TAccountBank,
     TAccountBankTokenAccount,
     TAccountUserAccount,
     TAccountUserTokenAccount,
-    TAccountPriceUpdate,
     TAccountAssociatedTokenProgram,
     TAccountTokenProgram,
     TAccountSystemProgram
@@ -418,12 +413,12 @@ export function getBorrowInstruction<
 ): BorrowInstruction<
   TProgramAddress,
   TAccountSigner,
+  TAccountMarket,
   TAccountMint,
   TAccountBank,
   TAccountBankTokenAccount,
   TAccountUserAccount,
   TAccountUserTokenAccount,
-  TAccountPriceUpdate,
   TAccountAssociatedTokenProgram,
   TAccountTokenProgram,
   TAccountSystemProgram
@@ -435,6 +430,7 @@ export function getBorrowInstruction<
   // Original accounts.
   const originalAccounts = {
     signer: { value: input.signer ?? null, isWritable: true },
+    market: { value: input.market ?? null, isWritable: false },
     mint: { value: input.mint ?? null, isWritable: false },
     bank: { value: input.bank ?? null, isWritable: true },
     bankTokenAccount: {
@@ -446,7 +442,6 @@ export function getBorrowInstruction<
       value: input.userTokenAccount ?? null,
       isWritable: true,
     },
-    priceUpdate: { value: input.priceUpdate ?? null, isWritable: false },
     associatedTokenProgram: {
       value: input.associatedTokenProgram ?? null,
       isWritable: false,
@@ -480,12 +475,12 @@ export function getBorrowInstruction<
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.signer),
+      getAccountMeta(accounts.market),
       getAccountMeta(accounts.mint),
       getAccountMeta(accounts.bank),
       getAccountMeta(accounts.bankTokenAccount),
       getAccountMeta(accounts.userAccount),
       getAccountMeta(accounts.userTokenAccount),
-      getAccountMeta(accounts.priceUpdate),
       getAccountMeta(accounts.associatedTokenProgram),
       getAccountMeta(accounts.tokenProgram),
       getAccountMeta(accounts.systemProgram),
@@ -497,12 +492,12 @@ export function getBorrowInstruction<
   } as BorrowInstruction<
     TProgramAddress,
     TAccountSigner,
+    TAccountMarket,
     TAccountMint,
     TAccountBank,
     TAccountBankTokenAccount,
     TAccountUserAccount,
     TAccountUserTokenAccount,
-    TAccountPriceUpdate,
     TAccountAssociatedTokenProgram,
     TAccountTokenProgram,
     TAccountSystemProgram
@@ -517,22 +512,22 @@ export type ParsedBorrowInstruction<
   accounts: {
     /** The signer of the transaction */
     signer: TAccountMetas[0];
+    /** The lending market the bank belongs to */
+    market: TAccountMetas[1];
     /** The mint address of the asset to be borrowed */
-    mint: TAccountMetas[1];
+    mint: TAccountMetas[2];
     /** The bank account of the mint that the user wants to borrow */
-    bank: TAccountMetas[2];
+    bank: TAccountMetas[3];
     /** The bank token account of the mint that the user wants to borrow */
-    bankTokenAccount: TAccountMetas[3];
+    bankTokenAccount: TAccountMetas[4];
     /** The user account that stores the state of the user */
-    userAccount: TAccountMetas[4];
+    userAccount: TAccountMetas[5];
     /**
      * The user token account which (will) hold the tokens that the user is looking to borrow
      *
      * Since we can't guarantee that the user will have a token account for the mint that they are looking to borrow, we will initialize it if needed.
      */
-    userTokenAccount: TAccountMetas[5];
-    /** Since the user will be borrowing a different asset than the one they deposited - we need to calculate the price corresponding to the asset they deposited using pyth-solana-receiver-sdk */
-    priceUpdate: TAccountMetas[6];
+    userTokenAccount: TAccountMetas[6];
     /** Associated token program because it's referenced in the instruction */
     associatedTokenProgram: TAccountMetas[7];
     /** Token program because it's referenced in the instruction */
@@ -565,12 +560,12 @@ export function parseBorrowInstruction<
     programAddress: instruction.programAddress,
     accounts: {
       signer: getNextAccount(),
+      market: getNextAccount(),
       mint: getNextAccount(),
       bank: getNextAccount(),
       bankTokenAccount: getNextAccount(),
       userAccount: getNextAccount(),
       userTokenAccount: getNextAccount(),
-      priceUpdate: getNextAccount(),
       associatedTokenProgram: getNextAccount(),
       tokenProgram: getNextAccount(),
       systemProgram: getNextAccount(),
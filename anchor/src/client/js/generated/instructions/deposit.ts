@@ -40,12 +40,6 @@ import {
   getAccountMetaFactory,
   type ResolvedAccount,
 } from '../shared';
-import {
-  getTokenTypeDecoder,
-  getTokenTypeEncoder,
-  type TokenType,
-  type TokenTypeArgs,
-} from '../types';
 
 export const DEPOSIT_DISCRIMINATOR = new Uint8Array([
   242, 35, 198, 137, 82, 225, 242, 182,
@@ -58,6 +52,7 @@ export function getDepositDiscriminatorBytes() {
 export type DepositInstruction<
   TProgram extends string = typeof LENDING_PROTOCOL_PROGRAM_ADDRESS,
   TAccountSigner extends string | AccountMeta<string> = string,
+  TAccountMarket extends string | AccountMeta<string> = string,
   TAccountMint extends string | AccountMeta<string> = string,
   TAccountBank extends string | AccountMeta<string> = string,
   TAccountBankTokenAccount extends string | AccountMeta<string> = string,
@@ -81,6 +76,9 @@ export type DepositInstruction<
         ? WritableSignerAccount<TAccountSigner> &
             AccountSignerMeta<TAccountSigner>
         : TAccountSigner,
+      TAccountMarket extends string
+        ? ReadonlyAccount<TAccountMarket>
+        : TAccountMarket,
       TAccountMint extends string
         ? ReadonlyAccount<TAccountMint>
         : TAccountMint,
@@ -112,12 +110,10 @@ export type DepositInstruction<
 export type DepositInstructionData = {
   discriminator: ReadonlyUint8Array;
   amountToDeposit: bigint;
-  tokenType: TokenType;
 };
 
 export type DepositInstructionDataArgs = {
   amountToDeposit: number | bigint;
-  tokenType: TokenTypeArgs;
 };
 
 export function getDepositInstructionDataEncoder(): FixedSizeEncoder<DepositInstructionDataArgs> {
@@ -125,7 +121,6 @@ export function getDepositInstructionDataEncoder(): FixedSizeEncoder<DepositInst
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
       ['amountToDeposit', getU64Encoder()],
-      ['tokenType', getTokenTypeEncoder()],
     ]),
     (value) => ({ ...value, discriminator: DEPOSIT_DISCRIMINATOR })
   );
@@ -135,7 +130,6 @@ export function getDepositInstructionDataDecoder(): FixedSizeDecoder<DepositInst
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
     ['amountToDeposit', getU64Decoder()],
-    ['tokenType', getTokenTypeDecoder()],
   ]);
 }
 
@@ -151,6 +145,7 @@ export function getDepositInstructionDataCodec(): FixedSizeCodec<
 
 export type DepositAsyncInput<
   TAccountSigner extends string = string,
+  TAccountMarket extends string = string,
   TAccountMint extends string = string,
   TAccountBank extends string = string,
   TAccountBankTokenAccount extends string = string,
@@ -162,6 +157,8 @@ export type DepositAsyncInput<
 > = {
   /** The signer of the transaction */
   signer: TransactionSigner<TAccountSigner>;
+  /** The lending market the bank belongs to */
+  market: Address<TAccountMarket>;
   /** The mint address of the asset to be deposited */
   mint: Address<TAccountMint>;
   /** The bank account to deposit the asset to */
@@ -183,11 +180,11 @@ export type DepositAsyncInput<
   /** System program to create the account */
   systemProgram?: Address<TAccountSystemProgram>;
   amountToDeposit: DepositInstructionDataArgs['amountToDeposit'];
-  tokenType: DepositInstructionDataArgs['tokenType'];
 };
 
 export async function getDepositInstructionAsync<
   TAccountSigner extends string,
+  TAccountMarket extends string,
   TAccountMint extends string,
   TAccountBank extends string,
   TAccountBankTokenAccount extends string,
@@ -200,6 +197,7 @@ export async function getDepositInstructionAsync<
 >(
   input: DepositAsyncInput<
     TAccountSigner,
+    TAccountMarket,
     TAccountMint,
     TAccountBank,
     TAccountBankTokenAccount,
@@ -214,6 +212,7 @@ export async function getDepositInstructionAsync<
   DepositInstruction<
     TProgramAddress,
     TAccountSigner,
+    TAccountMarket,
     TAccountMint,
     TAccountBank,
     TAccountBankTokenAccount,
@@ -231,6 +230,7 @@ export async function getDepositInstructionAsync<
   // Original accounts.
   const originalAccounts = {
     signer: { value: input.signer ?? null, isWritable: true },
+    market: { value: input.market ?? null, isWritable: false },
     mint: { value: input.mint ?? null, isWritable: false },
     bank: { value: input.bank ?? null, isWritable: true },
     bankTokenAccount: {
@@ -261,7 +261,10 @@ export async function getDepositInstructionAsync<
   if (!accounts.bank.value) {
     accounts.bank.value = await getProgramDerivedAddress({
       programAddress,
-      seeds: [getAddressEncoder().encode(expectAddress(accounts.mint.value))],
+      seeds: [
+        getAddressEncoder().encode(expectAddress(accounts.market.value)),
+        getAddressEncoder().encode(expectAddress(accounts.mint.value)),
+      ],
     });
   }
   if (!accounts.bankTokenAccount.value) {
@@ -271,6 +274,7 @@ export async function getDepositInstructionAsync<
         getBytesEncoder().encode(
           new Uint8Array([84, 114, 101, 97, 115, 117, 114, 121])
         ),
+        getAddressEncoder().encode(expectAddress(accounts.market.value)),
         getAddressEncoder().encode(expectAddress(accounts.mint.value)),
       ],
     });
@@ -278,7 +282,10 @@ export async function getDepositInstructionAsync<
   if (!accounts.userAccount.value) {
     accounts.userAccount.value = await getProgramDerivedAddress({
       programAddress,
-      seeds: [getAddressEncoder().encode(expectAddress(accounts.signer.value))],
+      seeds: [
+        getAddressEncoder().encode(expectAddress(accounts.market.value)),
+        getAddressEncoder().encode(expectAddress(accounts.signer.value)),
+      ],
     });
   }
   if (!accounts.tokenProgram.value) {
@@ -309,6 +316,7 @@ export async function getDepositInstructionAsync<
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.signer),
+      getAccountMeta(accounts.market),
       getAccountMeta(accounts.mint),
       getAccountMeta(accounts.bank),
       getAccountMeta(accounts.bankTokenAccount),
@@ -325,6 +333,7 @@ export async function getDepositInstructionAsync<
   } as DepositInstruction<
     TProgramAddress,
     TAccountSigner,
+    TAccountMarket,
     TAccountMint,
     TAccountBank,
     TAccountBankTokenAccount,
@@ -338,6 +347,7 @@ export async function getDepositInstructionAsync<
 
 export type DepositInput<
   TAccountSigner extends string = string,
+  TAccountMarket extends string = string,
   TAccountMint extends string = string,
   TAccountBank extends string = string,
   TAccountBankTokenAccount extends string = string,
@@ -349,6 +359,8 @@ export type DepositInput<
 > = {
   /** The signer of the transaction */
   signer: TransactionSigner<TAccountSigner>;
+  /** The lending market the bank belongs to */
+  market: Address<TAccountMarket>;
   /** The mint address of the asset to be deposited */
   mint: Address<TAccountMint>;
   /** The bank account to deposit the asset to */
@@ -370,11 +382,11 @@ export type DepositInput<
   /** System program to create the account */
   systemProgram?: Address<TAccountSystemProgram>;
   amountToDeposit: DepositInstructionDataArgs['amountToDeposit'];
-  tokenType: DepositInstructionDataArgs['tokenType'];
 };
 
 export function getDepositInstruction<
   TAccountSigner extends string,
+  TAccountMarket extends string,
   TAccountMint extends string,
   TAccountBank extends string,
   TAccountBankTokenAccount extends string,
@@ -387,6 +399,7 @@ export function getDepositInstruction<
 >(
   input: DepositInput<
     TAccountSigner,
+    TAccountMarket,
     TAccountMint,
     TAccountBank,
     TAccountBankTokenAccount,
@@ -400,6 +413,7 @@ export function getDepositInstruction<
 ): DepositInstruction<
   TProgramAddress,
   TAccountSigner,
+  TAccountMarket,
   TAccountMint,
   TAccountBank,
   TAccountBankTokenAccount,
@@ -416,6 +430,7 @@ export function getDepositInstruction<
   // Original accounts.
   const originalAccounts = {
     signer: { value: input.signer ?? null, isWritable: true },
+    market: { value: input.market ?? null, isWritable: false },
     mint: { value: input.mint ?? null, isWritable: false },
     bank: { value: input.bank ?? null, isWritable: true },
     bankTokenAccount: {
@@ -460,6 +475,7 @@ export function getDepositInstruction<
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.signer),
+      getAccountMeta(accounts.market),
       getAccountMeta(accounts.mint),
       getAccountMeta(accounts.bank),
       getAccountMeta(accounts.bankTokenAccount),
@@ -476,6 +492,7 @@ export function getDepositInstruction<
   } as DepositInstruction<
     TProgramAddress,
     TAccountSigner,
+    TAccountMarket,
     TAccountMint,
     TAccountBank,
     TAccountBankTokenAccount,
@@ -495,26 +512,28 @@ export type ParsedDepositInstruction<
   accounts: {
     /** The signer of the transaction */
     signer: TAccountMetas[0];
+    /** The lending market the bank belongs to */
+    market: TAccountMetas[1];
     /** The mint address of the asset to be deposited */
-    mint: TAccountMetas[1];
+    mint: TAccountMetas[2];
     /** The bank account to deposit the asset to */
-    bank: TAccountMetas[2];
+    bank: TAccountMetas[3];
     /** The bank token account to deposit the tokens */
-    bankTokenAccount: TAccountMetas[3];
+    bankTokenAccount: TAccountMetas[4];
     /** The user account that stores the state of the user */
-    userAccount: TAccountMetas[4];
+    userAccount: TAccountMetas[5];
     /**
      * Account that will take the tokens we are depositing and transfer them to the bank token account
      *
      * This will be an Associated Token Account for the mint address of the asset we are depositing into the bank.
      */
-    userTokenAccount: TAccountMetas[5];
+    userTokenAccount: TAccountMetas[6];
     /** Associated token program to create the associated token account */
-    associatedTokenProgram: TAccountMetas[6];
+    associatedTokenProgram: TAccountMetas[7];
     /** Token program to create the token account. */
-    tokenProgram: TAccountMetas[7];
+    tokenProgram: TAccountMetas[8];
     /** System program to create the account */
-    systemProgram: TAccountMetas[8];
+    systemProgram: TAccountMetas[9];
   };
   data: DepositInstructionData;
 };
@@ -527,7 +546,7 @@ export function parseDepositInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedDepositInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 9) {
+  if (instruction.accounts.length < 10) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -541,6 +560,7 @@ export function parseDepositInstruction<
     programAddress: instruction.programAddress,
     accounts: {
       signer: getNextAccount(),
+      market: getNextAccount(),
       mint: getNextAccount(),
       bank: getNextAccount(),
       bankTokenAccount: getNextAccount(),
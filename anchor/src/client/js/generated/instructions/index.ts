@@ -6,10 +6,27 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './acceptBankAuthority';
 export * from './borrow';
+export * from './cancelConfigChange';
+export * from './collectProtocolFees';
+export * from './createMultisig';
 export * from './deposit';
+export * from './executeConfigChange';
+export * from './fundInsurance';
 export * from './initializeAccount';
 export * from './initializeBank';
+export * from './initializeMarket';
 export * from './liquidate';
+export * from './proposeBankAuthority';
+export * from './queueConfigChange';
+export * from './refreshBank';
 export * from './repay';
+export * from './setBankPause';
+export * from './setGuardian';
+export * from './setMarketPause';
+export * from './setMultisigSigners';
+export * from './socializeBadDebt';
+export * from './updateBankCaps';
 export * from './withdraw';
+export * from './withdrawInsurance';
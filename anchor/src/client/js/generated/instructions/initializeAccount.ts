@@ -52,6 +52,7 @@ export function getInitializeAccountDiscriminatorBytes() {
 export type InitializeAccountInstruction<
   TProgram extends string = typeof LENDING_PROTOCOL_PROGRAM_ADDRESS,
   TAccountSigner extends string | AccountMeta<string> = string,
+  TAccountMarket extends string | AccountMeta<string> = string,
   TAccountUserAccount extends string | AccountMeta<string> = string,
   TAccountSystemProgram extends
     | string
@@ -65,6 +66,9 @@ export type InitializeAccountInstruction<
         ? WritableSignerAccount<TAccountSigner> &
             AccountSignerMeta<TAccountSigner>
         : TAccountSigner,
+      TAccountMarket extends string
+        ? ReadonlyAccount<TAccountMarket>
+        : TAccountMarket,
       TAccountUserAccount extends string
         ? WritableAccount<TAccountUserAccount>
         : TAccountUserAccount,
@@ -83,7 +87,9 @@ export type InitializeAccountInstructionDataArgs = {};
 
 export function getInitializeAccountInstructionDataEncoder(): FixedSizeEncoder<InitializeAccountInstructionDataArgs> {
   return transformEncoder(
-    getStructEncoder([['discriminator', fixEncoderSize(getBytesEncoder(), 8)]]),
+    getStructEncoder([
+      ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
+    ]),
     (value) => ({ ...value, discriminator: INITIALIZE_ACCOUNT_DISCRIMINATOR })
   );
 }
@@ -106,10 +112,13 @@ export function getInitializeAccountInstructionDataCodec(): FixedSizeCodec<
 
 export type InitializeAccountAsyncInput<
   TAccountSigner extends string = string,
+  TAccountMarket extends string = string,
   TAccountUserAccount extends string = string,
   TAccountSystemProgram extends string = string,
 > = {
   signer: TransactionSigner<TAccountSigner>;
+  /** The lending market the user account is opened in */
+  market: Address<TAccountMarket>;
   /** Initialize the user account */
   userAccount?: Address<TAccountUserAccount>;
   systemProgram?: Address<TAccountSystemProgram>;
@@ -117,12 +126,14 @@ export type InitializeAccountAsyncInput<
 
 export async function getInitializeAccountInstructionAsync<
   TAccountSigner extends string,
+  TAccountMarket extends string,
   TAccountUserAccount extends string,
   TAccountSystemProgram extends string,
   TProgramAddress extends Address = typeof LENDING_PROTOCOL_PROGRAM_ADDRESS,
 >(
   input: InitializeAccountAsyncInput<
     TAccountSigner,
+    TAccountMarket,
     TAccountUserAccount,
     TAccountSystemProgram
   >,
@@ -131,6 +142,7 @@ export async function getInitializeAccountInstructionAsync<
   InitializeAccountInstruction<
     TProgramAddress,
     TAccountSigner,
+    TAccountMarket,
     TAccountUserAccount,
     TAccountSystemProgram
   >
@@ -142,6 +154,7 @@ export async function getInitializeAccountInstructionAsync<
   // Original accounts.
   const originalAccounts = {
     signer: { value: input.signer ?? null, isWritable: true },
+    market: { value: input.market ?? null, isWritable: false },
     userAccount: { value: input.userAccount ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
   };
@@ -154,7 +167,10 @@ export async function getInitializeAccountInstructionAsync<
   if (!accounts.userAccount.value) {
     accounts.userAccount.value = await getProgramDerivedAddress({
       programAddress,
-      seeds: [getAddressEncoder().encode(expectAddress(accounts.signer.value))],
+      seeds: [
+        getAddressEncoder().encode(expectAddress(accounts.market.value)),
+        getAddressEncoder().encode(expectAddress(accounts.signer.value)),
+      ],
     });
   }
   if (!accounts.systemProgram.value) {
@@ -166,6 +182,7 @@ export async function getInitializeAccountInstructionAsync<
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.signer),
+      getAccountMeta(accounts.market),
       getAccountMeta(accounts.userAccount),
       getAccountMeta(accounts.systemProgram),
     ],
@@ -174,6 +191,7 @@ export async function getInitializeAccountInstructionAsync<
   } as InitializeAccountInstruction<
     TProgramAddress,
     TAccountSigner,
+    TAccountMarket,
     TAccountUserAccount,
     TAccountSystemProgram
   >);
@@ -181,10 +199,13 @@ export async function getInitializeAccountInstructionAsync<
 
 export type InitializeAccountInput<
   TAccountSigner extends string = string,
+  TAccountMarket extends string = string,
   TAccountUserAccount extends string = string,
   TAccountSystemProgram extends string = string,
 > = {
   signer: TransactionSigner<TAccountSigner>;
+  /** The lending market the user account is opened in */
+  market: Address<TAccountMarket>;
   /** Initialize the user account */
   userAccount: Address<TAccountUserAccount>;
   systemProgram?: Address<TAccountSystemProgram>;
@@ -192,12 +213,14 @@ export type InitializeAccountInput<
 
 export function getInitializeAccountInstruction<
   TAccountSigner extends string,
+  TAccountMarket extends string,
   TAccountUserAccount extends string,
   TAccountSystemProgram extends string,
   TProgramAddress extends Address = typeof LENDING_PROTOCOL_PROGRAM_ADDRESS,
 >(
   input: InitializeAccountInput<
     TAccountSigner,
+    TAccountMarket,
     TAccountUserAccount,
     TAccountSystemProgram
   >,
@@ -205,6 +228,7 @@ export function getInitializeAccountInstruction<
 ): InitializeAccountInstruction<
   TProgramAddress,
   TAccountSigner,
+  TAccountMarket,
   TAccountUserAccount,
   TAccountSystemProgram
 > {
@@ -215,6 +239,7 @@ export function getInitializeAccountInstruction<
   // Original accounts.
   const originalAccounts = {
     signer: { value: input.signer ?? null, isWritable: true },
+    market: { value: input.market ?? null, isWritable: false },
     userAccount: { value: input.userAccount ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
   };
@@ -233,6 +258,7 @@ export function getInitializeAccountInstruction<
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.signer),
+      getAccountMeta(accounts.market),
       getAccountMeta(accounts.userAccount),
       getAccountMeta(accounts.systemProgram),
     ],
@@ -241,6 +267,7 @@ export function getInitializeAccountInstruction<
   } as InitializeAccountInstruction<
     TProgramAddress,
     TAccountSigner,
+    TAccountMarket,
     TAccountUserAccount,
     TAccountSystemProgram
   >);
@@ -253,9 +280,11 @@ export type ParsedInitializeAccountInstruction<
   programAddress: Address<TProgram>;
   accounts: {
     signer: TAccountMetas[0];
+    /** The lending market the user account is opened in */
+    market: TAccountMetas[1];
     /** Initialize the user account */
-    userAccount: TAccountMetas[1];
-    systemProgram: TAccountMetas[2];
+    userAccount: TAccountMetas[2];
+    systemProgram: TAccountMetas[3];
   };
   data: InitializeAccountInstructionData;
 };
@@ -268,7 +297,7 @@ export function parseInitializeAccountInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedInitializeAccountInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 3) {
+  if (instruction.accounts.length < 4) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -282,6 +311,7 @@ export function parseInitializeAccountInstruction<
     programAddress: instruction.programAddress,
     accounts: {
       signer: getNextAccount(),
+      market: getNextAccount(),
       userAccount: getNextAccount(),
       systemProgram: getNextAccount(),
     },
@@ -40,6 +40,12 @@ import {
   getAccountMetaFactory,
   type ResolvedAccount,
 } from '../shared';
+import {
+  getOracleConfigDecoder,
+  getOracleConfigEncoder,
+  type OracleConfig,
+  type OracleConfigArgs,
+} from '../types';
 
 export const INITIALIZE_BANK_DISCRIMINATOR = new Uint8Array([
   217, 55, 77, 45, 245, 197, 75, 140,
@@ -53,10 +59,13 @@ export function getInitializeBankDiscriminatorBytes() {
 
 export type InitializeBankInstruction<
   TProgram extends string = typeof LENDING_PROTOCOL_PROGRAM_ADDRESS,
-  TAccountSigner extends string | AccountMeta<string> = string,
+  TAccountPayer extends string | AccountMeta<string> = string,
+  TAccountAdmin extends string | AccountMeta<string> = string,
+  TAccountMarket extends string | AccountMeta<string> = string,
   TAccountMint extends string | AccountMeta<string> = string,
   TAccountBank extends string | AccountMeta<string> = string,
   TAccountBankTokenAccount extends string | AccountMeta<string> = string,
+  TAccountInsuranceTokenAccount extends string | AccountMeta<string> = string,
   TAccountTokenProgram extends
     | string
     | AccountMeta<string> = 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA',
@@ -68,10 +77,16 @@ export type InitializeBankInstruction<
   InstructionWithData<ReadonlyUint8Array> &
   InstructionWithAccounts<
     [
-      TAccountSigner extends string
-        ? WritableSignerAccount<TAccountSigner> &
-            AccountSignerMeta<TAccountSigner>
-        : TAccountSigner,
+      TAccountPayer extends string
+        ? WritableSignerAccount<TAccountPayer> &
+            AccountSignerMeta<TAccountPayer>
+        : TAccountPayer,
+      TAccountAdmin extends string
+        ? ReadonlyAccount<TAccountAdmin>
+        : TAccountAdmin,
+      TAccountMarket extends string
+        ? ReadonlyAccount<TAccountMarket>
+        : TAccountMarket,
       TAccountMint extends string
         ? ReadonlyAccount<TAccountMint>
         : TAccountMint,
@@ -81,6 +96,9 @@ export type InitializeBankInstruction<
       TAccountBankTokenAccount extends string
         ? WritableAccount<TAccountBankTokenAccount>
         : TAccountBankTokenAccount,
+      TAccountInsuranceTokenAccount extends string
+        ? WritableAccount<TAccountInsuranceTokenAccount>
+        : TAccountInsuranceTokenAccount,
       TAccountTokenProgram extends string
         ? ReadonlyAccount<TAccountTokenProgram>
         : TAccountTokenProgram,
@@ -95,11 +113,13 @@ export type InitializeBankInstructionData = {
   discriminator: ReadonlyUint8Array;
   liquidationThreshold: bigint;
   maxLtv: bigint;
+  oracle: OracleConfig;
 };
 
 export type InitializeBankInstructionDataArgs = {
   liquidationThreshold: number | bigint;
   maxLtv: number | bigint;
+  oracle: OracleConfigArgs;
 };
 
 export function getInitializeBankInstructionDataEncoder(): FixedSizeEncoder<InitializeBankInstructionDataArgs> {
@@ -108,6 +128,7 @@ export function getInitializeBankInstructionDataEncoder(): FixedSizeEncoder<Init
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
       ['liquidationThreshold', getU64Encoder()],
       ['maxLtv', getU64Encoder()],
+      ['oracle', getOracleConfigEncoder()],
     ]),
     (value) => ({ ...value, discriminator: INITIALIZE_BANK_DISCRIMINATOR })
   );
@@ -118,6 +139,7 @@ export function getInitializeBankInstructionDataDecoder(): FixedSizeDecoder<Init
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
     ['liquidationThreshold', getU64Decoder()],
     ['maxLtv', getU64Decoder()],
+    ['oracle', getOracleConfigDecoder()],
   ]);
 }
 
@@ -132,14 +154,21 @@ export function getInitializeBankInstructionDataCodec(): FixedSizeCodec<
 }
 
 export type InitializeBankAsyncInput<
-  TAccountSigner extends string = string,
+  TAccountPayer extends string = string,
+  TAccountAdmin extends string = string,
+  TAccountMarket extends string = string,
   TAccountMint extends string = string,
   TAccountBank extends string = string,
   TAccountBankTokenAccount extends string = string,
+  TAccountInsuranceTokenAccount extends string = string,
   TAccountTokenProgram extends string = string,
   TAccountSystemProgram extends string = string,
 > = {
-  signer: TransactionSigner<TAccountSigner>;
+  payer: TransactionSigner<TAccountPayer>;
+  /** Only the admin of the market can list a new bank, otherwise whoever gets there first would control the bank of an asset */
+  admin: Address<TAccountAdmin>;
+  /** The lending market the bank belongs to */
+  market: Address<TAccountMarket>;
   /** The mint address of asset of the bank */
   mint: Address<TAccountMint>;
   /** Initialize the bank account */
@@ -150,6 +179,12 @@ export type InitializeBankAsyncInput<
    * We are not using an Associated Token Account instead - a Token Account with a PDA so we are able to know that this account is specific to lending protocol bank.
    */
   bankTokenAccount?: Address<TAccountBankTokenAccount>;
+  /**
+   * Insurance fund of the bank, a backstop that absorbs bad debt before the depositors do
+   *
+   * Same as the bank token account, it's a PDA token account that is its own authority.
+   */
+  insuranceTokenAccount?: Address<TAccountInsuranceTokenAccount>;
   /**
    * Token program to create the token account.
    *
@@ -160,22 +195,29 @@ export type InitializeBankAsyncInput<
   systemProgram?: Address<TAccountSystemProgram>;
   liquidationThreshold: InitializeBankInstructionDataArgs['liquidationThreshold'];
   maxLtv: InitializeBankInstructionDataArgs['maxLtv'];
+  oracle: InitializeBankInstructionDataArgs['oracle'];
 };
 
 export async function getInitializeBankInstructionAsync<
-  TAccountSigner extends string,
+  TAccountPayer extends string,
+  TAccountAdmin extends string,
+  TAccountMarket extends string,
   TAccountMint extends string,
   TAccountBank extends string,
   TAccountBankTokenAccount extends string,
+  TAccountInsuranceTokenAccount extends string,
   TAccountTokenProgram extends string,
   TAccountSystemProgram extends string,
   TProgramAddress extends Address = typeof LENDING_PROTOCOL_PROGRAM_ADDRESS,
 >(
   input: InitializeBankAsyncInput<
-    TAccountSigner,
+    TAccountPayer,
+    TAccountAdmin,
+    TAccountMarket,
     TAccountMint,
     TAccountBank,
     TAccountBankTokenAccount,
+    TAccountInsuranceTokenAccount,
     TAccountTokenProgram,
     TAccountSystemProgram
   >,
@@ -183,10 +225,13 @@ export async function getInitializeBankInstructionAsync<
 ): Promise<
   InitializeBankInstruction<
     TProgramAddress,
-    TAccountSigner,
+    TAccountPayer,
+    TAccountAdmin,
+    TAccountMarket,
     TAccountMint,
     TAccountBank,
     TAccountBankTokenAccount,
+    TAccountInsuranceTokenAccount,
     TAccountTokenProgram,
     TAccountSystemProgram
   >
@@ -197,13 +242,19 @@ export async function getInitializeBankInstructionAsync<
 
   // Original accounts.
   const originalAccounts = {
-    signer: { value: input.signer ?? null, isWritable: true },
+    payer: { value: input.payer ?? null, isWritable: true },
+    admin: { value: input.admin ?? null, isWritable: false },
+    market: { value: input.market ?? null, isWritable: false },
     mint: { value: input.mint ?? null, isWritable: false },
     bank: { value: input.bank ?? null, isWritable: true },
     bankTokenAccount: {
       value: input.bankTokenAccount ?? null,
       isWritable: true,
     },
+    insuranceTokenAccount: {
+      value: input.insuranceTokenAccount ?? null,
+      isWritable: true,
+    },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
   };
@@ -219,7 +270,10 @@ export async function getInitializeBankInstructionAsync<
   if (!accounts.bank.value) {
     accounts.bank.value = await getProgramDerivedAddress({
       programAddress,
-      seeds: [getAddressEncoder().encode(expectAddress(accounts.mint.value))],
+      seeds: [
+        getAddressEncoder().encode(expectAddress(accounts.market.value)),
+        getAddressEncoder().encode(expectAddress(accounts.mint.value)),
+      ],
     });
   }
   if (!accounts.bankTokenAccount.value) {
@@ -229,6 +283,19 @@ export async function getInitializeBankInstructionAsync<
         getBytesEncoder().encode(
           new Uint8Array([84, 114, 101, 97, 115, 117, 114, 121])
         ),
+        getAddressEncoder().encode(expectAddress(accounts.market.value)),
+        getAddressEncoder().encode(expectAddress(accounts.mint.value)),
+      ],
+    });
+  }
+  if (!accounts.insuranceTokenAccount.value) {
+    accounts.insuranceTokenAccount.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([73, 110, 115, 117, 114, 97, 110, 99, 101])
+        ),
+        getAddressEncoder().encode(expectAddress(accounts.market.value)),
         getAddressEncoder().encode(expectAddress(accounts.mint.value)),
       ],
     });
@@ -245,10 +312,13 @@ export async function getInitializeBankInstructionAsync<
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
     accounts: [
-      getAccountMeta(accounts.signer),
+      getAccountMeta(accounts.payer),
+      getAccountMeta(accounts.admin),
+      getAccountMeta(accounts.market),
       getAccountMeta(accounts.mint),
       getAccountMeta(accounts.bank),
       getAccountMeta(accounts.bankTokenAccount),
+      getAccountMeta(accounts.insuranceTokenAccount),
       getAccountMeta(accounts.tokenProgram),
       getAccountMeta(accounts.systemProgram),
     ],
@@ -258,24 +328,34 @@ export async function getInitializeBankInstructionAsync<
     programAddress,
   } as InitializeBankInstruction<
     TProgramAddress,
-    TAccountSigner,
+    TAccountPayer,
+    TAccountAdmin,
+    TAccountMarket,
     TAccountMint,
     TAccountBank,
     TAccountBankTokenAccount,
+    TAccountInsuranceTokenAccount,
     TAccountTokenProgram,
     TAccountSystemProgram
   >);
 }
 
 export type InitializeBankInput<
-  TAccountSigner extends string = string,
+  TAccountPayer extends string = string,
+  TAccountAdmin extends string = string,
+  TAccountMarket extends string = string,
   TAccountMint extends string = string,
   TAccountBank extends string = string,
   TAccountBankTokenAccount extends string = string,
+  TAccountInsuranceTokenAccount extends string = string,
   TAccountTokenProgram extends string = string,
   TAccountSystemProgram extends string = string,
 > = {
-  signer: TransactionSigner<TAccountSigner>;
+  payer: TransactionSigner<TAccountPayer>;
+  /** Only the admin of the market can list a new bank, otherwise whoever gets there first would control the bank of an asset */
+  admin: Address<TAccountAdmin>;
+  /** The lending market the bank belongs to */
+  market: Address<TAccountMarket>;
   /** The mint address of asset of the bank */
   mint: Address<TAccountMint>;
   /** Initialize the bank account */
@@ -286,6 +366,12 @@ export type InitializeBankInput<
    * We are not using an Associated Token Account instead - a Token Account with a PDA so we are able to know that this account is specific to lending protocol bank.
    */
   bankTokenAccount: Address<TAccountBankTokenAccount>;
+  /**
+   * Insurance fund of the bank, a backstop that absorbs bad debt before the depositors do
+   *
+   * Same as the bank token account, it's a PDA token account that is its own authority.
+   */
+  insuranceTokenAccount: Address<TAccountInsuranceTokenAccount>;
   /**
    * Token program to create the token account.
    *
@@ -296,32 +382,42 @@ export type InitializeBankInput<
   systemProgram?: Address<TAccountSystemProgram>;
   liquidationThreshold: InitializeBankInstructionDataArgs['liquidationThreshold'];
   maxLtv: InitializeBankInstructionDataArgs['maxLtv'];
+  oracle: InitializeBankInstructionDataArgs['oracle'];
 };
 
 export function getInitializeBankInstruction<
-  TAccountSigner extends string,
+  TAccountPayer extends string,
+  TAccountAdmin extends string,
+  TAccountMarket extends string,
   TAccountMint extends string,
   TAccountBank extends string,
   TAccountBankTokenAccount extends string,
+  TAccountInsuranceTokenAccount extends string,
   TAccountTokenProgram extends string,
   TAccountSystemProgram extends string,
   TProgramAddress extends Address = typeof LENDING_PROTOCOL_PROGRAM_ADDRESS,
 >(
   input: InitializeBankInput<
-    TAccountSigner,
+    TAccountPayer,
+    TAccountAdmin,
+    TAccountMarket,
     TAccountMint,
     TAccountBank,
     TAccountBankTokenAccount,
+    TAccountInsuranceTokenAccount,
     TAccountTokenProgram,
     TAccountSystemProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): InitializeBankInstruction<
   TProgramAddress,
-  TAccountSigner,
+  TAccountPayer,
+  TAccountAdmin,
+  TAccountMarket,
   TAccountMint,
   TAccountBank,
   TAccountBankTokenAccount,
+  TAccountInsuranceTokenAccount,
   TAccountTokenProgram,
   TAccountSystemProgram
 > {
@@ -331,13 +427,19 @@ export function getInitializeBankInstruction<
 
   // Original accounts.
   const originalAccounts = {
-    signer: { value: input.signer ?? null, isWritable: true },
+    payer: { value: input.payer ?? null, isWritable: true },
+    admin: { value: input.admin ?? null, isWritable: false },
+    market: { value: input.market ?? null, isWritable: false },
     mint: { value: input.mint ?? null, isWritable: false },
     bank: { value: input.bank ?? null, isWritable: true },
     bankTokenAccount: {
       value: input.bankTokenAccount ?? null,
       isWritable: true,
     },
+    insuranceTokenAccount: {
+      value: input.insuranceTokenAccount ?? null,
+      isWritable: true,
+    },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
   };
@@ -362,10 +464,13 @@ export function getInitializeBankInstruction<
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
     accounts: [
-      getAccountMeta(accounts.signer),
+      getAccountMeta(accounts.payer),
+      getAccountMeta(accounts.admin),
+      getAccountMeta(accounts.market),
       getAccountMeta(accounts.mint),
       getAccountMeta(accounts.bank),
       getAccountMeta(accounts.bankTokenAccount),
+      getAccountMeta(accounts.insuranceTokenAccount),
       getAccountMeta(accounts.tokenProgram),
       getAccountMeta(accounts.systemProgram),
     ],
@@ -375,10 +480,13 @@ export function getInitializeBankInstruction<
     programAddress,
   } as InitializeBankInstruction<
     TProgramAddress,
-    TAccountSigner,
+    TAccountPayer,
+    TAccountAdmin,
+    TAccountMarket,
     TAccountMint,
     TAccountBank,
     TAccountBankTokenAccount,
+    TAccountInsuranceTokenAccount,
     TAccountTokenProgram,
     TAccountSystemProgram
   >);
@@ -390,25 +498,35 @@ export type ParsedInitializeBankInstruction<
 > = {
   programAddress: Address<TProgram>;
   accounts: {
-    signer: TAccountMetas[0];
+    payer: TAccountMetas[0];
+    /** Only the admin of the market can list a new bank, otherwise whoever gets there first would control the bank of an asset */
+    admin: TAccountMetas[1];
+    /** The lending market the bank belongs to */
+    market: TAccountMetas[2];
     /** The mint address of asset of the bank */
-    mint: TAccountMetas[1];
+    mint: TAccountMetas[3];
     /** Initialize the bank account */
-    bank: TAccountMetas[2];
+    bank: TAccountMetas[4];
     /**
      * Token account to hold the tokens for the bank
      *
      * We are not using an Associated Token Account instead - a Token Account with a PDA so we are able to know that this account is specific to lending protocol bank.
      */
-    bankTokenAccount: TAccountMetas[3];
+    bankTokenAccount: TAccountMetas[5];
+    /**
+     * Insurance fund of the bank, a backstop that absorbs bad debt before the depositors do
+     *
+     * Same as the bank token account, it's a PDA token account that is its own authority.
+     */
+    insuranceTokenAccount: TAccountMetas[6];
     /**
      * Token program to create the token account.
      *
      * When you use Interface<'info, TokenInterface>, Anchor automatically determines which token program to use based on the accounts passed in
      */
-    tokenProgram: TAccountMetas[4];
+    tokenProgram: TAccountMetas[7];
     /** System program to initialize the bank account */
-    systemProgram: TAccountMetas[5];
+    systemProgram: TAccountMetas[8];
   };
   data: InitializeBankInstructionData;
 };
@@ -421,7 +539,7 @@ export function parseInitializeBankInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedInitializeBankInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 6) {
+  if (instruction.accounts.length < 9) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -434,10 +552,13 @@ export function parseInitializeBankInstruction<
   return {
     programAddress: instruction.programAddress,
     accounts: {
-      signer: getNextAccount(),
+      payer: getNextAccount(),
+      admin: getNextAccount(),
+      market: getNextAccount(),
       mint: getNextAccount(),
       bank: getNextAccount(),
       bankTokenAccount: getNextAccount(),
+      insuranceTokenAccount: getNextAccount(),
       tokenProgram: getNextAccount(),
       systemProgram: getNextAccount(),
     },
@@ -16,6 +16,8 @@ import {
   getProgramDerivedAddress,
   getStructDecoder,
   getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
   transformEncoder,
   type AccountMeta,
   type AccountSignerMeta,
@@ -38,12 +40,6 @@ import {
   getAccountMetaFactory,
   type ResolvedAccount,
 } from '../shared';
-import {
-  getTokenTypeDecoder,
-  getTokenTypeEncoder,
-  type TokenType,
-  type TokenTypeArgs,
-} from '../types';
 
 export const LIQUIDATE_DISCRIMINATOR = new Uint8Array([
   223, 179, 226, 125, 48, 46, 39, 74,
@@ -56,18 +52,27 @@ export function getLiquidateDiscriminatorBytes() {
 export type LiquidateInstruction<
   TProgram extends string = typeof LENDING_PROTOCOL_PROGRAM_ADDRESS,
   TAccountLiquidator extends string | AccountMeta<string> = string,
-  TAccountPriceUpdateAccount extends string | AccountMeta<string> = string,
+  TAccountCollateralOracle extends string | AccountMeta<string> = string,
+  TAccountBorrowedOracle extends string | AccountMeta<string> = string,
+  TAccountCollateralSecondaryOracle extends
+    | string
+    | AccountMeta<string> = string,
+  TAccountBorrowedSecondaryOracle extends string | AccountMeta<string> = string,
+  TAccountMarket extends string | AccountMeta<string> = string,
   TAccountCollateralMint extends string | AccountMeta<string> = string,
   TAccountBorrowedMint extends string | AccountMeta<string> = string,
   TAccountCollateralBank extends string | AccountMeta<string> = string,
   TAccountCollateralBankTokenAccount extends
     | string
     | AccountMeta<string> = string,
+  TAccountCollateralInsuranceTokenAccount extends
+    | string
+    | AccountMeta<string> = string,
   TAccountBorrowedBank extends string | AccountMeta<string> = string,
   TAccountBorrowedBankTokenAccount extends
     | string
     | AccountMeta<string> = string,
-  TAccountLiquidatorUserAccount extends string | AccountMeta<string> = string,
+  TAccountBorrowerUserAccount extends string | AccountMeta<string> = string,
   TAccountLiquidatorBorrowedTokenAccount extends
     | string
     | AccountMeta<string> = string,
@@ -92,9 +97,21 @@ export type LiquidateInstruction<
         ? WritableSignerAccount<TAccountLiquidator> &
             AccountSignerMeta<TAccountLiquidator>
         : TAccountLiquidator,
-      TAccountPriceUpdateAccount extends string
-        ? WritableAccount<TAccountPriceUpdateAccount>
-        : TAccountPriceUpdateAccount,
+      TAccountCollateralOracle extends string
+        ? ReadonlyAccount<TAccountCollateralOracle>
+        : TAccountCollateralOracle,
+      TAccountBorrowedOracle extends string
+        ? ReadonlyAccount<TAccountBorrowedOracle>
+        : TAccountBorrowedOracle,
+      TAccountCollateralSecondaryOracle extends string
+        ? ReadonlyAccount<TAccountCollateralSecondaryOracle>
+        : TAccountCollateralSecondaryOracle,
+      TAccountBorrowedSecondaryOracle extends string
+        ? ReadonlyAccount<TAccountBorrowedSecondaryOracle>
+        : TAccountBorrowedSecondaryOracle,
+      TAccountMarket extends string
+        ? ReadonlyAccount<TAccountMarket>
+        : TAccountMarket,
       TAccountCollateralMint extends string
         ? ReadonlyAccount<TAccountCollateralMint>
         : TAccountCollateralMint,
@@ -107,15 +124,18 @@ export type LiquidateInstruction<
       TAccountCollateralBankTokenAccount extends string
         ? WritableAccount<TAccountCollateralBankTokenAccount>
         : TAccountCollateralBankTokenAccount,
+      TAccountCollateralInsuranceTokenAccount extends string
+        ? WritableAccount<TAccountCollateralInsuranceTokenAccount>
+        : TAccountCollateralInsuranceTokenAccount,
       TAccountBorrowedBank extends string
         ? WritableAccount<TAccountBorrowedBank>
         : TAccountBorrowedBank,
       TAccountBorrowedBankTokenAccount extends string
         ? WritableAccount<TAccountBorrowedBankTokenAccount>
         : TAccountBorrowedBankTokenAccount,
-      TAccountLiquidatorUserAccount extends string
-        ? WritableAccount<TAccountLiquidatorUserAccount>
-        : TAccountLiquidatorUserAccount,
+      TAccountBorrowerUserAccount extends string
+        ? WritableAccount<TAccountBorrowerUserAccount>
+        : TAccountBorrowerUserAccount,
       TAccountLiquidatorBorrowedTokenAccount extends string
         ? WritableAccount<TAccountLiquidatorBorrowedTokenAccount>
         : TAccountLiquidatorBorrowedTokenAccount,
@@ -137,16 +157,21 @@ export type LiquidateInstruction<
 
 export type LiquidateInstructionData = {
   discriminator: ReadonlyUint8Array;
-  tokenType: TokenType;
+  repayAmount: bigint;
+  minCollateralOut: bigint;
 };
 
-export type LiquidateInstructionDataArgs = { tokenType: TokenTypeArgs };
+export type LiquidateInstructionDataArgs = {
+  repayAmount: number | bigint;
+  minCollateralOut: number | bigint;
+};
 
 export function getLiquidateInstructionDataEncoder(): FixedSizeEncoder<LiquidateInstructionDataArgs> {
   return transformEncoder(
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
-      ['tokenType', getTokenTypeEncoder()],
+      ['repayAmount', getU64Encoder()],
+      ['minCollateralOut', getU64Encoder()],
     ]),
     (value) => ({ ...value, discriminator: LIQUIDATE_DISCRIMINATOR })
   );
@@ -155,7 +180,8 @@ export function getLiquidateInstructionDataEncoder(): FixedSizeEncoder<Liquidate
 export function getLiquidateInstructionDataDecoder(): FixedSizeDecoder<LiquidateInstructionData> {
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
-    ['tokenType', getTokenTypeDecoder()],
+    ['repayAmount', getU64Decoder()],
+    ['minCollateralOut', getU64Decoder()],
   ]);
 }
 
@@ -171,14 +197,19 @@ export function getLiquidateInstructionDataCodec(): FixedSizeCodec<
 
 export type LiquidateAsyncInput<
   TAccountLiquidator extends string = string,
-  TAccountPriceUpdateAccount extends string = string,
+  TAccountCollateralOracle extends string = string,
+  TAccountBorrowedOracle extends string = string,
+  TAccountCollateralSecondaryOracle extends string = string,
+  TAccountBorrowedSecondaryOracle extends string = string,
+  TAccountMarket extends string = string,
   TAccountCollateralMint extends string = string,
   TAccountBorrowedMint extends string = string,
   TAccountCollateralBank extends string = string,
   TAccountCollateralBankTokenAccount extends string = string,
+  TAccountCollateralInsuranceTokenAccount extends string = string,
   TAccountBorrowedBank extends string = string,
   TAccountBorrowedBankTokenAccount extends string = string,
-  TAccountLiquidatorUserAccount extends string = string,
+  TAccountBorrowerUserAccount extends string = string,
   TAccountLiquidatorBorrowedTokenAccount extends string = string,
   TAccountLiquidatorCollateralTokenAccount extends string = string,
   TAccountSystemProgram extends string = string,
@@ -186,9 +217,19 @@ export type LiquidateAsyncInput<
   TAccountAssociatedTokenProgram extends string = string,
 > = {
   liquidator: TransactionSigner<TAccountLiquidator>;
-  priceUpdateAccount: Address<TAccountPriceUpdateAccount>;
+  /** Price of the collateral asset */
+  collateralOracle: Address<TAccountCollateralOracle>;
+  /** Price of the borrowed asset */
+  borrowedOracle: Address<TAccountBorrowedOracle>;
+  /** Secondary oracle of the collateral bank, only needed if it has one */
+  collateralSecondaryOracle?: Address<TAccountCollateralSecondaryOracle>;
+  /** Secondary oracle of the borrowed bank, only needed if it has one */
+  borrowedSecondaryOracle?: Address<TAccountBorrowedSecondaryOracle>;
+  /** The lending market both banks and the borrower belong to */
+  market: Address<TAccountMarket>;
   /** Mints for the collateral and borrowed asset */
   collateralMint: Address<TAccountCollateralMint>;
+  /** Must be a different asset than the collateral, otherwise the same bank would be loaded and written twice */
   borrowedMint: Address<TAccountBorrowedMint>;
   /**
    * Defining borrowed and collateral bank account for there respective token accounts
@@ -197,13 +238,15 @@ export type LiquidateAsyncInput<
   collateralBank?: Address<TAccountCollateralBank>;
   /** Collateral Bank token account */
   collateralBankTokenAccount?: Address<TAccountCollateralBankTokenAccount>;
+  /** Insurance fund of the collateral bank, receives its share of the liquidation bonus */
+  collateralInsuranceTokenAccount?: Address<TAccountCollateralInsuranceTokenAccount>;
   /** Borrowed bank account */
   borrowedBank?: Address<TAccountBorrowedBank>;
   /** Borrowed Bank token account */
   borrowedBankTokenAccount?: Address<TAccountBorrowedBankTokenAccount>;
-  /** User account for the liquidator */
-  liquidatorUserAccount: Address<TAccountLiquidatorUserAccount>;
-  /** Liquidator's borrowed token account */
+  /** User account of the borrower being liquidated */
+  borrowerUserAccount: Address<TAccountBorrowerUserAccount>;
+  /** Liquidator's borrowed token account, the debt of the borrower is repaid from it */
   liquidatorBorrowedTokenAccount?: Address<TAccountLiquidatorBorrowedTokenAccount>;
   /** Liquidator's collateral token account */
   liquidatorCollateralTokenAccount?: Address<TAccountLiquidatorCollateralTokenAccount>;
@@ -213,19 +256,25 @@ export type LiquidateAsyncInput<
   tokenProgram?: Address<TAccountTokenProgram>;
   /** Associated token program */
   associatedTokenProgram?: Address<TAccountAssociatedTokenProgram>;
-  tokenType: LiquidateInstructionDataArgs['tokenType'];
+  repayAmount: LiquidateInstructionDataArgs['repayAmount'];
+  minCollateralOut: LiquidateInstructionDataArgs['minCollateralOut'];
 };
 
 export async function getLiquidateInstructionAsync<
   TAccountLiquidator extends string,
-  TAccountPriceUpdateAccount extends string,
+  TAccountCollateralOracle extends string,
+  TAccountBorrowedOracle extends string,
+  TAccountCollateralSecondaryOracle extends string,
+  TAccountBorrowedSecondaryOracle extends string,
+  TAccountMarket extends string,
   TAccountCollateralMint extends string,
   TAccountBorrowedMint extends string,
   TAccountCollateralBank extends string,
   TAccountCollateralBankTokenAccount extends string,
+  TAccountCollateralInsuranceTokenAccount extends string,
   TAccountBorrowedBank extends string,
   TAccountBorrowedBankTokenAccount extends string,
-  TAccountLiquidatorUserAccount extends string,
+  TAccountBorrowerUserAccount extends string,
   TAccountLiquidatorBorrowedTokenAccount extends string,
   TAccountLiquidatorCollateralTokenAccount extends string,
   TAccountSystemProgram extends string,
@@ -235,14 +284,19 @@ export async function getLiquidateInstructionAsync<
 >(
   input: LiquidateAsyncInput<
     TAccountLiquidator,
-    TAccountPriceUpdateAccount,
+    TAccountCollateralOracle,
+    TAccountBorrowedOracle,
+    TAccountCollateralSecondaryOracle,
+    TAccountBorrowedSecondaryOracle,
+    TAccountMarket,
     TAccountCollateralMint,
     TAccountBorrowedMint,
     TAccountCollateralBank,
     TAccountCollateralBankTokenAccount,
+    TAccountCollateralInsuranceTokenAccount,
     TAccountBorrowedBank,
     TAccountBorrowedBankTokenAccount,
-    TAccountLiquidatorUserAccount,
+    TAccountBorrowerUserAccount,
     TAccountLiquidatorBorrowedTokenAccount,
     TAccountLiquidatorCollateralTokenAccount,
     TAccountSystemProgram,
@@ -254,14 +308,19 @@ export async function getLiquidateInstructionAsync<
   LiquidateInstruction<
     TProgramAddress,
     TAccountLiquidator,
-    TAccountPriceUpdateAccount,
+    TAccountCollateralOracle,
+    TAccountBorrowedOracle,
+    TAccountCollateralSecondaryOracle,
+    TAccountBorrowedSecondaryOracle,
+    TAccountMarket,
     TAccountCollateralMint,
     TAccountBorrowedMint,
     TAccountCollateralBank,
     TAccountCollateralBankTokenAccount,
+    TAccountCollateralInsuranceTokenAccount,
     TAccountBorrowedBank,
     TAccountBorrowedBankTokenAccount,
-    TAccountLiquidatorUserAccount,
+    TAccountBorrowerUserAccount,
     TAccountLiquidatorBorrowedTokenAccount,
     TAccountLiquidatorCollateralTokenAccount,
     TAccountSystemProgram,
@@ -276,10 +335,20 @@ export async function getLiquidateInstructionAsync<
   // Original accounts.
   const originalAccounts = {
     liquidator: { value: input.liquidator ?? null, isWritable: true },
-    priceUpdateAccount: {
-      value: input.priceUpdateAccount ?? null,
-      isWritable: true,
+    collateralOracle: {
+      value: input.collateralOracle ?? null,
+      isWritable: false,
+    },
+    borrowedOracle: { value: input.borrowedOracle ?? null, isWritable: false },
+    collateralSecondaryOracle: {
+      value: input.collateralSecondaryOracle ?? null,
+      isWritable: false,
     },
+    borrowedSecondaryOracle: {
+      value: input.borrowedSecondaryOracle ?? null,
+      isWritable: false,
+    },
+    market: { value: input.market ?? null, isWritable: false },
     collateralMint: { value: input.collateralMint ?? null, isWritable: false },
     borrowedMint: { value: input.borrowedMint ?? null, isWritable: false },
     collateralBank: { value: input.collateralBank ?? null, isWritable: true },
@@ -287,13 +356,17 @@ export async function getLiquidateInstructionAsync<
       value: input.collateralBankTokenAccount ?? null,
       isWritable: true,
     },
+    collateralInsuranceTokenAccount: {
+      value: input.collateralInsuranceTokenAccount ?? null,
+      isWritable: true,
+    },
     borrowedBank: { value: input.borrowedBank ?? null, isWritable: true },
     borrowedBankTokenAccount: {
       value: input.borrowedBankTokenAccount ?? null,
       isWritable: true,
     },
-    liquidatorUserAccount: {
-      value: input.liquidatorUserAccount ?? null,
+    borrowerUserAccount: {
+      value: input.borrowerUserAccount ?? null,
       isWritable: true,
     },
     liquidatorBorrowedTokenAccount: {
@@ -324,6 +397,7 @@ export async function getLiquidateInstructionAsync<
     accounts.collateralBank.value = await getProgramDerivedAddress({
       programAddress,
       seeds: [
+        getAddressEncoder().encode(expectAddress(accounts.market.value)),
         getAddressEncoder().encode(
           expectAddress(accounts.collateralMint.value)
         ),
@@ -337,16 +411,33 @@ export async function getLiquidateInstructionAsync<
         getBytesEncoder().encode(
           new Uint8Array([84, 114, 101, 97, 115, 117, 114, 121])
         ),
+        getAddressEncoder().encode(expectAddress(accounts.market.value)),
         getAddressEncoder().encode(
           expectAddress(accounts.collateralMint.value)
         ),
       ],
     });
   }
+  if (!accounts.collateralInsuranceTokenAccount.value) {
+    accounts.collateralInsuranceTokenAccount.value =
+      await getProgramDerivedAddress({
+        programAddress,
+        seeds: [
+          getBytesEncoder().encode(
+            new Uint8Array([73, 110, 115, 117, 114, 97, 110, 99, 101])
+          ),
+          getAddressEncoder().encode(expectAddress(accounts.market.value)),
+          getAddressEncoder().encode(
+            expectAddress(accounts.collateralMint.value)
+          ),
+        ],
+      });
+  }
   if (!accounts.borrowedBank.value) {
     accounts.borrowedBank.value = await getProgramDerivedAddress({
       programAddress,
       seeds: [
+        getAddressEncoder().encode(expectAddress(accounts.market.value)),
         getAddressEncoder().encode(expectAddress(accounts.borrowedMint.value)),
       ],
     });
@@ -358,25 +449,24 @@ export async function getLiquidateInstructionAsync<
         getBytesEncoder().encode(
           new Uint8Array([84, 114, 101, 97, 115, 117, 114, 121])
         ),
+        getAddressEncoder().encode(expectAddress(accounts.market.value)),
         getAddressEncoder().encode(expectAddress(accounts.borrowedMint.value)),
       ],
     });
   }
+  if (!accounts.tokenProgram.value) {
+    accounts.tokenProgram.value =
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
+  }
   if (!accounts.liquidatorBorrowedTokenAccount.value) {
     accounts.liquidatorBorrowedTokenAccount.value =
       await getProgramDerivedAddress({
         programAddress:
           'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL' as Address<'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'>,
         seeds: [
+          getAddressEncoder().encode(expectAddress(accounts.liquidator.value)),
           getAddressEncoder().encode(
-            expectAddress(accounts.liquidatorUserAccount.value)
-          ),
-          getBytesEncoder().encode(
-            new Uint8Array([
-              6, 221, 246, 225, 215, 101, 161, 147, 217, 203, 225, 70, 206, 235,
-              121, 172, 28, 180, 133, 237, 95, 91, 55, 145, 58, 140, 245, 133,
-              126, 255, 0, 169,
-            ])
+            expectAddress(accounts.tokenProgram.value)
           ),
           getAddressEncoder().encode(
             expectAddress(accounts.borrowedMint.value)
@@ -390,15 +480,9 @@ export async function getLiquidateInstructionAsync<
         programAddress:
           'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL' as Address<'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'>,
         seeds: [
+          getAddressEncoder().encode(expectAddress(accounts.liquidator.value)),
           getAddressEncoder().encode(
-            expectAddress(accounts.liquidatorUserAccount.value)
-          ),
-          getBytesEncoder().encode(
-            new Uint8Array([
-              6, 221, 246, 225, 215, 101, 161, 147, 217, 203, 225, 70, 206, 235,
-              121, 172, 28, 180, 133, 237, 95, 91, 55, 145, 58, 140, 245, 133,
-              126, 255, 0, 169,
-            ])
+            expectAddress(accounts.tokenProgram.value)
           ),
           getAddressEncoder().encode(
             expectAddress(accounts.collateralMint.value)
@@ -410,10 +494,6 @@ export async function getLiquidateInstructionAsync<
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
   }
-  if (!accounts.tokenProgram.value) {
-    accounts.tokenProgram.value =
-      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
-  }
   if (!accounts.associatedTokenProgram.value) {
     accounts.associatedTokenProgram.value =
       'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL' as Address<'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'>;
@@ -423,14 +503,19 @@ export async function getLiquidateInstructionAsync<
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.liquidator),
-      getAccountMeta(accounts.priceUpdateAccount),
+      getAccountMeta(accounts.collateralOracle),
+      getAccountMeta(accounts.borrowedOracle),
+      getAccountMeta(accounts.collateralSecondaryOracle),
+      getAccountMeta(accounts.borrowedSecondaryOracle),
+      getAccountMeta(accounts.market),
       getAccountMeta(accounts.collateralMint),
       getAccountMeta(accounts.borrowedMint),
       getAccountMeta(accounts.collateralBank),
       getAccountMeta(accounts.collateralBankTokenAccount),
+      getAccountMeta(accounts.collateralInsuranceTokenAccount),
       getAccountMeta(accounts.borrowedBank),
       getAccountMeta(accounts.borrowedBankTokenAccount),
-      getAccountMeta(accounts.liquidatorUserAccount),
+      getAccountMeta(accounts.borrowerUserAccount),
       getAccountMeta(accounts.liquidatorBorrowedTokenAccount),
       getAccountMeta(accounts.liquidatorCollateralTokenAccount),
       getAccountMeta(accounts.systemProgram),
@@ -444,14 +529,19 @@ export async function getLiquidateInstructionAsync<
   } as LiquidateInstruction<
     TProgramAddress,
     TAccountLiquidator,
-    TAccountPriceUpdateAccount,
+    TAccountCollateralOracle,
+    TAccountBorrowedOracle,
+    TAccountCollateralSecondaryOracle,
+    TAccountBorrowedSecondaryOracle,
+    TAccountMarket,
     TAccountCollateralMint,
     TAccountBorrowedMint,
     TAccountCollateralBank,
     TAccountCollateralBankTokenAccount,
+    TAccountCollateralInsuranceTokenAccount,
     TAccountBorrowedBank,
     TAccountBorrowedBankTokenAccount,
-    TAccountLiquidatorUserAccount,
+    TAccountBorrowerUserAccount,
     TAccountLiquidatorBorrowedTokenAccount,
     TAccountLiquidatorCollateralTokenAccount,
     TAccountSystemProgram,
@@ -462,14 +552,19 @@ export async function getLiquidateInstructionAsync<
 
 export type LiquidateInput<
   TAccountLiquidator extends string = string,
-  TAccountPriceUpdateAccount extends string = string,
+  TAccountCollateralOracle extends string = string,
+  TAccountBorrowedOracle extends string = string,
+  TAccountCollateralSecondaryOracle extends string = string,
+  TAccountBorrowedSecondaryOracle extends string = string,
+  TAccountMarket extends string = string,
   TAccountCollateralMint extends string = string,
   TAccountBorrowedMint extends string = string,
   TAccountCollateralBank extends string = string,
   TAccountCollateralBankTokenAccount extends string = string,
+  TAccountCollateralInsuranceTokenAccount extends string = string,
   TAccountBorrowedBank extends string = string,
   TAccountBorrowedBankTokenAccount extends string = string,
-  TAccountLiquidatorUserAccount extends string = string,
+  TAccountBorrowerUserAccount extends string = string,
   TAccountLiquidatorBorrowedTokenAccount extends string = string,
   TAccountLiquidatorCollateralTokenAccount extends string = string,
   TAccountSystemProgram extends string = string,
@@ -477,9 +572,19 @@ export type LiquidateInput<
   TAccountAssociatedTokenProgram extends string = string,
 > = {
   liquidator: TransactionSigner<TAccountLiquidator>;
-  priceUpdateAccount: Address<TAccountPriceUpdateAccount>;
+  /** Price of the collateral asset */
+  collateralOracle: Address<TAccountCollateralOracle>;
+  /** Price of the borrowed asset */
+  borrowedOracle: Address<TAccountBorrowedOracle>;
+  /** Secondary oracle of the collateral bank, only needed if it has one */
+  collateralSecondaryOracle?: Address<TAccountCollateralSecondaryOracle>;
+  /** Secondary oracle of the borrowed bank, only needed if it has one */
+  borrowedSecondaryOracle?: Address<TAccountBorrowedSecondaryOracle>;
+  /** The lending market both banks and the borrower belong to */
+  market: Address<TAccountMarket>;
   /** Mints for the collateral and borrowed asset */
   collateralMint: Address<TAccountCollateralMint>;
+  /** Must be a different asset than the collateral, otherwise the same bank would be loaded and written twice */
   borrowedMint: Address<TAccountBorrowedMint>;
   /**
    * Defining borrowed and collateral bank account for there respective token accounts
@@ -488,13 +593,15 @@ export type LiquidateInput<
   collateralBank: Address<TAccountCollateralBank>;
   /** Collateral Bank token account */
   collateralBankTokenAccount: Address<TAccountCollateralBankTokenAccount>;
+  /** Insurance fund of the collateral bank, receives its share of the liquidation bonus */
+  collateralInsuranceTokenAccount: Address<TAccountCollateralInsuranceTokenAccount>;
   /** Borrowed bank account */
   borrowedBank: Address<TAccountBorrowedBank>;
   /** Borrowed Bank token account */
   borrowedBankTokenAccount: Address<TAccountBorrowedBankTokenAccount>;
-  /** User account for the liquidator */
-  liquidatorUserAccount: Address<TAccountLiquidatorUserAccount>;
-  /** Liquidator's borrowed token account */
+  /** User account of the borrower being liquidated */
+  borrowerUserAccount: Address<TAccountBorrowerUserAccount>;
+  /** Liquidator's borrowed token account, the debt of the borrower is repaid from it */
   liquidatorBorrowedTokenAccount: Address<TAccountLiquidatorBorrowedTokenAccount>;
   /** Liquidator's collateral token account */
   liquidatorCollateralTokenAccount: Address<TAccountLiquidatorCollateralTokenAccount>;
@@ -504,19 +611,25 @@ export type LiquidateInput<
   tokenProgram?: Address<TAccountTokenProgram>;
   /** Associated token program */
   associatedTokenProgram?: Address<TAccountAssociatedTokenProgram>;
-  tokenType: LiquidateInstructionDataArgs['tokenType'];
+  repayAmount: LiquidateInstructionDataArgs['repayAmount'];
+  minCollateralOut: LiquidateInstructionDataArgs['minCollateralOut'];
 };
 
 export function getLiquidateInstruction<
   TAccountLiquidator extends string,
-  TAccountPriceUpdateAccount extends string,
+  TAccountCollateralOracle extends string,
+  TAccountBorrowedOracle extends string,
+  TAccountCollateralSecondaryOracle extends string,
+  TAccountBorrowedSecondaryOracle extends string,
+  TAccountMarket extends string,
   TAccountCollateralMint extends string,
   TAccountBorrowedMint extends string,
   TAccountCollateralBank extends string,
   TAccountCollateralBankTokenAccount extends string,
+  TAccountCollateralInsuranceTokenAccount extends string,
   TAccountBorrowedBank extends string,
   TAccountBorrowedBankTokenAccount extends string,
-  TAccountLiquidatorUserAccount extends string,
+  TAccountBorrowerUserAccount extends string,
   TAccountLiquidatorBorrowedTokenAccount extends string,
   TAccountLiquidatorCollateralTokenAccount extends string,
   TAccountSystemProgram extends string,
@@ -526,14 +639,19 @@ export function getLiquidateInstruction<
 >(
   input: LiquidateInput<
     TAccountLiquidator,
-    TAccountPriceUpdateAccount,
+    TAccountCollateralOracle,
+    TAccountBorrowedOracle,
+    TAccountCollateralSecondaryOracle,
+    TAccountBorrowedSecondaryOracle,
+    TAccountMarket,
     TAccountCollateralMint,
     TAccountBorrowedMint,
     TAccountCollateralBank,
     TAccountCollateralBankTokenAccount,
+    TAccountCollateralInsuranceTokenAccount,
     TAccountBorrowedBank,
     TAccountBorrowedBankTokenAccount,
-    TAccountLiquidatorUserAccount,
+    TAccountBorrowerUserAccount,
     TAccountLiquidatorBorrowedTokenAccount,
     TAccountLiquidatorCollateralTokenAccount,
     TAccountSystemProgram,
@@ -544,14 +662,19 @@ export function getLiquidateInstruction<
 ): LiquidateInstruction<
   TProgramAddress,
   TAccountLiquidator,
-  TAccountPriceUpdateAccount,
+  TAccountCollateralOracle,
+  TAccountBorrowedOracle,
+  TAccountCollateralSecondaryOracle,
+  TAccountBorrowedSecondaryOracle,
+  TAccountMarket,
   TAccountCollateralMint,
   TAccountBorrowedMint,
   TAccountCollateralBank,
   TAccountCollateralBankTokenAccount,
+  TAccountCollateralInsuranceTokenAccount,
   TAccountBorrowedBank,
   TAccountBorrowedBankTokenAccount,
-  TAccountLiquidatorUserAccount,
+  TAccountBorrowerUserAccount,
   TAccountLiquidatorBorrowedTokenAccount,
   TAccountLiquidatorCollateralTokenAccount,
   TAccountSystemProgram,
@@ -565,10 +688,20 @@ export function getLiquidateInstruction<
   // Original accounts.
   const originalAccounts = {
     liquidator: { value: input.liquidator ?? null, isWritable: true },
-    priceUpdateAccount: {
-      value: input.priceUpdateAccount ?? null,
-      isWritable: true,
+    collateralOracle: {
+      value: input.collateralOracle ?? null,
+      isWritable: false,
+    },
+    borrowedOracle: { value: input.borrowedOracle ?? null, isWritable: false },
+    collateralSecondaryOracle: {
+      value: input.collateralSecondaryOracle ?? null,
+      isWritable: false,
     },
+    borrowedSecondaryOracle: {
+      value: input.borrowedSecondaryOracle ?? null,
+      isWritable: false,
+    },
+    market: { value: input.market ?? null, isWritable: false },
     collateralMint: { value: input.collateralMint ?? null, isWritable: false },
     borrowedMint: { value: input.borrowedMint ?? null, isWritable: false },
     collateralBank: { value: input.collateralBank ?? null, isWritable: true },
@@ -576,13 +709,17 @@ export function getLiquidateInstruction<
       value: input.collateralBankTokenAccount ?? null,
       isWritable: true,
     },
+    collateralInsuranceTokenAccount: {
+      value: input.collateralInsuranceTokenAccount ?? null,
+      isWritable: true,
+    },
     borrowedBank: { value: input.borrowedBank ?? null, isWritable: true },
     borrowedBankTokenAccount: {
       value: input.borrowedBankTokenAccount ?? null,
       isWritable: true,
     },
-    liquidatorUserAccount: {
-      value: input.liquidatorUserAccount ?? null,
+    borrowerUserAccount: {
+      value: input.borrowerUserAccount ?? null,
       isWritable: true,
     },
     liquidatorBorrowedTokenAccount: {
@@ -609,14 +746,14 @@ export function getLiquidateInstruction<
   const args = { ...input };
 
   // Resolve default values.
-  if (!accounts.systemProgram.value) {
-    accounts.systemProgram.value =
-      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
-  }
   if (!accounts.tokenProgram.value) {
     accounts.tokenProgram.value =
       'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
   }
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
   if (!accounts.associatedTokenProgram.value) {
     accounts.associatedTokenProgram.value =
       'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL' as Address<'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'>;
@@ -626,14 +763,19 @@ export function getLiquidateInstruction<
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.liquidator),
-      getAccountMeta(accounts.priceUpdateAccount),
+      getAccountMeta(accounts.collateralOracle),
+      getAccountMeta(accounts.borrowedOracle),
+      getAccountMeta(accounts.collateralSecondaryOracle),
+      getAccountMeta(accounts.borrowedSecondaryOracle),
+      getAccountMeta(accounts.market),
       getAccountMeta(accounts.collateralMint),
       getAccountMeta(accounts.borrowedMint),
       getAccountMeta(accounts.collateralBank),
       getAccountMeta(accounts.collateralBankTokenAccount),
+      getAccountMeta(accounts.collateralInsuranceTokenAccount),
       getAccountMeta(accounts.borrowedBank),
       getAccountMeta(accounts.borrowedBankTokenAccount),
-      getAccountMeta(accounts.liquidatorUserAccount),
+      getAccountMeta(accounts.borrowerUserAccount),
       getAccountMeta(accounts.liquidatorBorrowedTokenAccount),
       getAccountMeta(accounts.liquidatorCollateralTokenAccount),
       getAccountMeta(accounts.systemProgram),
@@ -647,14 +789,19 @@ export function getLiquidateInstruction<
   } as LiquidateInstruction<
     TProgramAddress,
     TAccountLiquidator,
-    TAccountPriceUpdateAccount,
+    TAccountCollateralOracle,
+    TAccountBorrowedOracle,
+    TAccountCollateralSecondaryOracle,
+    TAccountBorrowedSecondaryOracle,
+    TAccountMarket,
     TAccountCollateralMint,
     TAccountBorrowedMint,
     TAccountCollateralBank,
     TAccountCollateralBankTokenAccount,
+    TAccountCollateralInsuranceTokenAccount,
     TAccountBorrowedBank,
     TAccountBorrowedBankTokenAccount,
-    TAccountLiquidatorUserAccount,
+    TAccountBorrowerUserAccount,
     TAccountLiquidatorBorrowedTokenAccount,
     TAccountLiquidatorCollateralTokenAccount,
     TAccountSystemProgram,
@@ -670,33 +817,45 @@ export type ParsedLiquidateInstruction<
   programAddress: Address<TProgram>;
   accounts: {
     liquidator: TAccountMetas[0];
-    priceUpdateAccount: TAccountMetas[1];
+    /** Price of the collateral asset */
+    collateralOracle: TAccountMetas[1];
+    /** Price of the borrowed asset */
+    borrowedOracle: TAccountMetas[2];
+    /** Secondary oracle of the collateral bank, only needed if it has one */
+    collateralSecondaryOracle?: TAccountMetas[3] | undefined;
+    /** Secondary oracle of the borrowed bank, only needed if it has one */
+    borrowedSecondaryOracle?: TAccountMetas[4] | undefined;
+    /** The lending market both banks and the borrower belong to */
+    market: TAccountMetas[5];
     /** Mints for the collateral and borrowed asset */
-    collateralMint: TAccountMetas[2];
-    borrowedMint: TAccountMetas[3];
+    collateralMint: TAccountMetas[6];
+    /** Must be a different asset than the collateral, otherwise the same bank would be loaded and written twice */
+    borrowedMint: TAccountMetas[7];
     /**
      * Defining borrowed and collateral bank account for there respective token accounts
      * Collateral bank account
      */
-    collateralBank: TAccountMetas[4];
+    collateralBank: TAccountMetas[8];
     /** Collateral Bank token account */
-    collateralBankTokenAccount: TAccountMetas[5];
+    collateralBankTokenAccount: TAccountMetas[9];
+    /** Insurance fund of the collateral bank, receives its share of the liquidation bonus */
+    collateralInsuranceTokenAccount: TAccountMetas[10];
     /** Borrowed bank account */
-    borrowedBank: TAccountMetas[6];
+    borrowedBank: TAccountMetas[11];
     /** Borrowed Bank token account */
-    borrowedBankTokenAccount: TAccountMetas[7];
-    /** User account for the liquidator */
-    liquidatorUserAccount: TAccountMetas[8];
-    /** Liquidator's borrowed token account */
-    liquidatorBorrowedTokenAccount: TAccountMetas[9];
+    borrowedBankTokenAccount: TAccountMetas[12];
+    /** User account of the borrower being liquidated */
+    borrowerUserAccount: TAccountMetas[13];
+    /** Liquidator's borrowed token account, the debt of the borrower is repaid from it */
+    liquidatorBorrowedTokenAccount: TAccountMetas[14];
     /** Liquidator's collateral token account */
-    liquidatorCollateralTokenAccount: TAccountMetas[10];
+    liquidatorCollateralTokenAccount: TAccountMetas[15];
     /** System program */
-    systemProgram: TAccountMetas[11];
+    systemProgram: TAccountMetas[16];
     /** Token program */
-    tokenProgram: TAccountMetas[12];
+    tokenProgram: TAccountMetas[17];
     /** Associated token program */
-    associatedTokenProgram: TAccountMetas[13];
+    associatedTokenProgram: TAccountMetas[18];
   };
   data: LiquidateInstructionData;
 };
@@ -709,7 +868,7 @@ export function parseLiquidateInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedLiquidateInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 14) {
+  if (instruction.accounts.length < 19) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -719,18 +878,29 @@ export function parseLiquidateInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === LENDING_PROTOCOL_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
       liquidator: getNextAccount(),
-      priceUpdateAccount: getNextAccount(),
+      collateralOracle: getNextAccount(),
+      borrowedOracle: getNextAccount(),
+      collateralSecondaryOracle: getNextOptionalAccount(),
+      borrowedSecondaryOracle: getNextOptionalAccount(),
+      market: getNextAccount(),
       collateralMint: getNextAccount(),
       borrowedMint: getNextAccount(),
       collateralBank: getNextAccount(),
       collateralBankTokenAccount: getNextAccount(),
+      collateralInsuranceTokenAccount: getNextAccount(),
       borrowedBank: getNextAccount(),
       borrowedBankTokenAccount: getNextAccount(),
-      liquidatorUserAccount: getNextAccount(),
+      borrowerUserAccount: getNextAccount(),
       liquidatorBorrowedTokenAccount: getNextAccount(),
       liquidatorCollateralTokenAccount: getNextAccount(),
       systemProgram: getNextAccount(),
@@ -40,12 +40,6 @@ import {
   getAccountMetaFactory,
   type ResolvedAccount,
 } from '../shared';
-import {
-  getTokenTypeDecoder,
-  getTokenTypeEncoder,
-  type TokenType,
-  type TokenTypeArgs,
-} from '../types';
 
 export const REPAY_DISCRIMINATOR = new Uint8Array([
   234, 103, 67, 82, 208, 234, 219, 166,
@@ -58,6 +52,7 @@ export function getRepayDiscriminatorBytes() {
 export type RepayInstruction<
   TProgram extends string = typeof LENDING_PROTOCOL_PROGRAM_ADDRESS,
   TAccountSigner extends string | AccountMeta<string> = string,
+  TAccountMarket extends string | AccountMeta<string> = string,
   TAccountMint extends string | AccountMeta<string> = string,
   TAccountBank extends string | AccountMeta<string> = string,
   TAccountBankTokenAccount extends string | AccountMeta<string> = string,
@@ -81,6 +76,9 @@ export type RepayInstruction<
         ? WritableSignerAccount<TAccountSigner> &
             AccountSignerMeta<TAccountSigner>
         : TAccountSigner,
+      TAccountMarket extends string
+        ? ReadonlyAccount<TAccountMarket>
+        : TAccountMarket,
       TAccountMint extends string
         ? ReadonlyAccount<TAccountMint>
         : TAccountMint,
@@ -112,12 +110,10 @@ export type RepayInstruction<
 export type RepayInstructionData = {
   discriminator: ReadonlyUint8Array;
   amountToRepay: bigint;
-  tokenType: TokenType;
 };
 
 export type RepayInstructionDataArgs = {
   amountToRepay: number | bigint;
-  tokenType: TokenTypeArgs;
 };
 
 export function getRepayInstructionDataEncoder(): FixedSizeEncoder<RepayInstructionDataArgs> {
@@ -125,7 +121,6 @@ export function getRepayInstructionDataEncoder(): FixedSizeEncoder<RepayInstruct
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
       ['amountToRepay', getU64Encoder()],
-      ['tokenType', getTokenTypeEncoder()],
     ]),
     (value) => ({ ...value, discriminator: REPAY_DISCRIMINATOR })
   );
@@ -135,7 +130,6 @@ export function getRepayInstructionDataDecoder(): FixedSizeDecoder<RepayInstruct
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
     ['amountToRepay', getU64Decoder()],
-    ['tokenType', getTokenTypeDecoder()],
   ]);
 }
 
@@ -151,6 +145,7 @@ export function getRepayInstructionDataCodec(): FixedSizeCodec<
 
 export type RepayAsyncInput<
   TAccountSigner extends string = string,
+  TAccountMarket extends string = string,
   TAccountMint extends string = string,
   TAccountBank extends string = string,
   TAccountBankTokenAccount extends string = string,
@@ -161,6 +156,8 @@ export type RepayAsyncInput<
   TAccountSystemProgram extends string = string,
 > = {
   signer: TransactionSigner<TAccountSigner>;
+  /** The lending market the bank belongs to */
+  market: Address<TAccountMarket>;
   mint: Address<TAccountMint>;
   /** The bank account to deposit the asset to */
   bank?: Address<TAccountBank>;
@@ -181,11 +178,11 @@ export type RepayAsyncInput<
   /** System program to create the account */
   systemProgram?: Address<TAccountSystemProgram>;
   amountToRepay: RepayInstructionDataArgs['amountToRepay'];
-  tokenType: RepayInstructionDataArgs['tokenType'];
 };
 
 export async function getRepayInstructionAsync<
   TAccountSigner extends string,
+  TAccountMarket extends string,
   TAccountMint extends string,
   TAccountBank extends string,
   TAccountBankTokenAccount extends string,
@@ -198,6 +195,7 @@ export async function getRepayInstructionAsync<
 >(
   input: RepayAsyncInput<
     TAccountSigner,
+    TAccountMarket,
     TAccountMint,
     TAccountBank,
     TAccountBankTokenAccount,
@@ -212,6 +210,7 @@ export async function getRepayInstructionAsync<
   RepayInstruction<
     TProgramAddress,
     TAccountSigner,
+    TAccountMarket,
     TAccountMint,
     TAccountBank,
     TAccountBankTokenAccount,
@@ -229,6 +228,7 @@ export async function getRepayInstructionAsync<
   // Original accounts.
   const originalAccounts = {
     signer: { value: input.signer ?? null, isWritable: true },
+    market: { value: input.market ?? null, isWritable: false },
     mint: { value: input.mint ?? null, isWritable: false },
     bank: { value: input.bank ?? null, isWritable: true },
     bankTokenAccount: {
@@ -259,7 +259,10 @@ export async function getRepayInstructionAsync<
   if (!accounts.bank.value) {
     accounts.bank.value = await getProgramDerivedAddress({
       programAddress,
-      seeds: [getAddressEncoder().encode(expectAddress(accounts.mint.value))],
+      seeds: [
+        getAddressEncoder().encode(expectAddress(accounts.market.value)),
+        getAddressEncoder().encode(expectAddress(accounts.mint.value)),
+      ],
     });
   }
   if (!accounts.bankTokenAccount.value) {
@@ -269,6 +272,7 @@ export async function getRepayInstructionAsync<
         getBytesEncoder().encode(
           new Uint8Array([84, 114, 101, 97, 115, 117, 114, 121])
         ),
+        getAddressEncoder().encode(expectAddress(accounts.market.value)),
         getAddressEncoder().encode(expectAddress(accounts.mint.value)),
       ],
     });
@@ -276,7 +280,10 @@ export async function getRepayInstructionAsync<
   if (!accounts.userAccount.value) {
     accounts.userAccount.value = await getProgramDerivedAddress({
       programAddress,
-      seeds: [getAddressEncoder().encode(expectAddress(accounts.signer.value))],
+      seeds: [
+        getAddressEncoder().encode(expectAddress(accounts.market.value)),
+        getAddressEncoder().encode(expectAddress(accounts.signer.value)),
+      ],
     });
   }
   if (!accounts.tokenProgram.value) {
@@ -307,6 +314,7 @@ export async function getRepayInstructionAsync<
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.signer),
+      getAccountMeta(accounts.market),
       getAccountMeta(accounts.mint),
       getAccountMeta(accounts.bank),
       getAccountMeta(accounts.bankTokenAccount),
@@ -323,6 +331,7 @@ export async function getRepayInstructionAsync<
   } as RepayInstruction<
     TProgramAddress,
     TAccountSigner,
+    TAccountMarket,
     TAccountMint,
     TAccountBank,
     TAccountBankTokenAccount,
@@ -336,6 +345,7 @@ export async function getRepayInstructionAsync<
 
 export type RepayInput<
   TAccountSigner extends string = string,
+  TAccountMarket extends string = string,
   TAccountMint extends string = string,
   TAccountBank extends string = string,
   TAccountBankTokenAccount extends string = string,
@@ -346,6 +356,8 @@ export type RepayInput<
   TAccountSystemProgram extends string = string,
 > = {
   signer: TransactionSigner<TAccountSigner>;
+  /** The lending market the bank belongs to */
+  market: Address<TAccountMarket>;
   mint: Address<TAccountMint>;
   /** The bank account to deposit the asset to */
   bank: Address<TAccountBank>;
@@ -366,11 +378,11 @@ export type RepayInput<
   /** System program to create the account */
   systemProgram?: Address<TAccountSystemProgram>;
   amountToRepay: RepayInstructionDataArgs['amountToRepay'];
-  tokenType: RepayInstructionDataArgs['tokenType'];
 };
 
 export function getRepayInstruction<
   TAccountSigner extends string,
+  TAccountMarket extends string,
   TAccountMint extends string,
   TAccountBank extends string,
   TAccountBankTokenAccount extends string,
@@ -383,6 +395,7 @@ export function getRepayInstruction<
 >(
   input: RepayInput<
     TAccountSigner,
+    TAccountMarket,
     TAccountMint,
     TAccountBank,
     TAccountBankTokenAccount,
@@ -396,6 +409,7 @@ export function getRepayInstruction<
 ): RepayInstruction<
   TProgramAddress,
   TAccountSigner,
+  TAccountMarket,
   TAccountMint,
   TAccountBank,
   TAccountBankTokenAccount,
@@ -412,6 +426,7 @@ export function getRepayInstruction<
   // Original accounts.
   const originalAccounts = {
     signer: { value: input.signer ?? null, isWritable: true },
+    market: { value: input.market ?? null, isWritable: false },
     mint: { value: input.mint ?? null, isWritable: false },
     bank: { value: input.bank ?? null, isWritable: true },
     bankTokenAccount: {
@@ -456,6 +471,7 @@ export function getRepayInstruction<
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.signer),
+      getAccountMeta(accounts.market),
       getAccountMeta(accounts.mint),
       getAccountMeta(accounts.bank),
       getAccountMeta(accounts.bankTokenAccount),
@@ -472,6 +488,7 @@ export function getRepayInstruction<
   } as RepayInstruction<
     TProgramAddress,
     TAccountSigner,
+    TAccountMarket,
     TAccountMint,
     TAccountBank,
     TAccountBankTokenAccount,
@@ -490,25 +507,27 @@ export type ParsedRepayInstruction<
   programAddress: Address<TProgram>;
   accounts: {
     signer: TAccountMetas[0];
-    mint: TAccountMetas[1];
+    /** The lending market the bank belongs to */
+    market: TAccountMetas[1];
+    mint: TAccountMetas[2];
     /** The bank account to deposit the asset to */
-    bank: TAccountMetas[2];
+    bank: TAccountMetas[3];
     /** The bank token account to deposit the tokens */
-    bankTokenAccount: TAccountMetas[3];
+    bankTokenAccount: TAccountMetas[4];
     /** The user account that stores the state of the user */
-    userAccount: TAccountMetas[4];
+    userAccount: TAccountMetas[5];
     /**
      * Account that will take the tokens we are depositing and transfer them to the bank token account
      *
      * This will be an Associated Token Account for the mint address of the asset we are depositing into the bank.
      */
-    userTokenAccount: TAccountMetas[5];
+    userTokenAccount: TAccountMetas[6];
     /** Associated token program to create the associated token account */
-    associatedTokenProgram: TAccountMetas[6];
+    associatedTokenProgram: TAccountMetas[7];
     /** Token program to create the token account. */
-    tokenProgram: TAccountMetas[7];
+    tokenProgram: TAccountMetas[8];
     /** System program to create the account */
-    systemProgram: TAccountMetas[8];
+    systemProgram: TAccountMetas[9];
   };
   data: RepayInstructionData;
 };
@@ -521,7 +540,7 @@ export function parseRepayInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedRepayInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 9) {
+  if (instruction.accounts.length < 10) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -535,6 +554,7 @@ export function parseRepayInstruction<
     programAddress: instruction.programAddress,
     accounts: {
       signer: getNextAccount(),
+      market: getNextAccount(),
       mint: getNextAccount(),
       bank: getNextAccount(),
       bankTokenAccount: getNextAccount(),
@@ -40,12 +40,6 @@ import {
   getAccountMetaFactory,
   type ResolvedAccount,
 } from '../shared';
-import {
-  getTokenTypeDecoder,
-  getTokenTypeEncoder,
-  type TokenType,
-  type TokenTypeArgs,
-} from '../types';
 
 export const WITHDRAW_DISCRIMINATOR = new Uint8Array([
   183, 18, 70, 156, 148, 109, 161, 34,
@@ -58,6 +52,7 @@ export function getWithdrawDiscriminatorBytes() {
 export type WithdrawInstruction<
   TProgram extends string = typeof LENDING_PROTOCOL_PROGRAM_ADDRESS,
   TAccountSigner extends string | AccountMeta<string> = string,
+  TAccountMarket extends string | AccountMeta<string> = string,
   TAccountMint extends string | AccountMeta<string> = string,
   TAccountBank extends string | AccountMeta<string> = string,
   TAccountBankTokenAccount extends string | AccountMeta<string> = string,
@@ -81,6 +76,9 @@ export type WithdrawInstruction<
         ? WritableSignerAccount<TAccountSigner> &
             AccountSignerMeta<TAccountSigner>
         : TAccountSigner,
+      TAccountMarket extends string
+        ? ReadonlyAccount<TAccountMarket>
+        : TAccountMarket,
       TAccountMint extends string
         ? ReadonlyAccount<TAccountMint>
         : TAccountMint,
@@ -112,12 +110,10 @@ export type WithdrawInstruction<
 export type WithdrawInstructionData = {
   discriminator: ReadonlyUint8Array;
   amountToWithdraw: bigint;
-  tokenType: TokenType;
 };
 
 export type WithdrawInstructionDataArgs = {
   amountToWithdraw: number | bigint;
-  tokenType: TokenTypeArgs;
 };
 
 export function getWithdrawInstructionDataEncoder(): FixedSizeEncoder<WithdrawInstructionDataArgs> {
@@ -125,7 +121,6 @@ export function getWithdrawInstructionDataEncoder(): FixedSizeEncoder<WithdrawIn
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
       ['amountToWithdraw', getU64Encoder()],
-      ['tokenType', getTokenTypeEncoder()],
     ]),
     (value) => ({ ...value, discriminator: WITHDRAW_DISCRIMINATOR })
   );
@@ -135,7 +130,6 @@ export function getWithdrawInstructionDataDecoder(): FixedSizeDecoder<WithdrawIn
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
     ['amountToWithdraw', getU64Decoder()],
-    ['tokenType', getTokenTypeDecoder()],
   ]);
 }
 
@@ -151,6 +145,7 @@ export function getWithdrawInstructionDataCodec(): FixedSizeCodec<
 
 export type WithdrawAsyncInput<
   TAccountSigner extends string = string,
+  TAccountMarket extends string = string,
   TAccountMint extends string = string,
   TAccountBank extends string = string,
   TAccountBankTokenAccount extends string = string,
@@ -162,6 +157,8 @@ export type WithdrawAsyncInput<
 > = {
   /** The signer of the transaction */
   signer: TransactionSigner<TAccountSigner>;
+  /** The lending market the bank belongs to */
+  market: Address<TAccountMarket>;
   /** The mint address of the asset to be withdrawn */
   mint: Address<TAccountMint>;
   /** The bank account to withdraw the asset from */
@@ -183,11 +180,11 @@ export type WithdrawAsyncInput<
   /** System program to create the account */
   systemProgram?: Address<TAccountSystemProgram>;
   amountToWithdraw: WithdrawInstructionDataArgs['amountToWithdraw'];
-  tokenType: WithdrawInstructionDataArgs['tokenType'];
 };
 
 export async function getWithdrawInstructionAsync<
   TAccountSigner extends string,
+  TAccountMarket extends string,
   TAccountMint extends string,
   TAccountBank extends string,
   TAccountBankTokenAccount extends string,
@@ -200,6 +197,7 @@ export async function getWithdrawInstructionAsync<
 >(
   input: WithdrawAsyncInput<
     TAccountSigner,
+    TAccountMarket,
     TAccountMint,
     TAccountBank,
     TAccountBankTokenAccount,
@@ -214,6 +212,7 @@ export async function getWithdrawInstructionAsync<
   WithdrawInstruction<
     TProgramAddress,
     TAccountSigner,
+    TAccountMarket,
     TAccountMint,
     TAccountBank,
     TAccountBankTokenAccount,
@@ -231,6 +230,7 @@ export async function getWithdrawInstructionAsync<
   // Original accounts.
   const originalAccounts = {
     signer: { value: input.signer ?? null, isWritable: true },
+    market: { value: input.market ?? null, isWritable: false },
     mint: { value: input.mint ?? null, isWritable: false },
     bank: { value: input.bank ?? null, isWritable: true },
     bankTokenAccount: {
@@ -261,7 +261,10 @@ export async function getWithdrawInstructionAsync<
   if (!accounts.bank.value) {
     accounts.bank.value = await getProgramDerivedAddress({
       programAddress,
-      seeds: [getAddressEncoder().encode(expectAddress(accounts.mint.value))],
+      seeds: [
+        getAddressEncoder().encode(expectAddress(accounts.market.value)),
+        getAddressEncoder().encode(expectAddress(accounts.mint.value)),
+      ],
     });
   }
   if (!accounts.bankTokenAccount.value) {
@@ -271,6 +274,7 @@ export async function getWithdrawInstructionAsync<
         getBytesEncoder().encode(
           new Uint8Array([84, 114, 101, 97, 115, 117, 114, 121])
         ),
+        getAddressEncoder().encode(expectAddress(accounts.market.value)),
         getAddressEncoder().encode(expectAddress(accounts.mint.value)),
       ],
     });
@@ -278,7 +282,10 @@ export async function getWithdrawInstructionAsync<
   if (!accounts.userAccount.value) {
     accounts.userAccount.value = await getProgramDerivedAddress({
       programAddress,
-      seeds: [getAddressEncoder().encode(expectAddress(accounts.signer.value))],
+      seeds: [
+        getAddressEncoder().encode(expectAddress(accounts.market.value)),
+        getAddressEncoder().encode(expectAddress(accounts.signer.value)),
+      ],
     });
   }
   if (!accounts.tokenProgram.value) {
@@ -309,6 +316,7 @@ export async function getWithdrawInstructionAsync<
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.signer),
+      getAccountMeta(accounts.market),
       getAccountMeta(accounts.mint),
       getAccountMeta(accounts.bank),
       getAccountMeta(accounts.bankTokenAccount),
@@ -325,6 +333,7 @@ export async function getWithdrawInstructionAsync<
   } as WithdrawInstruction<
     TProgramAddress,
     TAccountSigner,
+    TAccountMarket,
     TAccountMint,
     TAccountBank,
     TAccountBankTokenAccount,
@@ -338,6 +347,7 @@ export async function getWithdrawInstructionAsync<
 
 export type WithdrawInput<
   TAccountSigner extends string = string,
+  TAccountMarket extends string = string,
   TAccountMint extends string = string,
   TAccountBank extends string = string,
   TAccountBankTokenAccount extends string = string,
@@ -349,6 +359,8 @@ export type WithdrawInput<
 > = {
   /** The signer of the transaction */
   signer: TransactionSigner<TAccountSigner>;
+  /** The lending market the bank belongs to */
+  market: Address<TAccountMarket>;
   /** The mint address of the asset to be withdrawn */
   mint: Address<TAccountMint>;
   /** The bank account to withdraw the asset from */
@@ -370,11 +382,11 @@ export type WithdrawInput<
   /** System program to create the account */
   systemProgram?: Address<TAccountSystemProgram>;
   amountToWithdraw: WithdrawInstructionDataArgs['amountToWithdraw'];
-  tokenType: WithdrawInstructionDataArgs['tokenType'];
 };
 
 export function getWithdrawInstruction<
   TAccountSigner extends string,
+  TAccountMarket extends string,
   TAccountMint extends string,
   TAccountBank extends string,
   TAccountBankTokenAccount extends string,
@@ -387,6 +399,7 @@ export function getWithdrawInstruction<
 >(
   input: WithdrawInput<
     TAccountSigner,
+    TAccountMarket,
     TAccountMint,
     TAccountBank,
     TAccountBankTokenAccount,
@@ -400,6 +413,7 @@ export function getWithdrawInstruction<
 ): WithdrawInstruction<
   TProgramAddress,
   TAccountSigner,
+  TAccountMarket,
   TAccountMint,
   TAccountBank,
   TAccountBankTokenAccount,
@@ -416,6 +430,7 @@ export function getWithdrawInstruction<
   // Original accounts.
   const originalAccounts = {
     signer: { value: input.signer ?? null, isWritable: true },
+    market: { value: input.market ?? null, isWritable: false },
     mint: { value: input.mint ?? null, isWritable: false },
     bank: { value: input.bank ?? null, isWritable: true },
     bankTokenAccount: {
@@ -460,6 +475,7 @@ export function getWithdrawInstruction<
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.signer),
+      getAccountMeta(accounts.market),
       getAccountMeta(accounts.mint),
       getAccountMeta(accounts.bank),
       getAccountMeta(accounts.bankTokenAccount),
@@ -476,6 +492,7 @@ export function getWithdrawInstruction<
   } as WithdrawInstruction<
     TProgramAddress,
     TAccountSigner,
+    TAccountMarket,
     TAccountMint,
     TAccountBank,
     TAccountBankTokenAccount,
@@ -495,26 +512,28 @@ export type ParsedWithdrawInstruction<
   accounts: {
     /** The signer of the transaction */
     signer: TAccountMetas[0];
+    /** The lending market the bank belongs to */
+    market: TAccountMetas[1];
     /** The mint address of the asset to be withdrawn */
-    mint: TAccountMetas[1];
+    mint: TAccountMetas[2];
     /** The bank account to withdraw the asset from */
-    bank: TAccountMetas[2];
+    bank: TAccountMetas[3];
     /** The bank token account which holds the asset to be withdrawn */
-    bankTokenAccount: TAccountMetas[3];
+    bankTokenAccount: TAccountMetas[4];
     /** The user account requesting the withdrawal */
-    userAccount: TAccountMetas[4];
+    userAccount: TAccountMetas[5];
     /**
      * User token account we will be withdrawing the asset to.
      *
      * Ideally the account would already have been initialized when the user is depositing the asset, but since we can't guarantee that, we will initialize it if needed.
      */
-    userTokenAccount: TAccountMetas[5];
+    userTokenAccount: TAccountMetas[6];
     /** Associated token program to create the associated token account */
-    associatedTokenProgram: TAccountMetas[6];
+    associatedTokenProgram: TAccountMetas[7];
     /** Token program to create the token account. */
-    tokenProgram: TAccountMetas[7];
+    tokenProgram: TAccountMetas[8];
     /** System program to create the account */
-    systemProgram: TAccountMetas[8];
+    systemProgram: TAccountMetas[9];
   };
   data: WithdrawInstructionData;
 };
@@ -527,7 +546,7 @@ export function parseWithdrawInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedWithdrawInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 9) {
+  if (instruction.accounts.length < 10) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -541,6 +560,7 @@ export function parseWithdrawInstruction<
     programAddress: instruction.programAddress,
     accounts: {
       signer: getNextAccount(),
+      market: getNextAccount(),
       mint: getNextAccount(),
       bank: getNextAccount(),
       bankTokenAccount: getNextAccount(),
@@ -14,13 +14,30 @@ import {
   type ReadonlyUint8Array,
 } from 'gill';
 import {
+  type ParsedAcceptBankAuthorityInstruction,
   type ParsedBorrowInstruction,
+  type ParsedCancelConfigChangeInstruction,
+  type ParsedCollectProtocolFeesInstruction,
+  type ParsedCreateMultisigInstruction,
   type ParsedDepositInstruction,
+  type ParsedExecuteConfigChangeInstruction,
+  type ParsedFundInsuranceInstruction,
   type ParsedInitializeAccountInstruction,
   type ParsedInitializeBankInstruction,
+  type ParsedInitializeMarketInstruction,
   type ParsedLiquidateInstruction,
+  type ParsedProposeBankAuthorityInstruction,
+  type ParsedQueueConfigChangeInstruction,
+  type ParsedRefreshBankInstruction,
   type ParsedRepayInstruction,
+  type ParsedSetBankPauseInstruction,
+  type ParsedSetGuardianInstruction,
+  type ParsedSetMarketPauseInstruction,
+  type ParsedSetMultisigSignersInstruction,
+  type ParsedSocializeBadDebtInstruction,
+  type ParsedUpdateBankCapsInstruction,
   type ParsedWithdrawInstruction,
+  type ParsedWithdrawInsuranceInstruction,
 } from '../instructions';
 
 export const LENDING_PROTOCOL_PROGRAM_ADDRESS =
@@ -28,7 +45,9 @@ export const LENDING_PROTOCOL_PROGRAM_ADDRESS =
 
 export enum LendingProtocolAccount {
   Bank,
-  PriceUpdateV2,
+  ConfigQueue,
+  LendingMarket,
+  Multisig,
   User,
 }
 
@@ -51,12 +70,34 @@ export function identifyLendingProtocolAccount(
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([34, 241, 35, 99, 157, 126, 244, 205])
+        new Uint8Array([253, 221, 155, 251, 171, 81, 88, 27])
       ),
       0
     )
   ) {
-    return LendingProtocolAccount.PriceUpdateV2;
+    return LendingProtocolAccount.ConfigQueue;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([246, 114, 50, 98, 72, 157, 28, 120])
+      ),
+      0
+    )
+  ) {
+    return LendingProtocolAccount.LendingMarket;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([224, 116, 121, 186, 68, 161, 79, 236])
+      ),
+      0
+    )
+  ) {
+    return LendingProtocolAccount.Multisig;
   }
   if (
     containsBytes(
@@ -75,19 +116,47 @@ export function identifyLendingProtocolAccount(
 }
 
 export enum LendingProtocolInstruction {
+  AcceptBankAuthority,
   Borrow,
+  CancelConfigChange,
+  CollectProtocolFees,
+  CreateMultisig,
   Deposit,
+  ExecuteConfigChange,
+  FundInsurance,
   InitializeAccount,
   InitializeBank,
+  InitializeMarket,
   Liquidate,
+  ProposeBankAuthority,
+  QueueConfigChange,
+  RefreshBank,
   Repay,
+  SetBankPause,
+  SetGuardian,
+  SetMarketPause,
+  SetMultisigSigners,
+  SocializeBadDebt,
+  UpdateBankCaps,
   Withdraw,
+  WithdrawInsurance,
 }
 
 export function identifyLendingProtocolInstruction(
   instruction: { data: ReadonlyUint8Array } | ReadonlyUint8Array
 ): LendingProtocolInstruction {
   const data = 'data' in instruction ? instruction.data : instruction;
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([70, 106, 33, 88, 119, 222, 0, 60])
+      ),
+      0
+    )
+  ) {
+    return LendingProtocolInstruction.AcceptBankAuthority;
+  }
   if (
     containsBytes(
       data,
@@ -99,6 +168,39 @@ export function identifyLendingProtocolInstruction(
   ) {
     return LendingProtocolInstruction.Borrow;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([222, 114, 136, 167, 183, 86, 61, 158])
+      ),
+      0
+    )
+  ) {
+    return LendingProtocolInstruction.CancelConfigChange;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([22, 67, 23, 98, 150, 178, 70, 220])
+      ),
+      0
+    )
+  ) {
+    return LendingProtocolInstruction.CollectProtocolFees;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([148, 146, 240, 10, 226, 215, 167, 174])
+      ),
+      0
+    )
+  ) {
+    return LendingProtocolInstruction.CreateMultisig;
+  }
   if (
     containsBytes(
       data,
@@ -110,6 +212,28 @@ export function identifyLendingProtocolInstruction(
   ) {
     return LendingProtocolInstruction.Deposit;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([100, 150, 198, 236, 103, 241, 118, 71])
+      ),
+      0
+    )
+  ) {
+    return LendingProtocolInstruction.ExecuteConfigChange;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([58, 216, 37, 162, 201, 50, 20, 66])
+      ),
+      0
+    )
+  ) {
+    return LendingProtocolInstruction.FundInsurance;
+  }
   if (
     containsBytes(
       data,
@@ -132,6 +256,17 @@ export function identifyLendingProtocolInstruction(
   ) {
     return LendingProtocolInstruction.InitializeBank;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([35, 35, 189, 193, 155, 48, 170, 203])
+      ),
+      0
+    )
+  ) {
+    return LendingProtocolInstruction.InitializeMarket;
+  }
   if (
     containsBytes(
       data,
@@ -143,6 +278,39 @@ export function identifyLendingProtocolInstruction(
   ) {
     return LendingProtocolInstruction.Liquidate;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([94, 43, 251, 9, 123, 139, 208, 162])
+      ),
+      0
+    )
+  ) {
+    return LendingProtocolInstruction.ProposeBankAuthority;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([170, 159, 206, 172, 182, 240, 205, 53])
+      ),
+      0
+    )
+  ) {
+    return LendingProtocolInstruction.QueueConfigChange;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([239, 110, 85, 201, 138, 157, 19, 99])
+      ),
+      0
+    )
+  ) {
+    return LendingProtocolInstruction.RefreshBank;
+  }
   if (
     containsBytes(
       data,
@@ -154,6 +322,72 @@ export function identifyLendingProtocolInstruction(
   ) {
     return LendingProtocolInstruction.Repay;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([170, 171, 187, 99, 148, 41, 167, 160])
+      ),
+      0
+    )
+  ) {
+    return LendingProtocolInstruction.SetBankPause;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([147, 243, 50, 121, 154, 164, 50, 30])
+      ),
+      0
+    )
+  ) {
+    return LendingProtocolInstruction.SetGuardian;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([118, 203, 96, 59, 170, 213, 38, 101])
+      ),
+      0
+    )
+  ) {
+    return LendingProtocolInstruction.SetMarketPause;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([53, 59, 97, 183, 115, 206, 235, 141])
+      ),
+      0
+    )
+  ) {
+    return LendingProtocolInstruction.SetMultisigSigners;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([25, 82, 191, 176, 114, 122, 211, 248])
+      ),
+      0
+    )
+  ) {
+    return LendingProtocolInstruction.SocializeBadDebt;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([24, 46, 162, 7, 242, 249, 220, 170])
+      ),
+      0
+    )
+  ) {
+    return LendingProtocolInstruction.UpdateBankCaps;
+  }
   if (
     containsBytes(
       data,
@@ -165,6 +399,17 @@ export function identifyLendingProtocolInstruction(
   ) {
     return LendingProtocolInstruction.Withdraw;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([201, 133, 145, 118, 235, 89, 90, 189])
+      ),
+      0
+    )
+  ) {
+    return LendingProtocolInstruction.WithdrawInsurance;
+  }
   throw new Error(
     'The provided instruction could not be identified as a lendingProtocol instruction.'
   );
@@ -173,24 +418,75 @@ export function identifyLendingProtocolInstruction(
 export type ParsedLendingProtocolInstruction<
   TProgram extends string = '9CoY42r3y5WFDJjQX97e9m9THcVGpvuVSKjBjGkiksMR',
 > =
+  | ({
+      instructionType: LendingProtocolInstruction.AcceptBankAuthority;
+    } & ParsedAcceptBankAuthorityInstruction<TProgram>)
   | ({
       instructionType: LendingProtocolInstruction.Borrow;
     } & ParsedBorrowInstruction<TProgram>)
+  | ({
+      instructionType: LendingProtocolInstruction.CancelConfigChange;
+    } & ParsedCancelConfigChangeInstruction<TProgram>)
+  | ({
+      instructionType: LendingProtocolInstruction.CollectProtocolFees;
+    } & ParsedCollectProtocolFeesInstruction<TProgram>)
+  | ({
+      instructionType: LendingProtocolInstruction.CreateMultisig;
+    } & ParsedCreateMultisigInstruction<TProgram>)
   | ({
       instructionType: LendingProtocolInstruction.Deposit;
     } & ParsedDepositInstruction<TProgram>)
+  | ({
+      instructionType: LendingProtocolInstruction.ExecuteConfigChange;
+    } & ParsedExecuteConfigChangeInstruction<TProgram>)
+  | ({
+      instructionType: LendingProtocolInstruction.FundInsurance;
+    } & ParsedFundInsuranceInstruction<TProgram>)
   | ({
       instructionType: LendingProtocolInstruction.InitializeAccount;
     } & ParsedInitializeAccountInstruction<TProgram>)
   | ({
       instructionType: LendingProtocolInstruction.InitializeBank;
     } & ParsedInitializeBankInstruction<TProgram>)
+  | ({
+      instructionType: LendingProtocolInstruction.InitializeMarket;
+    } & ParsedInitializeMarketInstruction<TProgram>)
   | ({
       instructionType: LendingProtocolInstruction.Liquidate;
     } & ParsedLiquidateInstruction<TProgram>)
+  | ({
+      instructionType: LendingProtocolInstruction.ProposeBankAuthority;
+    } & ParsedProposeBankAuthorityInstruction<TProgram>)
+  | ({
+      instructionType: LendingProtocolInstruction.QueueConfigChange;
+    } & ParsedQueueConfigChangeInstruction<TProgram>)
+  | ({
+      instructionType: LendingProtocolInstruction.RefreshBank;
+    } & ParsedRefreshBankInstruction<TProgram>)
   | ({
       instructionType: LendingProtocolInstruction.Repay;
     } & ParsedRepayInstruction<TProgram>)
+  | ({
+      instructionType: LendingProtocolInstruction.SetBankPause;
+    } & ParsedSetBankPauseInstruction<TProgram>)
+  | ({
+      instructionType: LendingProtocolInstruction.SetGuardian;
+    } & ParsedSetGuardianInstruction<TProgram>)
+  | ({
+      instructionType: LendingProtocolInstruction.SetMarketPause;
+    } & ParsedSetMarketPauseInstruction<TProgram>)
+  | ({
+      instructionType: LendingProtocolInstruction.SetMultisigSigners;
+    } & ParsedSetMultisigSignersInstruction<TProgram>)
+  | ({
+      instructionType: LendingProtocolInstruction.SocializeBadDebt;
+    } & ParsedSocializeBadDebtInstruction<TProgram>)
+  | ({
+      instructionType: LendingProtocolInstruction.UpdateBankCaps;
+    } & ParsedUpdateBankCapsInstruction<TProgram>)
   | ({
       instructionType: LendingProtocolInstruction.Withdraw;
-    } & ParsedWithdrawInstruction<TProgram>);
+    } & ParsedWithdrawInstruction<TProgram>)
+  | ({
+      instructionType: LendingProtocolInstruction.WithdrawInsurance;
+    } & ParsedWithdrawInsuranceInstruction<TProgram>);
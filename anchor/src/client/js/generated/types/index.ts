@@ -6,6 +6,9 @@
  * @see https://github.com/codama-idl/codama
  */
 
-export * from './priceFeedMessage';
-export * from './tokenType';
-export * from './verificationLevel';
+export * from './badDebtSocialized';
+export * from './bankConfigUpdate';
+export * from './oracleConfig';
+export * from './oracleType';
+export * from './pendingConfigChange';
+export * from './position';
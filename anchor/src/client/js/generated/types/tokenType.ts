@@ -1,35 +0,0 @@
-/**
- * This code was AUTOGENERATED using the Codama library.
- * Please DO NOT EDIT THIS FILE, instead use visitors
- * to add features, then rerun Codama to update it.
- *
- * @see https://github.com/codama-idl/codama
- */
-
-import {
-  combineCodec,
-  getEnumDecoder,
-  getEnumEncoder,
-  type FixedSizeCodec,
-  type FixedSizeDecoder,
-  type FixedSizeEncoder,
-} from 'gill';
-
-export enum TokenType {
-  SOL,
-  USDC,
-}
-
-export type TokenTypeArgs = TokenType;
-
-export function getTokenTypeEncoder(): FixedSizeEncoder<TokenTypeArgs> {
-  return getEnumEncoder(TokenType);
-}
-
-export function getTokenTypeDecoder(): FixedSizeDecoder<TokenType> {
-  return getEnumDecoder(TokenType);
-}
-
-export function getTokenTypeCodec(): FixedSizeCodec<TokenTypeArgs, TokenType> {
-  return combineCodec(getTokenTypeEncoder(), getTokenTypeDecoder());
-}
@@ -1,5 +1,13 @@
 import { NextResponse, NextRequest } from 'next/server'
-import { Connection, Keypair, PublicKey, LAMPORTS_PER_SOL, Transaction, sendAndConfirmTransaction } from '@solana/web3.js'
+import {
+  Connection,
+  Keypair,
+  PublicKey,
+  LAMPORTS_PER_SOL,
+  Transaction,
+  TransactionInstruction,
+  sendAndConfirmTransaction,
+} from '@solana/web3.js'
 import {
   createMint,
   getOrCreateAssociatedTokenAccount,
@@ -7,15 +15,31 @@ import {
   mintTo,
 } from '@solana/spl-token'
 import {
+  getInitializeMarketInstruction,
   getInitializeBankInstructionAsync,
   getInitializeAccountInstructionAsync,
   getDepositInstructionAsync
 } from '../../../../anchor/src/client/js/generated/instructions'
-import { address } from 'gill'
+import { address, getBase16Encoder, isSignerRole, isWritableRole, type Instruction } from 'gill'
 import { createKeyPairSignerFromBytes, KeyPairSigner } from '@solana/signers'
 import fs from 'fs'
 import path from 'path'
-import { TokenType } from '../../../../anchor/src/client/js/generated/types'
+import { OracleType, type OracleConfigArgs } from '../../../../anchor/src/client/js/generated/types'
+
+const PROGRAM_ID = new PublicKey("9CoY42r3y5WFDJjQX97e9m9THcVGpvuVSKjBjGkiksMR")
+
+// Pyth sponsored price feeds, their PriceUpdateV2 accounts have to be cloned from mainnet on localnet
+const SOL_USD_FEED_ID = 'ef0d8b6fda2ceba41da15d4095d1da392a0d2f8ed0c6c7bc0f4cfac8c280b56d'
+const USDC_USD_FEED_ID = 'eaa020c61cc479712813461ce153894a96a6c00b21ed0cfc2798d1f9a9e9c94a'
+
+function pythOracle(feedId: string): OracleConfigArgs {
+  return {
+    oracleType: OracleType.PythPull,
+    feedId: getBase16Encoder().encode(feedId),
+    maxAge: 60, // 1 minute
+    maxConfidence: 200, // 2% of the price
+  }
+}
 
 export async function POST(request: NextRequest) {
   try {
@@ -85,14 +109,26 @@ export async function POST(request: NextRequest) {
     )
     console.log('✅ USDC Mint created:', USDC_MINT.toBase58())
 
+    // Use the market from the environment, or create one administered by the deployer
+    let MARKET: PublicKey
+    if (process.env.MARKET) {
+      MARKET = new PublicKey(process.env.MARKET)
+      console.log('✅ Using existing market:', MARKET.toBase58())
+    } else {
+      console.log('🏛️ Creating lending market...')
+      MARKET = await initializeMarket(connection, deployer, deployerSigner)
+      console.log('✅ Market created:', MARKET.toBase58())
+    }
+
     // Initialize SOL Bank
     console.log('🏦 Initializing SOL Bank...')
     await initializeAndFundBank(
       connection,
       deployer,
       deployerSigner,
-      USDC_MINT,
+      MARKET,
       SOL_MINT,
+      pythOracle(SOL_USD_FEED_ID),
       50 * LAMPORTS_PER_SOL, // 50 SOL
       'SOL'
     )
@@ -103,14 +139,16 @@ export async function POST(request: NextRequest) {
       connection,
       deployer,
       deployerSigner,
+      MARKET,
       USDC_MINT,
-      SOL_MINT,
+      pythOracle(USDC_USD_FEED_ID),
       50 * 1_000_000, // 50 USDC (6 decimals)
       'USDC'
     )
 
     // Save configuration
     const config = {
+      MARKET: MARKET.toBase58(),
       SOL_MINT: SOL_MINT.toBase58(),
       USDC_MINT: USDC_MINT.toBase58(),
       SOL_MINT_AUTHORITY: deployer.publicKey.toBase58(),
@@ -153,42 +191,68 @@ export async function POST(request: NextRequest) {
   }
 }
 
+// Convert a Gill instruction (from Codama) to a web3 instruction and send it
+async function sendInstruction(connection: Connection, ix: Instruction, signers: Keypair[]) {
+  const web3Ix = new TransactionInstruction({
+    keys: (ix.accounts ?? []).map((acc) => ({
+      pubkey: new PublicKey(acc.address),
+      isSigner: isSignerRole(acc.role),
+      isWritable: isWritableRole(acc.role),
+    })),
+    programId: new PublicKey(ix.programAddress),
+    data: Buffer.from(ix.data ?? []),
+  })
+
+  const tx = new Transaction().add(web3Ix)
+  const { blockhash } = await connection.getLatestBlockhash()
+  tx.recentBlockhash = blockhash
+  tx.feePayer = signers[0].publicKey
+
+  return sendAndConfirmTransaction(connection, tx, signers)
+}
+
+async function initializeMarket(connection: Connection, deployer: Keypair, deployerSigner: KeyPairSigner) {
+  // The market is a regular account, so its new keypair signs its creation
+  const market = Keypair.generate()
+  const marketSigner = await createKeyPairSignerFromBytes(market.secretKey)
+
+  const name = new Uint8Array(32)
+  name.set(new TextEncoder().encode('Main'))
+
+  const marketIx = getInitializeMarketInstruction({
+    payer: deployerSigner,
+    admin: deployerSigner.address,
+    market: marketSigner,
+    name,
+  })
+
+  await sendInstruction(connection, marketIx, [deployer, market])
+
+  return market.publicKey
+}
+
 async function initializeAndFundBank(
   connection: Connection,
   deployer: Keypair,
   deployerSigner: KeyPairSigner,
-  usdcMint: PublicKey,
-  solMint: PublicKey,
+  market: PublicKey,
+  mint: PublicKey,
+  oracle: OracleConfigArgs,
   amount: number,
   tokenName: string
 ) {
-  const mint = tokenName === 'SOL' ? solMint : usdcMint;
-
-  // 1. Initialize the bank
+  // 1. Initialize the bank, the deployer signs as the admin of the market
   const bankIx = await getInitializeBankInstructionAsync({
-    signer: deployerSigner,
+    payer: deployerSigner,
+    admin: deployerSigner.address,
+    market: address(market.toString()),
     mint: address(mint.toString()),
     liquidationThreshold: 80, // 80%
     maxLtv: 70, // 70%
+    oracle,
   })
 
-  const web3BankIx = {
-    keys: bankIx.accounts.map((acc: { address: string }, index: number) => ({
-      pubkey: new PublicKey(acc.address),
-      isSigner: index === 0,
-      isWritable: index === 0 || index === 2 || index === 3
-    })),
-    programId: new PublicKey(bankIx.programAddress),
-    data: Buffer.from(bankIx.data)
-  }
-
-  const bankTx = new Transaction().add(web3BankIx)
-  const { blockhash } = await connection.getLatestBlockhash()
-  bankTx.recentBlockhash = blockhash
-  bankTx.feePayer = deployer.publicKey
-  bankTx.sign(deployer)
-
-  const bankSig = await sendAndConfirmTransaction(connection, bankTx, [deployer])
+  const bankSig = await sendInstruction(connection, bankIx, [deployer])
   console.log(`✅ ${tokenName} Bank initialized: ${bankSig}`)
 
   // 2. Create deployer's token account and mint tokens
@@ -210,29 +274,14 @@ async function initializeAndFundBank(
   const displayAmount = tokenName === 'SOL' ? amount / LAMPORTS_PER_SOL : amount / 1_000_000
   console.log(`✅ Minted ${displayAmount} ${tokenName} to deployer`)
 
-  // 3. Initialize deployer user account
+  // 3. Initialize deployer user account in the market
   try {
     const userAccountIx = await getInitializeAccountInstructionAsync({
-      signer: deployerSigner
+      signer: deployerSigner,
+      market: address(market.toString()),
     })
 
-    const web3UserAccountIx = {
-      keys: userAccountIx.accounts.map((acc: { address: string }, index: number) => ({
-        pubkey: new PublicKey(acc.address),
-        isSigner: index === 0,
-        isWritable: index === 0 || index === 1
-      })),
-      programId: new PublicKey(userAccountIx.programAddress),
-      data: Buffer.from(userAccountIx.data)
-    }
-
-    const userAccountTx = new Transaction().add(web3UserAccountIx)
-    const { blockhash: userBlockhash } = await connection.getLatestBlockhash()
-    userAccountTx.recentBlockhash = userBlockhash
-    userAccountTx.feePayer = deployer.publicKey
-    userAccountTx.sign(deployer)
-
-    const userAccountSig = await sendAndConfirmTransaction(connection, userAccountTx, [deployer])
+    const userAccountSig = await sendInstruction(connection, userAccountIx, [deployer])
     console.log(`✅ Deployer user account initialized: ${userAccountSig}`)
   } catch {
     console.log(`ℹ️  Deployer user account may already exist, continuing...`)
@@ -241,53 +290,35 @@ async function initializeAndFundBank(
   // 4. Deposit tokens into the bank
   console.log(`💰 Depositing ${displayAmount} ${tokenName} into bank...`)
 
-  const PROGRAM_ID = "9CoY42r3y5WFDJjQX97e9m9THcVGpvuVSKjBjGkiksMR"
-
   const [derivedBankAddress] = PublicKey.findProgramAddressSync(
-    [mint.toBuffer()],
-    new PublicKey(PROGRAM_ID)
+    [market.toBuffer(), mint.toBuffer()],
+    PROGRAM_ID
   )
 
   const [derivedBankTokenAccountAddress] = PublicKey.findProgramAddressSync(
-    [Buffer.from("Treasury"), mint.toBuffer()],
-    new PublicKey(PROGRAM_ID)
+    [Buffer.from("Treasury"), market.toBuffer(), mint.toBuffer()],
+    PROGRAM_ID
   )
 
   const [derivedUserAccountAddress] = PublicKey.findProgramAddressSync(
-    [deployer.publicKey.toBuffer()],
-    new PublicKey(PROGRAM_ID)
+    [market.toBuffer(), deployer.publicKey.toBuffer()],
+    PROGRAM_ID
   )
 
   const derivedUserTokenAccount = await getAssociatedTokenAddress(mint, deployer.publicKey)
 
   const depositIx = await getDepositInstructionAsync({
     signer: deployerSigner,
+    market: address(market.toString()),
     mint: address(mint.toString()),
     bank: address(derivedBankAddress.toString()),
     bankTokenAccount: address(derivedBankTokenAccountAddress.toString()),
     userAccount: address(derivedUserAccountAddress.toString()),
     userTokenAccount: address(derivedUserTokenAccount.toString()),
     amountToDeposit: amount,
-    tokenType: tokenName === 'SOL' ? TokenType.SOL : TokenType.USDC
   })
 
-    const web3DepositIx = {
-      keys: depositIx.accounts.map((acc: { address: string }, index: number) => ({
-        pubkey: new PublicKey(acc.address),
-        isSigner: index === 0,
-        isWritable: index === 0 || index === 2 || index === 3 || index === 4 || index === 5
-      })),
-    programId: new PublicKey(depositIx.programAddress),
-    data: Buffer.from(depositIx.data)
-  }
-
-  const depositTx = new Transaction().add(web3DepositIx)
-  const { blockhash: depositBlockhash } = await connection.getLatestBlockhash()
-  depositTx.recentBlockhash = depositBlockhash
-  depositTx.feePayer = deployer.publicKey
-  depositTx.sign(deployer)
-
-  const depositSig = await sendAndConfirmTransaction(connection, depositTx, [deployer])
+  const depositSig = await sendInstruction(connection, depositIx, [deployer])
   console.log(`✅ ${tokenName} deposited into bank: ${depositSig}`)
 }
 
@@ -2,22 +2,35 @@
 
 import DashboardFeature from '@/features/dashboard/dashboard-feature'
 import { useLendingdappUserAccount } from '@/features/lendingdapp/data-access/use-lendingdapp-user-account'
+import { useLendingdappBanksQuery } from '@/features/lendingdapp/data-access/use-lendingdapp-banks-query'
+import { getBorrowAmount, getDepositAmount } from '@/features/lendingdapp/data-access/lendingdapp-accounts'
 import { useSolana } from '@/components/solana/use-solana'
 
 export default function Home() {
   const { account } = useSolana()
   const { data: userAccount } = useLendingdappUserAccount(account?.address)
+  const { data: banks } = useLendingdappBanksQuery()
 
-  const userAccountData = userAccount ? {
-    depositedSol: Number(userAccount.depositedSol || 0n),
-    borrowedSol: Number(userAccount.borrowedSol || 0n),
-    depositedUsdc: Number(userAccount.depositedUsdc || 0n),
-    borrowedUsdc: Number(userAccount.borrowedUsdc || 0n),
-  } : {
-    depositedSol: 0,
-    borrowedSol: 0,
-    depositedUsdc: 0,
-    borrowedUsdc: 0,
+  // Positions hold shares of a bank, convert them to the tokens they are worth
+  const positionAmounts = (token: 'SOL' | 'USDC') => {
+    const bank = banks?.find((bank) => bank.type === token)
+    const position = bank && userAccount?.positions.find((position) => position.bank === bank.address)
+    if (!bank || !position) {
+      return { deposited: 0, borrowed: 0 }
+    }
+    return {
+      deposited: Number(getDepositAmount(bank, position.depositShares)),
+      borrowed: Number(getBorrowAmount(bank, position.borrowShares)),
+    }
+  }
+
+  const sol = positionAmounts('SOL')
+  const usdc = positionAmounts('USDC')
+  const userAccountData = {
+    depositedSol: sol.deposited,
+    borrowedSol: sol.borrowed,
+    depositedUsdc: usdc.deposited,
+    borrowedUsdc: usdc.borrowed,
   }
 
   return <DashboardFeature userAccount={userAccountData} />
@@ -0,0 +1,96 @@
+import {
+  AccountRole,
+  address,
+  fetchEncodedAccount,
+  getAddressDecoder,
+  getAddressEncoder,
+  getProgramDerivedAddress,
+  getU16Encoder,
+  isSome,
+  type AccountMeta,
+  type Address,
+} from 'gill'
+import {
+  fetchBank,
+  fetchMaybeUser,
+  LENDING_PROTOCOL_PROGRAM_ADDRESS,
+  OracleType,
+  type Bank,
+  type OracleConfig,
+} from '@project/anchor'
+
+type Rpc = Parameters<typeof fetchEncodedAccount>[0]
+
+// The indices of a bank are fixed point numbers with 27 decimals, `Rate` in the program
+const RATE_ONE = 10n ** 27n
+
+// Program of the Pyth sponsored feeds, which keeps a PriceUpdateV2 account per feed up to date
+const PYTH_PUSH_ORACLE_PROGRAM_ADDRESS = address('pythWSnswVUd12oZpeFP8e9CVaEqJg25g1Vtc2biRsT')
+
+// Bank PDA = [market, mint]
+export async function getBankAddress(market: Address, mint: Address) {
+  const [bank] = await getProgramDerivedAddress({
+    programAddress: LENDING_PROTOCOL_PROGRAM_ADDRESS,
+    seeds: [getAddressEncoder().encode(market), getAddressEncoder().encode(mint)],
+  })
+  return bank
+}
+
+// User PDA = [market, owner]
+export async function getUserAddress(market: Address, owner: Address) {
+  const [user] = await getProgramDerivedAddress({
+    programAddress: LENDING_PROTOCOL_PROGRAM_ADDRESS,
+    seeds: [getAddressEncoder().encode(market), getAddressEncoder().encode(owner)],
+  })
+  return user
+}
+
+// Tokens the deposit shares of a position are worth
+export function getDepositAmount(bank: Bank, shares: bigint) {
+  return (shares * bank.supplyIndex) / RATE_ONE
+}
+
+// Tokens owed for the borrow shares of a position
+export function getBorrowAmount(bank: Bank, shares: bigint) {
+  return (shares * bank.cumulativeBorrowIndex) / RATE_ONE
+}
+
+// Account the program reads the price of an oracle from
+export async function getOracleAddress(oracle: OracleConfig) {
+  if (oracle.oracleType === OracleType.PythPull) {
+    // Sponsored feeds are posted on shard 0
+    const [priceUpdate] = await getProgramDerivedAddress({
+      programAddress: PYTH_PUSH_ORACLE_PROGRAM_ADDRESS,
+      seeds: [getU16Encoder().encode(0), oracle.feedId],
+    })
+    return priceUpdate
+  }
+
+  // The feed id of a push oracle is the address of its price account
+  return getAddressDecoder().decode(oracle.feedId)
+}
+
+/*
+  Borrow and withdraw check the health of the user across all of their positions, so they take as remaining accounts,
+  for every position and in the order of the positions: the bank, its oracle, and its secondary oracle if it has one.
+  A borrow from a bank the user has no position in yet adds the position at the end.
+  A withdrawal that empties a position closes it, the program skips the accounts of banks the user no longer has a position in.
+*/
+export async function getHealthAccounts(rpc: Rpc, market: Address, owner: Address, bank: Address) {
+  const user = await fetchMaybeUser(rpc, await getUserAddress(market, owner))
+  const banks = user.exists ? user.data.positions.map((position) => position.bank) : []
+  if (!banks.includes(bank)) {
+    banks.push(bank)
+  }
+
+  const accounts: AccountMeta[] = []
+  for (const bankAddress of banks) {
+    const { data } = await fetchBank(rpc, bankAddress)
+    accounts.push({ address: bankAddress, role: AccountRole.READONLY })
+    accounts.push({ address: await getOracleAddress(data.oracle), role: AccountRole.READONLY })
+    if (isSome(data.secondaryOracle)) {
+      accounts.push({ address: await getOracleAddress(data.secondaryOracle.value), role: AccountRole.READONLY })
+    }
+  }
+  return accounts
+}
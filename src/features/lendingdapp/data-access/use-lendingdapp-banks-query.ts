@@ -1,26 +1,22 @@
 import { useSolana } from '@/components/solana/use-solana'
 import { useQuery } from '@tanstack/react-query'
-import { address, getProgramDerivedAddress, getAddressEncoder, fetchEncodedAccount } from 'gill'
+import { address, fetchEncodedAccount, type Address } from 'gill'
 import { Bank, fetchMaybeBank } from '@project/anchor'
-import { LENDING_PROTOCOL_PROGRAM_ADDRESS } from '@project/anchor'
 import { useBanksConfig } from './use-bank-config'
+import { getBankAddress } from './lendingdapp-accounts'
 
 async function confirmBankExists(
+  market: string,
   mint: string,
   rpc: Parameters<typeof fetchEncodedAccount>[0],
-  banks: Array<{ mint: string, type: string } & Bank>,
+  banks: Array<{ address: Address, mint: string, type: string } & Bank>,
   currency: string
 ) {
   // Convert string to Solana address
   const bankMintAddress = address(mint)
 
   // Calculate where the given bank account should be (since PDAs can be deterministically calculated from: Program ID + Seeds)
-  const [bankAddress] = await getProgramDerivedAddress({
-    programAddress: LENDING_PROTOCOL_PROGRAM_ADDRESS,
-
-    // Bank PDA = [mint_address]
-    seeds: [getAddressEncoder().encode(bankMintAddress)],
-  })
+  const bankAddress = await getBankAddress(address(market), bankMintAddress)
 
   // Try to fetch the bank account from blockchain, using fetchMaybeBank instead of fetchBank to avoid throwing when account doesn't exist
   const bankAccount = await fetchMaybeBank(rpc, bankAddress)
@@ -28,6 +24,7 @@ async function confirmBankExists(
   // Only add to banks array if the account actually exists
   if (bankAccount.exists) {
     banks.push({
+      address: bankAddress,
       mint: mint,
       // Spread the bank data (total deposits, etc.)
       ...bankAccount.data,
@@ -60,6 +57,7 @@ export function useLendingdappBanksQuery() {
     queryFn: async () => {
       // Will store found banks
       const banks: Array<{
+        address: Address,
         mint: string,
         type: string
       } & Bank> = []
@@ -67,7 +65,7 @@ export function useLendingdappBanksQuery() {
       // SOL Bank (using custom SOL token from config)
       if (banksConfig?.SOL_MINT) {
         try {
-          await confirmBankExists(banksConfig.SOL_MINT, rpc, banks, 'SOL');
+          await confirmBankExists(banksConfig.MARKET, banksConfig.SOL_MINT, rpc, banks, 'SOL');
           console.log('SOL Bank found!!')
         } catch (e) {
           console.error('SOL Bank fetch error:', e)
@@ -77,7 +75,7 @@ export function useLendingdappBanksQuery() {
       // USDC Bank with similar logic
       if (banksConfig?.USDC_MINT) {
         try {
-          await confirmBankExists(banksConfig.USDC_MINT, rpc, banks, 'USDC')
+          await confirmBankExists(banksConfig.MARKET, banksConfig.USDC_MINT, rpc, banks, 'USDC')
           console.log('USDC Bank found!!')
 
         } catch (e) {
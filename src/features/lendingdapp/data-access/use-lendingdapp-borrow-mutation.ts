@@ -1,21 +1,22 @@
 import { useMutation, useQueryClient } from '@tanstack/react-query'
 import { UiWalletAccount, useWalletUiSigner } from '@wallet-ui/react'
 import { useSolana } from '@/components/solana/use-solana'
-import { address } from 'gill'
-import { getBorrowInstructionAsync, TokenType } from '@project/anchor'
+import { address, isWritableRole } from 'gill'
+import { getBorrowInstructionAsync } from '@project/anchor'
 import { toast } from 'sonner'
 import { toastTx } from '@/components/toast-tx'
 import { Connection, PublicKey, Transaction, TransactionInstruction } from '@solana/web3.js'
 import { useBanksConfig } from './use-bank-config'
+import { getBankAddress, getHealthAccounts } from './lendingdapp-accounts'
 
 export function useLendingdappBorrowMutation({ account }: { account: UiWalletAccount }) {
-  const { cluster } = useSolana()
+  const { cluster, client } = useSolana()
   const queryClient = useQueryClient()
   const signer = useWalletUiSigner({ account })
   const banksConfig = useBanksConfig()
 
   return useMutation({
-    mutationFn: async ({ amount, priceUpdate }: { amount: number; priceUpdate: string }) => {
+    mutationFn: async ({ amount }: { amount: number }) => {
       if (!banksConfig) {
         throw new Error('Bank config not loaded')
       }
@@ -23,22 +24,30 @@ export function useLendingdappBorrowMutation({ account }: { account: UiWalletAcc
       try {
         const connection = new Connection('http://127.0.0.1:8899', 'confirmed')
 
+        const market = address(banksConfig.config.MARKET)
         const mintAddress = address(banksConfig.config.SOL_MINT)
         const amountInSmallestUnit = BigInt(Math.floor(amount * 1_000_000_000))
 
         const gillIx = await getBorrowInstructionAsync({
           signer,
+          market,
           mint: mintAddress,
-          priceUpdate: address(priceUpdate),
           amountToBorrow: amountInSmallestUnit,
-          tokenType: TokenType.SOL
         })
 
+        // Banks and oracles of every position, so the program can check the borrow is covered by the collateral
+        const healthAccounts = await getHealthAccounts(
+          client.rpc,
+          market,
+          signer.address,
+          await getBankAddress(market, mintAddress)
+        )
+
         const web3Ix = new TransactionInstruction({
-          keys: gillIx.accounts.map((acc: any, i: number) => ({
+          keys: [...gillIx.accounts, ...healthAccounts].map((acc, i) => ({
             pubkey: new PublicKey(acc.address),
             isSigner: i === 0,
-            isWritable: [0, 2, 3, 4, 5].includes(i), // signer, bank, bank_token_account, user_account, user_token_account
+            isWritable: isWritableRole(acc.role),
           })),
           programId: new PublicKey(gillIx.programAddress),
           data: Buffer.from(gillIx.data),
@@ -1,10 +1,10 @@
 import { useMutation, useQueryClient } from '@tanstack/react-query'
 import { useSolana } from '@/components/solana/use-solana'
 import { UiWalletAccount, useWalletUiSigner } from '@wallet-ui/react'
-import { getDepositInstructionAsync, TokenType } from '@project/anchor'
+import { getDepositInstructionAsync } from '@project/anchor'
 import { toastTx } from '@/components/toast-tx'
 import { toast } from 'sonner'
-import { address } from 'gill'
+import { address, isWritableRole } from 'gill'
 import { Connection, PublicKey, Transaction, TransactionInstruction } from '@solana/web3.js'
 import { useBanksConfig } from './use-bank-config'
 
@@ -35,7 +35,8 @@ export function useLendingdappDepositMutation({ account }: { account: UiWalletAc
       try {
         const connection = new Connection('http://127.0.0.1:8899', 'confirmed')
 
-        // Get mint address from config
+        // Get market and mint address from config
+        const market = address(banksConfig.config.MARKET)
         const mintAddress = address(token === 'SOL' ? banksConfig.config.SOL_MINT : banksConfig.config.USDC_MINT)
 
         // Convert amount to smallest units (SOL: 9 decimals, USDC: 6 decimals)
@@ -46,19 +47,18 @@ export function useLendingdappDepositMutation({ account }: { account: UiWalletAc
         // Generate deposit instruction using Codama-generated functions
         const gillIx = await getDepositInstructionAsync({
           signer,
+          market,
           mint: mintAddress,
           amountToDeposit: amountInSmallestUnit,
-          tokenType: token === 'SOL' ? TokenType.SOL : TokenType.USDC
         })
         console.log('Instruction generated:', gillIx)
 
         // Convert Gill instruction to Web3.js TransactionInstruction
         const web3Ix = new TransactionInstruction({
-          keys: gillIx.accounts.map((acc: any, i: number) => ({
+          keys: gillIx.accounts.map((acc, i) => ({
             pubkey: new PublicKey(acc.address),
             isSigner: i === 0,
-            // signer, bank, bank_token_account, user_account, user_token_account
-            isWritable: [0, 2, 3, 4, 5].includes(i),
+            isWritable: isWritableRole(acc.role),
           })),
           programId: new PublicKey(gillIx.programAddress),
           data: Buffer.from(gillIx.data),
@@ -5,6 +5,7 @@ import { useWalletUiSignAndSend } from '@wallet-ui/react-gill'
 import { getInitializeAccountInstructionAsync } from '@project/anchor'
 import { toastTx } from '@/components/toast-tx'
 import { toast } from 'sonner'
+import { address } from 'gill'
 import { useBanksConfig } from './use-bank-config'
 
 export function useLendingdappInitializeAccountMutation({ account }: { account: UiWalletAccount }) {
@@ -16,12 +17,13 @@ export function useLendingdappInitializeAccountMutation({ account }: { account:
 
   return useMutation({
     mutationFn: async () => {
-      if (!banksConfig?.USDC_MINT) {
-        throw new Error('USDC mint not found in banks config')
+      if (!banksConfig?.MARKET) {
+        throw new Error('Market not found in banks config')
       }
 
       const instruction = await getInitializeAccountInstructionAsync({
         signer,
+        market: address(banksConfig.MARKET),
       })
       return await signAndSend(instruction, signer)
     },
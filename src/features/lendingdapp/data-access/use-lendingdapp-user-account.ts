@@ -1,19 +1,19 @@
 import { useQuery } from '@tanstack/react-query'
 import { useSolana } from '@/components/solana/use-solana'
-import { fetchUser, LENDING_PROTOCOL_PROGRAM_ADDRESS } from '@project/anchor'
-import { address, getAddressEncoder, getProgramDerivedAddress } from 'gill'
+import { fetchUser } from '@project/anchor'
+import { address } from 'gill'
+import { useBanksConfig } from './use-bank-config'
+import { getUserAddress } from './lendingdapp-accounts'
 
 export function useLendingdappUserAccount(userAddress: string | undefined) {
   const { client, cluster } = useSolana()
-  
+  const { config: banksConfig } = useBanksConfig()
+
   return useQuery({
     queryKey: ['lendingdapp', 'user', userAddress, { cluster }],
     queryFn: async () => {
-      if (!userAddress) return null
-      const [userPda] = await getProgramDerivedAddress({
-        programAddress: LENDING_PROTOCOL_PROGRAM_ADDRESS,
-        seeds: [getAddressEncoder().encode(address(userAddress))],
-      })
+      if (!userAddress || !banksConfig?.MARKET) return null
+      const userPda = await getUserAddress(address(banksConfig.MARKET), address(userAddress))
       try {
         const account = await fetchUser(client.rpc, userPda)
         return account.data
@@ -21,6 +21,6 @@ export function useLendingdappUserAccount(userAddress: string | undefined) {
         return null
       }
     },
-    enabled: !!userAddress,
+    enabled: !!userAddress && !!banksConfig,
   })
 }
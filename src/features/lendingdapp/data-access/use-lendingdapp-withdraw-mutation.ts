@@ -1,15 +1,16 @@
 import { useMutation, useQueryClient } from '@tanstack/react-query'
 import { UiWalletAccount, useWalletUiSigner } from '@wallet-ui/react'
 import { useSolana } from '@/components/solana/use-solana'
-import { address } from 'gill'
-import { getWithdrawInstructionAsync, TokenType } from '@project/anchor'
+import { address, isWritableRole } from 'gill'
+import { getWithdrawInstructionAsync } from '@project/anchor'
 import { toast } from 'sonner'
 import { toastTx } from '@/components/toast-tx'
 import { Connection, PublicKey, Transaction, TransactionInstruction } from '@solana/web3.js'
 import { useBanksConfig } from './use-bank-config'
+import { getBankAddress, getHealthAccounts } from './lendingdapp-accounts'
 
 export function useLendingdappWithdrawMutation({ account }: { account: UiWalletAccount }) {
-  const { cluster } = useSolana()
+  const { cluster, client } = useSolana()
   const queryClient = useQueryClient()
   const signer = useWalletUiSigner({ account })
   const banksConfig = useBanksConfig()
@@ -23,21 +24,30 @@ export function useLendingdappWithdrawMutation({ account }: { account: UiWalletA
       try {
         const connection = new Connection('http://127.0.0.1:8899', 'confirmed')
 
+        const market = address(banksConfig.config.MARKET)
         const mintAddress = address(token === 'SOL' ? banksConfig.config.SOL_MINT : banksConfig.config.USDC_MINT)
         const amountInSmallestUnit = BigInt(Math.floor(amount * (token === 'SOL' ? 1_000_000_000 : 1_000_000)))
 
         const gillIx = await getWithdrawInstructionAsync({
           signer,
+          market,
           mint: mintAddress,
           amountToWithdraw: amountInSmallestUnit,
-          tokenType: token === 'SOL' ? TokenType.SOL : TokenType.USDC
         })
 
+        // Banks and oracles of every position, so the program can check the remaining collateral still covers the debt
+        const healthAccounts = await getHealthAccounts(
+          client.rpc,
+          market,
+          signer.address,
+          await getBankAddress(market, mintAddress)
+        )
+
         const web3Ix = new TransactionInstruction({
-          keys: gillIx.accounts.map((acc: any, i: number) => ({
+          keys: [...gillIx.accounts, ...healthAccounts].map((acc, i) => ({
             pubkey: new PublicKey(acc.address),
             isSigner: i === 0,
-            isWritable: [0, 2, 3, 4, 5].includes(i), // signer, bank, bank_token_account, user_account, user_token_account
+            isWritable: isWritableRole(acc.role),
           })),
           programId: new PublicKey(gillIx.programAddress),
           data: Buffer.from(gillIx.data),
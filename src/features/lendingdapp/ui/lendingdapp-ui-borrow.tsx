@@ -9,10 +9,6 @@ export function LendingdappUiBorrow({ account }: { account: UiWalletAccount }) {
   const [amount, setAmount] = useState('')
   const mutation = useLendingdappBorrowMutation({ account })
 
-  // For now, use a placeholder price update account
-  // In a real implementation, this would come from a Pyth oracle
-  const priceUpdateAccount = '11111111111111111111111111111112' // Placeholder
-
   return (
     <div className="space-y-4">
       <div className="space-y-2">
@@ -31,7 +27,7 @@ export function LendingdappUiBorrow({ account }: { account: UiWalletAccount }) {
       </div>
 
       <Button
-        onClick={() => mutation.mutateAsync({ amount: parseFloat(amount), priceUpdate: priceUpdateAccount })}
+        onClick={() => mutation.mutateAsync({ amount: parseFloat(amount) })}
         disabled={mutation.isPending || !amount || parseFloat(amount) <= 0}
         className="w-full bg-orange-600 hover:bg-orange-700 text-white font-medium py-2 px-4 rounded-md transition-colors"
       >